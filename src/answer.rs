@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{utils, Error, Result};
+
+/// The answer to one part of a puzzle
+///
+/// Integer answers are normalized when converted, so that the same value compares equal
+/// regardless of the integer type returned by the solver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    /// Unsigned integer too large to fit in an `i64`
+    UInt(u64),
+    Text(String),
+    /// Multi-line answer, like the text displayed on a screen
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Lines(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::UInt(v) => write!(f, "{}", v),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Int(value as i64)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                match i64::try_from(value) {
+                    Ok(v) => Answer::Int(v),
+                    Err(_) => Answer::UInt(value as u64),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64);
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+/// Conversion of the values returned by the solvers, which may be plain values or `Result`s
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<Answer> {
+                Ok(self.into())
+            }
+        })*
+    };
+}
+
+impl_into_answer!(i8, i16, i32, i64, u8, u16, u32, u64, usize, String, Vec<String>);
+
+impl<T: IntoAnswer> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<Answer> {
+        self.and_then(IntoAnswer::into_answer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    /// Answers of the example inputs, in the order of the example files
+    #[serde(default)]
+    examples: Vec<ExampleAnswers>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExampleAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Expected answers of the puzzles, keyed by (year, day, part)
+///
+/// The answers are stored in a TOML file with one table per day. The answers of the example
+/// inputs of a day (see [`crate::example`]) are listed in an array of tables, whose n-th element
+/// gives the answers of the n-th example:
+///
+/// ```toml
+/// [2022.05]
+/// part1 = "QNHWJVJZW"
+/// part2 = "BPCZJLFJW"
+///
+/// [[2022.05.examples]]
+/// part1 = "CMZ"
+/// part2 = "MCD"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, u32), Answer>,
+    /// Answers of the examples, keyed by (year, day, example, part)
+    examples: BTreeMap<(u32, u32, u32, u32), Answer>,
+}
+
+impl Answers {
+    /// Path of the answers file at the root of the crate
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::parse(&utils::read_input(path)?)
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let years: BTreeMap<u32, BTreeMap<u32, DayAnswers>> = toml::from_str(input).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            Error::parse_at(input, offset, e.message())
+        })?;
+        let mut answers = BTreeMap::new();
+        let mut examples = BTreeMap::new();
+        for (year, days) in years {
+            for (day, parts) in days {
+                for (part, answer) in [(1, parts.part1), (2, parts.part2)] {
+                    if let Some(answer) = answer {
+                        answers.insert((year, day, part), answer);
+                    }
+                }
+                for (n, example) in (1..).zip(parts.examples) {
+                    for (part, answer) in [(1, example.part1), (2, example.part2)] {
+                        if let Some(answer) = answer {
+                            examples.insert((year, day, n, part), answer);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Self { answers, examples })
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+
+    /// Expected answer of the `n`-th example of a day (numbered from 1)
+    pub fn example(&self, year: u32, day: u32, n: u32, part: u32) -> Option<&Answer> {
+        self.examples.get(&(year, day, n, part))
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42i32), Answer::Int(42));
+        assert_eq!(Answer::from(42usize), Answer::from(42i64));
+        assert_eq!(Answer::from(u64::MAX), Answer::UInt(u64::MAX));
+        assert_eq!(Answer::from("QNHWJVJZW"), Answer::Text("QNHWJVJZW".to_string()));
+        assert_ne!(Answer::from(42), Answer::from("42"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(vec!["#.".to_string(), ".#".to_string()]).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_serde() {
+        let answers = vec![
+            Answer::from(6399153661894u64),
+            Answer::from("2=-1=0"),
+            Answer::from(vec!["##".to_string(), "..".to_string()]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r###"[6399153661894,"2=-1=0",["##",".."]]"###);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "[2022.05]\npart1 = \"QNHWJVJZW\"\n\n[2022.10]\npart1 = 14860\npart2 = [\"#.\", \".#\"]\n",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(2022, 5, 1), Some(&Answer::from("QNHWJVJZW")));
+        assert_eq!(answers.get(2022, 5, 2), None);
+        assert_eq!(answers.get(2022, 10, 1), Some(&Answer::from(14860)));
+        assert!(answers.get(2022, 10, 2).unwrap().is_multiline());
+        assert!(matches!(
+            Answers::parse("[2022.05]\npart3 = 1\n"),
+            Err(Error::Parse { line: 2, column: 1, .. })
+        ));
+        assert!(Answers::parse("[2022.day05]\npart1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let answers = Answers::parse(
+            "[2023.20]\npart1 = 1\n\n\
+             [[2023.20.examples]]\npart1 = 32000000\n\n\
+             [[2023.20.examples]]\npart1 = 11687500\n",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers.example(2023, 20, 1, 1), Some(&Answer::from(32000000)));
+        assert_eq!(answers.example(2023, 20, 2, 1), Some(&Answer::from(11687500)));
+        assert_eq!(answers.example(2023, 20, 2, 2), None);
+        assert_eq!(answers.example(2023, 20, 3, 1), None);
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let answers = Answers::load(Answers::default_path()).unwrap();
+        assert_eq!(answers.get(2024, 9, 1), Some(&Answer::from(6399153661894u64)));
+    }
+}
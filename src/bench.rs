@@ -0,0 +1,153 @@
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Entry;
+use crate::{utils, Error, Result};
+
+/// Wall time statistics of several runs of a solver (times in milliseconds)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+impl Timing {
+    /// Computes the statistics from a non-empty list of durations (in milliseconds)
+    pub fn from_samples(year: u32, day: u32, part: u32, mut samples: Vec<f64>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2.0
+        };
+        Self {
+            year,
+            day,
+            part,
+            runs: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        }
+    }
+
+    /// Relative change of the median time compared to a baseline (0.1 means 10% slower), or
+    /// `None` if the median time of the baseline is zero
+    pub fn change_from(&self, baseline: &Timing) -> Option<f64> {
+        if baseline.median <= 0.0 {
+            return None;
+        }
+        Some((self.median - baseline.median) / baseline.median)
+    }
+}
+
+/// Timing of a solver compared to a baseline, as reported by the `bench` command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    #[serde(flatten)]
+    pub timing: Timing,
+    /// Relative change of the median time compared to the baseline, in percent (missing if there
+    /// is no baseline for the solver)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change: Option<f64>,
+    /// Whether the change is above the tolerated slowdown
+    pub regression: bool,
+}
+
+impl Report {
+    /// Compares a timing with the timing of the same solver in `baseline` (if any), a slowdown of
+    /// more than `tolerance` percent being a regression
+    pub fn new(timing: Timing, baseline: &[Timing], tolerance: f64) -> Self {
+        let change = baseline
+            .iter()
+            .find(|t| (t.year, t.day, t.part) == (timing.year, timing.day, timing.part))
+            .and_then(|reference| timing.change_from(reference))
+            .map(|change| change * 100.0);
+        let regression = change.is_some_and(|change| change > tolerance);
+        Self { timing, change, regression }
+    }
+}
+
+/// Runs the solver of an entry `runs` times on the given input and measures each run
+pub fn bench(entry: &Entry, input: &str, runs: usize) -> Result<Timing> {
+    let mut samples = Vec::new();
+    for _ in 0..runs {
+        let start = Instant::now();
+        entry.solve(input)?;
+        samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    Ok(Timing::from_samples(entry.year, entry.day, entry.part, samples))
+}
+
+/// Reads a list of timings saved as JSON
+pub fn load_timings<P: AsRef<Path>>(path: P) -> Result<Vec<Timing>> {
+    let contents = utils::read_input(path)?;
+    serde_json::from_str(&contents).map_err(|e| Error::Parse {
+        line: e.line(),
+        column: e.column(),
+        message: e.to_string(),
+    })
+}
+
+/// Formats a duration given in milliseconds with a suitable unit
+pub fn format_time(ms: f64) -> String {
+    if ms < 1.0 {
+        format!("{:.1}µs", ms * 1000.0)
+    } else if ms < 1000.0 {
+        format!("{:.2}ms", ms)
+    } else {
+        format!("{:.2}s", ms / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let timing = Timing::from_samples(2022, 1, 1, vec![3.0, 1.0, 2.0]);
+        assert_eq!((timing.min, timing.median, timing.max), (1.0, 2.0, 3.0));
+        let timing = Timing::from_samples(2022, 1, 1, vec![4.0, 1.0, 2.0, 8.0]);
+        assert_eq!((timing.runs, timing.median), (4, 3.0));
+    }
+
+    #[test]
+    fn test_change_from() {
+        let baseline = Timing::from_samples(2022, 1, 1, vec![2.0]);
+        let timing = Timing::from_samples(2022, 1, 1, vec![3.0]);
+        assert_eq!(timing.change_from(&baseline), Some(0.5));
+        let zero = Timing::from_samples(2022, 1, 1, vec![0.0]);
+        assert_eq!(timing.change_from(&zero), None);
+    }
+
+    #[test]
+    fn test_report() {
+        let baseline = vec![Timing::from_samples(2022, 1, 1, vec![2.0]), Timing::from_samples(2022, 1, 2, vec![0.0])];
+        let report = Report::new(Timing::from_samples(2022, 1, 1, vec![3.0]), &baseline, 20.0);
+        assert_eq!((report.change, report.regression), (Some(50.0), true));
+        let report = Report::new(Timing::from_samples(2022, 1, 2, vec![3.0]), &baseline, 20.0);
+        assert_eq!((report.change, report.regression), (None, false));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!((json["day"].as_u64(), json.get("change")), (Some(1), None));
+        let report = Report::new(Timing::from_samples(2022, 1, 1, vec![2.2]), &baseline, 20.0);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!((json["change"].as_f64().map(f64::round), json["regression"].as_bool()), (Some(10.0), Some(false)));
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.0123), "12.3µs");
+        assert_eq!(format_time(12.346), "12.35ms");
+        assert_eq!(format_time(2500.0), "2.50s");
+    }
+}
@@ -0,0 +1,227 @@
+//! Client for the Advent of Code website
+//!
+//! Requests are authenticated with the session token of the configuration and spaced by at least
+//! `min_interval` (the time of the last request is saved in the data directory so that the limit
+//! also holds across runs of the program).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+use crate::config::Config;
+use crate::{Error, Result};
+
+const USER_AGENT: &str = "github.com/vpoupet/advent-rust";
+
+pub struct Client {
+    config: Config,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self { config, agent }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Waits until `min_interval` has passed since the last request, and records the time of the
+    /// new request
+    fn throttle(&self) -> Result<()> {
+        let path = self.config.data_dir.join("last_request");
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| contents.trim().parse().ok())
+            .map(|ms| UNIX_EPOCH + Duration::from_millis(ms));
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if elapsed < self.config.min_interval {
+                thread::sleep(self.config.min_interval - elapsed);
+            }
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        write_file(&path, &now.as_millis().to_string())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url, path)
+    }
+
+    fn cookie(&self) -> Result<String> {
+        Ok(format!("session={}", self.config.session()?))
+    }
+
+    /// Sends an authenticated GET request and returns the body of the response
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let cookie = self.cookie()?;
+        self.throttle()?;
+        let response = self.agent.get(&url).header("Cookie", cookie).call();
+        read_response(&url, response)
+    }
+
+    /// Sends an authenticated POST request with form data and returns the body of the response
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        let cookie = self.cookie()?;
+        self.throttle()?;
+        let response = self.agent.post(&url).header("Cookie", cookie).send_form(form.iter().copied());
+        read_response(&url, response)
+    }
+
+    /// Returns the path of the downloaded input of a day, downloading it first if it is not in
+    /// the cache yet (or if `force` is set)
+    pub fn fetch_input(&self, year: u32, day: u32, force: bool) -> Result<PathBuf> {
+        let path = self.config.input_cache_path(year, day);
+        if path.exists() && !force {
+            return Ok(path);
+        }
+        let url_path = format!("/{}/day/{}/input", year, day);
+        let input = self.get(&url_path)?;
+        if input.is_empty() {
+            return Err(Error::Http { url: self.url(&url_path), message: "empty input".to_string() });
+        }
+        write_file(&path, &input)?;
+        Ok(path)
+    }
+}
+
+/// Checks the status of a response and reads its body
+fn read_response(
+    url: &str,
+    response: std::result::Result<Response<Body>, ureq::Error>,
+) -> Result<String> {
+    let http_error = |message: String| Error::Http { url: url.to_string(), message };
+    let mut response = response.map_err(|e| http_error(e.to_string()))?;
+    let status = response.status();
+    let body = response.body_mut().read_to_string().map_err(|e| http_error(e.to_string()))?;
+    if !status.is_success() {
+        let message = match body.lines().next().map(str::trim) {
+            Some(reason) if !reason.is_empty() => format!("{}: {}", status, reason),
+            _ => status.to_string(),
+        };
+        return Err(http_error(message));
+    }
+    Ok(body)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(path, e))
+}
+
+/// Minimal HTTP server standing in for the website in tests
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+
+    use crate::config::Config;
+
+    /// Configuration pointing to a test server, with an empty data directory
+    pub fn test_config(name: &str, base_url: String) -> Config {
+        let data_dir = std::env::temp_dir().join(format!("advent-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+        Config {
+            session: Some("token".to_string()),
+            base_url,
+            data_dir,
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    /// Serves the given responses (status and body) to successive requests, and returns the base
+    /// URL of the server and a handle returning the received requests (head and body)
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{serve, test_config};
+    use super::*;
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, server) = serve(vec![(200, "1\n2\n3\n"), (404, "Not found\n")]);
+        let client = Client::new(test_config("fetch", url));
+        let path = client.fetch_input(2022, 1, false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        // cached inputs are not downloaded again
+        assert_eq!(client.fetch_input(2022, 1, false).unwrap(), path);
+        let error = client.fetch_input(2022, 2, false).unwrap_err();
+        assert!(matches!(&error, Error::Http { message, .. } if message == "404 Not Found: Not found"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].to_ascii_lowercase().contains("cookie: session=token\r\n"));
+        fs::remove_dir_all(&client.config.data_dir).unwrap();
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut config = test_config("throttle", String::new());
+        config.min_interval = Duration::from_millis(200);
+        let client = Client::new(config);
+        let start = std::time::Instant::now();
+        client.throttle().unwrap();
+        client.throttle().unwrap();
+        // the time of the last request is saved in milliseconds
+        assert!(start.elapsed() >= Duration::from_millis(199));
+        fs::remove_dir_all(&client.config.data_dir).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+//! Settings of the client for the Advent of Code website
+//!
+//! The settings are read from a TOML file (`advent.toml` at the root of the crate, or the file
+//! given by the `ADVENT_CONFIG` environment variable), and each of them can be overridden by an
+//! environment variable:
+//!
+//! ```toml
+//! session = "53616c74..."              # ADVENT_SESSION
+//! base_url = "https://adventofcode.com" # ADVENT_BASE_URL
+//! data_dir = "/home/me/.advent"         # ADVENT_DATA_DIR
+//! min_interval = 5.0                    # minimum time between two requests, in seconds
+//! ```
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::{utils, Error, Result};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    data_dir: Option<PathBuf>,
+    min_interval: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in user
+    pub session: Option<String>,
+    /// URL of the website, without trailing slash
+    pub base_url: String,
+    /// Directory where downloaded inputs (and other local data) are stored
+    pub data_dir: PathBuf,
+    /// Minimum time between two requests to the website
+    pub min_interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            data_dir: Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    /// Path of the configuration file
+    pub fn default_path() -> PathBuf {
+        match env::var_os("ADVENT_CONFIG") {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).join("advent.toml"),
+        }
+    }
+
+    /// Reads the configuration file (if it exists) and applies the environment variables
+    pub fn load() -> Result<Self> {
+        let path = Self::default_path();
+        let mut config = if path.exists() {
+            Self::parse(&utils::read_input(&path)?)?
+        } else {
+            Self::default()
+        };
+        if let Ok(session) = env::var("ADVENT_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("ADVENT_BASE_URL") {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+        if let Some(data_dir) = env::var_os("ADVENT_DATA_DIR") {
+            config.data_dir = PathBuf::from(data_dir);
+        }
+        Ok(config)
+    }
+
+    /// Reads the settings from the contents of a configuration file (missing settings keep their
+    /// default value)
+    pub fn parse(input: &str) -> Result<Self> {
+        let file: ConfigFile = toml::from_str(input).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            Error::parse_at(input, offset, e.message())
+        })?;
+        let default = Self::default();
+        let min_interval = match file.min_interval {
+            // rejects negative, infinite or NaN values and values that do not fit in a duration
+            Some(seconds) => Duration::try_from_secs_f64(seconds)
+                .map_err(|_| Error::Config(format!("invalid min_interval: {}", seconds)))?,
+            None => default.min_interval,
+        };
+        Ok(Self {
+            session: file.session.map(|session| session.trim().to_string()),
+            base_url: file.base_url.map_or(default.base_url, |url| url.trim_end_matches('/').to_string()),
+            data_dir: file.data_dir.unwrap_or(default.data_dir),
+            min_interval,
+        })
+    }
+
+    /// Returns the session token, or an error explaining how to set it
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            Error::Config("no session token (set ADVENT_SESSION or `session` in advent.toml)".to_string())
+        })
+    }
+
+    /// Path where the downloaded input of a day is stored
+    pub fn input_cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.data_dir.join(format!("inputs/{}/day{:02}.txt", year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config =
+            Config::parse("session = \"abc\\n\"\nbase_url = \"http://localhost:8080/\"\nmin_interval = 0.5\n").unwrap();
+        assert_eq!(config.session().unwrap(), "abc");
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.min_interval, Duration::from_millis(500));
+        assert_eq!(config.data_dir, Config::default().data_dir);
+        assert!(matches!(Config::parse("sesion = \"abc\""), Err(Error::Parse { line: 1, .. })));
+        assert!(matches!(Config::default().session(), Err(Error::Config(_))));
+        for min_interval in ["-1.0", "nan", "inf", "1e30"] {
+            let input = format!("min_interval = {}", min_interval);
+            assert!(matches!(Config::parse(&input), Err(Error::Config(_))), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_input_cache_path() {
+        let config = Config { data_dir: PathBuf::from("/tmp/advent"), ..Config::default() };
+        assert_eq!(config.input_cache_path(2022, 5), PathBuf::from("/tmp/advent/inputs/2022/day05.txt"));
+    }
+}
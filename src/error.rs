@@ -0,0 +1,140 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Errors raised when reading inputs or running solvers
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written
+    Io { path: PathBuf, source: io::Error },
+    /// The input does not have the expected format (line and column start at 1)
+    Parse { line: usize, column: usize, message: String },
+    /// The input is valid but the puzzle has no solution
+    NoSolution(String),
+    /// The solver failed unexpectedly (for instance it panicked)
+    Solver(String),
+    /// A setting is missing or invalid
+    Config(String),
+    /// A request to the website failed
+    Http { url: String, message: String },
+    /// An answer was not submitted because it is known to be wrong
+    Refused(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io { path: path.into(), source }
+    }
+
+    /// Makes a parse error located at the given byte offset of the input (moved back to the
+    /// start of the character if it falls inside one)
+    pub fn parse_at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        Error::Parse { line, column, message: message.into() }
+    }
+
+    /// Makes a parse error located at the start of `remaining`, which must be a suffix of `input`
+    /// (typically what was left unparsed by a nom parser)
+    pub fn parse_remaining(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        Self::parse_at(input, input.len().saturating_sub(remaining.len()), message)
+    }
+
+    /// Makes a parse error located at the start of `part`, which must be a slice of `input` (like
+    /// a line or a word of the input)
+    pub fn parse_in(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        let offset = if input.is_char_boundary(offset) { offset } else { 0 };
+        Self::parse_at(input, offset, message)
+    }
+
+    /// Converts the error of a nom parser that was run on `input` (or on a slice of `input`)
+    pub fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => Self::parse_at(input, input.len(), "unexpected end of input"),
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::parse_in(input, e.input, format!("{:?} failed", e.code)),
+        }
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Error::NoSolution(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Parse { line, column, message } => {
+                write!(f, "parse error at line {}, column {}: {}", line, column, message)
+            }
+            Error::NoSolution(message) => write!(f, "no solution found: {}", message),
+            Error::Solver(message) => write!(f, "solver failed: {}", message),
+            Error::Config(message) => write!(f, "configuration error: {}", message),
+            Error::Http { url, message } => write!(f, "{}: {}", url, message),
+            Error::Refused(message) => write!(f, "answer not submitted: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "abc\ndéf\nghi";
+        match Error::parse_at(input, 0, "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (1, 1)),
+            _ => unreachable!(),
+        }
+        match Error::parse_remaining(input, "f\nghi", "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (2, 3)),
+            _ => unreachable!(),
+        }
+        match Error::parse_at(input, input.len(), "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (3, 4)),
+            _ => unreachable!(),
+        }
+        let word = input.lines().nth(2).unwrap();
+        match Error::parse_in(input, &word[1..], "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (3, 2)),
+            _ => unreachable!(),
+        }
+        // offset inside the 'é'
+        match Error::parse_at(input, 6, "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (2, 2)),
+            _ => unreachable!(),
+        }
+        // `remaining` is not a suffix of `input`
+        match Error::parse_remaining("ab", "abcd", "") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (1, 1)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let error = Error::parse_remaining("1,2\n3,x", "x", "expected a number");
+        assert_eq!(error.to_string(), "parse error at line 2, column 3: expected a number");
+        let error = Error::no_solution("no pair sums to 2020");
+        assert_eq!(error.to_string(), "no solution found: no pair sums to 2020");
+    }
+}
@@ -0,0 +1,115 @@
+//! Example inputs of the puzzles
+//!
+//! The examples given in a puzzle statement are stored next to the puzzle input, in
+//! `example.txt` for the first one and `example2.txt`, `example3.txt`, etc. for the next ones.
+//! Their expected answers are listed in the answers file (see [`Answers`]).
+//!
+//! The solvers of a day are checked against its examples by adding `example_tests!(year, day)`
+//! to the tests of the day.
+
+use crate::answer::{Answer, Answers};
+use crate::solution::registry;
+use crate::{utils, Result};
+
+/// An example input of a puzzle, with its expected answers
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// Number of the example (starting from 1)
+    pub number: u32,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    /// Expected answer of the given part, if known
+    pub fn answer(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Loads all example inputs of a day, along with their expected answers
+///
+/// Examples are read in order until the next example file is missing.
+pub fn load_examples(year: u32, day: u32, answers: &Answers) -> Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    for number in 1.. {
+        if !utils::example_path(year, day, number).exists() {
+            break;
+        }
+        examples.push(Example {
+            number,
+            input: utils::read_example(year, day, number)?,
+            part1: answers.example(year, day, number, 1).cloned(),
+            part2: answers.example(year, day, number, 2).cloned(),
+        });
+    }
+    Ok(examples)
+}
+
+/// Runs the registered solvers of a day on its examples and checks the answers
+///
+/// Panics if an answer is wrong, or if the day has no example with an expected answer. This is
+/// meant to be called from tests (see [`example_tests!`](crate::example_tests)).
+pub fn check_examples(year: u32, day: u32) {
+    let answers = Answers::load(Answers::default_path()).unwrap();
+    let examples = load_examples(year, day, &answers).unwrap();
+    let mut checked = 0;
+    for example in &examples {
+        for entry in registry().day(year, day) {
+            let Some(expected) = example.answer(entry.part) else {
+                continue;
+            };
+            let answer = entry.solve(&example.input).unwrap_or_else(|error| {
+                panic!("{} day {} example {} part {}: {}", year, day, example.number, entry.part, error)
+            });
+            assert_eq!(
+                &answer, expected,
+                "{} day {} example {} part {}",
+                year, day, example.number, entry.part
+            );
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no example answers for {} day {}", year, day);
+}
+
+/// Generates a test running the solvers of a day on its examples
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     crate::example_tests!(2022, 10);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($year:expr, $day:expr) => {
+        #[test]
+        fn test_examples() {
+            $crate::example::check_examples($year, $day);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_examples() {
+        let answers = Answers::parse("[[2023.17.examples]]\npart1 = 102\n").unwrap();
+        let examples = load_examples(2023, 17, &answers).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].answer(1), Some(&Answer::from(102)));
+        assert_eq!(examples[1].number, 2);
+        assert_eq!(examples[1].answer(1), None);
+        assert!(load_examples(2020, 1, &answers).unwrap().is_empty());
+    }
+}
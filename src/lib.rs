@@ -1,5 +1,21 @@
+pub mod answer;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod example;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
 pub mod utils;
+#[cfg(feature = "y2020")]
 pub mod year2020;
+#[cfg(feature = "y2022")]
 pub mod year2022;
+#[cfg(feature = "y2023")]
 pub mod year2023;
-pub mod year2024;
\ No newline at end of file
+#[cfg(feature = "y2024")]
+pub mod year2024;
+
+pub use error::{Error, Result};
@@ -1,3 +1,560 @@
-fn main() {
-    println!("Solution: {}", advent::year2023::day22::solve1());
+use std::fs;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use advent::answer::{Answer, Answers};
+use advent::client::Client;
+use advent::config::Config;
+use advent::runner::{self, Outcome};
+use advent::submit::{self, Verdict};
+use advent::{bench, scaffold};
+use advent::solution::{registry, Cost, Entry};
+use advent::{utils, Error};
+
+#[derive(Parser)]
+#[command(name = "advent", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of a day, of a whole year, or of all years
+    Run(RunArgs),
+    /// List the implemented days and parts
+    List,
+    /// Check the solvers against the expected answers
+    Verify(VerifyArgs),
+    /// Measure the running time of the solvers
+    Bench(BenchArgs),
+    /// Create the module of a new day from the template of its year
+    New(NewArgs),
+    /// Download the input of a puzzle (kept in a local cache)
+    Fetch(FetchArgs),
+    /// Compute the answer to a part of a puzzle and submit it
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year of the puzzle
+    #[arg(value_name = "YEAR", conflicts_with = "year")]
+    year_arg: Option<u32>,
+    /// Day of the puzzle (all days of the year if omitted)
+    day: Option<u32>,
+    /// Run all days of a year
+    #[arg(long)]
+    year: Option<u32>,
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Read the puzzle input from the given file
+    #[arg(long, value_name = "PATH")]
+    input: Option<String>,
+    /// Run on the given example input of the day instead of the puzzle input
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with = "input",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    example: Option<u32>,
+    /// Run the solvers of all years in parallel and print a summary
+    #[arg(long, conflicts_with_all = ["year_arg", "day", "year", "input", "example"])]
+    all: bool,
+    /// Number of solvers run at the same time with --all (defaults to the number of CPUs)
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+    /// Abandon the solvers still running after the given time with --all (e.g. 30s, 500ms)
+    #[arg(long, value_parser = runner::parse_duration)]
+    timeout: Option<Duration>,
+    /// Skip the slow solvers with --all
+    #[arg(long)]
+    fast: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only check the puzzles of the given year
+    #[arg(long)]
+    year: Option<u32>,
+    /// Only check the puzzles of the given day
+    #[arg(long, requires = "year")]
+    day: Option<u32>,
+    /// Read the expected answers from the given file
+    #[arg(long, value_name = "PATH")]
+    answers: Option<String>,
+    /// Skip the slow solvers
+    #[arg(long)]
+    fast: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only measure the puzzles of the given year
+    #[arg(long)]
+    year: Option<u32>,
+    /// Only measure the puzzles of the given day
+    #[arg(long, requires = "year")]
+    day: Option<u32>,
+    /// Only measure the given part
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Number of runs of each solver
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Compare the median times with the timings saved in the given JSON file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<String>,
+    /// Relative slowdown (in percent) of the median time considered a regression
+    #[arg(long, default_value_t = 20.0)]
+    tolerance: f64,
+    /// Save the timings as JSON to the given file (can be used as a baseline later)
+    #[arg(long, value_name = "PATH")]
+    save: Option<String>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Year of the puzzle
+    #[arg(value_parser = clap::value_parser!(u32).range(2015..))]
+    year: u32,
+    /// Day of the puzzle
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Year of the puzzle
+    #[arg(value_parser = clap::value_parser!(u32).range(2015..))]
+    year: u32,
+    /// Day of the puzzle
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// Download the input again even if it is already in the cache
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Year of the puzzle
+    year: u32,
+    /// Day of the puzzle
+    day: u32,
+    /// Part of the puzzle
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+}
+
+type CliResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+fn print_answer(entry: &Entry, answer: &Answer, elapsed: f64) {
+    let header = format!("{} day {:02} part {}", entry.year, entry.day, entry.part);
+    let stub = if entry.stub { " [stub]" } else { "" };
+    if answer.is_multiline() {
+        println!("{}:{} ({:.1}ms)", header, stub, elapsed);
+        for line in answer.to_string().lines() {
+            println!("    {}", line);
+        }
+    } else {
+        println!("{}: {}{} ({:.1}ms)", header, answer, stub, elapsed);
+    }
+}
+
+/// Where to read the input of a solver from
+#[derive(Clone, Copy)]
+enum InputSource<'a> {
+    Puzzle,
+    File(&'a str),
+    Example(u32),
+}
+
+fn read_entry_input(entry: &Entry, source: InputSource) -> advent::Result<String> {
+    match source {
+        InputSource::Puzzle => utils::read_input(entry.input_path()),
+        InputSource::File(path) => utils::read_input(path),
+        InputSource::Example(n) => utils::read_input(entry.example_path(n)),
+    }
+}
+
+fn run_entry(entry: &Entry, source: InputSource) -> CliResult<()> {
+    let input = read_entry_input(entry, source)?;
+    let start = Instant::now();
+    let answer = entry.solve(&input)?;
+    let elapsed = start.elapsed().as_secs_f64() * 1000.0;
+    print_answer(entry, &answer, elapsed);
+    Ok(())
+}
+
+/// Short description of the outcome of a solver, for the summary of `run --all`
+fn outcome_cell(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Solved { answer, elapsed } => {
+            let mut text = match answer {
+                Answer::Lines(lines) => format!("<{} lines>", lines.len()),
+                answer => answer.to_string(),
+            };
+            if text.chars().count() > 18 {
+                text = text.chars().take(17).collect::<String>() + "…";
+            }
+            format!("{} ({})", text, bench::format_time(*elapsed))
+        }
+        Outcome::Failed(_) => "FAILED".to_string(),
+        Outcome::TimedOut => "TIMEOUT".to_string(),
+    }
+}
+
+fn run_all(args: RunArgs) -> CliResult<()> {
+    let entries: Vec<&'static Entry> = registry()
+        .iter()
+        .filter(|entry| {
+            !entry.stub
+                && (!args.fast || entry.cost == Cost::Fast)
+                && args.part.is_none_or(|part| part == entry.part)
+        })
+        .collect();
+    let jobs = match args.jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    let total = entries.len();
+    let mut done = 0;
+    let start = Instant::now();
+    let read_input = |entry: &Entry| read_entry_input(entry, InputSource::Puzzle);
+    let runs = runner::run_all(entries, read_input, jobs, args.timeout, |run| {
+        done += 1;
+        eprintln!(
+            "[{}/{}] {} day {:02} part {}: {}",
+            done,
+            total,
+            run.entry.year,
+            run.entry.day,
+            run.entry.part,
+            outcome_cell(&run.outcome)
+        );
+    });
+    let elapsed = start.elapsed().as_secs_f64();
+
+    println!("{:<12} {:<30} {:<30}", "puzzle", "part 1", "part 2");
+    let mut i = 0;
+    while i < runs.len() {
+        let (year, day) = (runs[i].entry.year, runs[i].entry.day);
+        let mut cells = [String::from("-"), String::from("-")];
+        while i < runs.len() && (runs[i].entry.year, runs[i].entry.day) == (year, day) {
+            cells[runs[i].entry.part as usize - 1] = outcome_cell(&runs[i].outcome);
+            i += 1;
+        }
+        println!("{:<12} {:<30} {:<30}", format!("{} day {:02}", year, day), cells[0], cells[1]);
+    }
+
+    let (mut solved, mut failed, mut timed_out) = (0, 0, 0);
+    for run in &runs {
+        match &run.outcome {
+            Outcome::Solved { .. } => solved += 1,
+            Outcome::Failed(error) => {
+                if failed == 0 {
+                    println!();
+                }
+                println!("{} day {:02} part {}: {}", run.entry.year, run.entry.day, run.entry.part, error);
+                failed += 1;
+            }
+            Outcome::TimedOut => timed_out += 1,
+        }
+    }
+    println!();
+    println!("{} solved, {} failed, {} timed out ({:.1}s)", solved, failed, timed_out, elapsed);
+    if failed + timed_out > 0 {
+        return Err(format!("{} solver(s) failed, {} timed out", failed, timed_out).into());
+    }
+    Ok(())
+}
+
+fn run(args: RunArgs) -> CliResult<()> {
+    if args.all {
+        return run_all(args);
+    }
+    if args.jobs.is_some() || args.timeout.is_some() || args.fast {
+        return Err("--jobs, --timeout and --fast require --all".into());
+    }
+    let registry = registry();
+    let year = args.year_arg.or(args.year).ok_or("no year given")?;
+
+    let entries: Vec<&Entry> = match args.day {
+        Some(day) => registry.day(year, day).collect(),
+        None => {
+            if args.part.is_some() {
+                return Err("--part requires a day".into());
+            }
+            if args.input.is_some() {
+                return Err("--input requires a day".into());
+            }
+            if args.example.is_some() {
+                return Err("--example requires a day".into());
+            }
+            registry.year(year).collect()
+        }
+    };
+    if entries.is_empty() {
+        return Err(match args.day {
+            Some(day) => format!("no solver for {} day {}", year, day),
+            None => format!("no solver for year {}", year),
+        }
+        .into());
+    }
+
+    let source = match (&args.input, args.example) {
+        (Some(path), _) => InputSource::File(path),
+        (None, Some(n)) => InputSource::Example(n),
+        (None, None) => InputSource::Puzzle,
+    };
+    for entry in entries {
+        if args.part.is_some_and(|part| part != entry.part) {
+            continue;
+        }
+        if entry.stub && args.day.is_none() {
+            // placeholders are only run when explicitly requested
+            println!("{} day {:02} part {}: [stub]", entry.year, entry.day, entry.part);
+            continue;
+        }
+        run_entry(entry, source)?;
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> CliResult<()> {
+    let answers = match &args.answers {
+        Some(path) => Answers::load(path)?,
+        None => Answers::load(Answers::default_path())?,
+    };
+    let entries = registry().iter().filter(|entry| {
+        args.year.is_none_or(|year| year == entry.year)
+            && args.day.is_none_or(|day| day == entry.day)
+            && (!args.fast || entry.cost == Cost::Fast)
+    });
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for entry in entries {
+        let header = format!("{} day {:02} part {}", entry.year, entry.day, entry.part);
+        let Some(expected) = answers.get(entry.year, entry.day, entry.part) else {
+            println!("{}: missing", header);
+            missing += 1;
+            continue;
+        };
+        let result = read_entry_input(entry, InputSource::Puzzle).and_then(|input| entry.solve(&input));
+        match result {
+            Ok(answer) if &answer == expected => {
+                println!("{}: ok", header);
+                passed += 1;
+            }
+            Ok(answer) => {
+                if expected.is_multiline() || answer.is_multiline() {
+                    println!("{}: FAIL\nexpected:\n{}\ngot:\n{}", header, expected, answer);
+                } else {
+                    println!("{}: FAIL (expected {}, got {})", header, expected, answer);
+                }
+                failed += 1;
+            }
+            Err(error) => {
+                println!("{}: FAIL ({})", header, error);
+                failed += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        return Err(format!("{} puzzle(s) failed", failed).into());
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> CliResult<()> {
+    let baseline = match &args.baseline {
+        Some(path) => bench::load_timings(path)?,
+        None => Vec::new(),
+    };
+    let entries = registry().iter().filter(|entry| {
+        !entry.stub
+            && args.year.is_none_or(|year| year == entry.year)
+            && args.day.is_none_or(|day| day == entry.day)
+            && args.part.is_none_or(|part| part == entry.part)
+    });
+
+    let mut timings = Vec::new();
+    let mut failures = 0;
+    for entry in entries {
+        let timing = read_entry_input(entry, InputSource::Puzzle)
+            .and_then(|input| bench::bench(entry, &input, args.runs as usize));
+        match timing {
+            Ok(timing) => timings.push(timing),
+            Err(error) => {
+                // the other solvers are still measured
+                eprintln!("{} day {:02} part {}: {}", entry.year, entry.day, entry.part, error);
+                failures += 1;
+            }
+        }
+    }
+
+    let reports: Vec<bench::Report> = timings
+        .iter()
+        .map(|timing| bench::Report::new(timing.clone(), &baseline, args.tolerance))
+        .collect();
+    let regressions = reports.iter().filter(|report| report.regression).count();
+
+    match args.format {
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+        }
+        Format::Table => {
+            println!(
+                "{:<20} {:>5} {:>10} {:>10} {:>10} {:>8}",
+                "puzzle", "runs", "min", "median", "max", "change"
+            );
+            for report in &reports {
+                let change = match report.change {
+                    Some(change) if report.regression => format!("{:+.0}% REGRESSION", change),
+                    Some(change) => format!("{:+.0}%", change),
+                    None => String::new(),
+                };
+                let timing = &report.timing;
+                println!(
+                    "{:<20} {:>5} {:>10} {:>10} {:>10} {:>8}",
+                    format!("{} day {:02} part {}", timing.year, timing.day, timing.part),
+                    timing.runs,
+                    bench::format_time(timing.min),
+                    bench::format_time(timing.median),
+                    bench::format_time(timing.max),
+                    change,
+                );
+            }
+        }
+    }
+
+    if let Some(path) = &args.save {
+        let json = serde_json::to_string_pretty(&timings).unwrap();
+        fs::write(path, json + "\n").map_err(|e| Error::io(path, e))?;
+    }
+    if failures > 0 {
+        return Err(format!("{} solver(s) could not be measured", failures).into());
+    }
+    if regressions > 0 {
+        return Err(format!("{} solver(s) slower than the baseline", regressions).into());
+    }
+    Ok(())
+}
+
+fn new(args: NewArgs) -> CliResult<()> {
+    let src = scaffold::src_dir();
+    let root = src.parent().unwrap_or(&src);
+    for path in scaffold::new_day(&src, args.year, args.day)? {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    println!(
+        "Rebuild to run the new solvers, and download the puzzle input with `advent fetch {} {}`",
+        args.year, args.day
+    );
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> CliResult<()> {
+    let client = Client::new(Config::load()?);
+    let module_path = utils::module_input_path(args.year, args.day);
+    if module_path.exists() {
+        println!("note: {} takes precedence over the downloaded input", module_path.display());
+    }
+    let path = client.fetch_input(args.year, args.day, args.force)?;
+    println!("{}", path.display());
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> CliResult<()> {
+    let entry = registry()
+        .get(args.year, args.day, args.part)
+        .ok_or_else(|| format!("no solver for {} day {} part {}", args.year, args.day, args.part))?;
+    if entry.stub {
+        return Err("the solver of this part is a stub".into());
+    }
+    let input = read_entry_input(entry, InputSource::Puzzle)?;
+    let answer = entry.solve(&input)?;
+    println!("{} day {:02} part {}: submitting {}", entry.year, entry.day, entry.part, answer);
+
+    let client = Client::new(Config::load()?);
+    let verdict = submit::submit(&client, args.year, args.day, args.part, &answer)?;
+    println!("{}", verdict);
+    if verdict != Verdict::Correct {
+        return Err("the answer was not accepted".into());
+    }
+    Ok(())
+}
+
+fn list() {
+    let registry = registry();
+    let mut current_year = None;
+    let mut current_day = None;
+    for entry in registry.iter() {
+        if current_year != Some(entry.year) {
+            if current_year.is_some() {
+                println!();
+            }
+            println!("{}", entry.year);
+            current_year = Some(entry.year);
+            current_day = None;
+        }
+        if current_day != Some(entry.day) {
+            if current_day.is_some() {
+                println!();
+            }
+            print!("  day {:02}:", entry.day);
+            current_day = Some(entry.day);
+        }
+        let note = match (entry.stub, entry.cost) {
+            (true, _) => " (stub)",
+            (false, Cost::Slow) => " (slow)",
+            (false, Cost::Fast) => "",
+        };
+        print!("  part {}{}", entry.part, note);
+    }
+    if current_day.is_some() {
+        println!();
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
 }
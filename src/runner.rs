@@ -0,0 +1,163 @@
+//! Parallel execution of many solvers, with a time limit for each of them
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::solution::Entry;
+use crate::{Error, Result};
+
+/// Result of running a solver
+#[derive(Debug)]
+pub enum Outcome {
+    /// The solver returned an answer (time in milliseconds)
+    Solved { answer: Answer, elapsed: f64 },
+    /// The input could not be read, or the solver failed
+    Failed(Error),
+    /// The solver did not finish in time (it is abandoned and keeps running in the background)
+    TimedOut,
+}
+
+/// A solver that was run, and its outcome
+pub struct Run {
+    pub entry: &'static Entry,
+    pub outcome: Outcome,
+}
+
+/// Runs a solver in its own thread and waits for its answer for at most `timeout`
+///
+/// Threads cannot be killed, so a solver that times out is left running: its thread ends when the
+/// solver returns, or when the program exits.
+fn run_with_timeout(
+    entry: &'static Entry,
+    read_input: fn(&Entry) -> Result<String>,
+    timeout: Option<Duration>,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let outcome = read_input(entry).and_then(|input| {
+            let start = Instant::now();
+            let answer = entry.solve(&input)?;
+            Ok((answer, start.elapsed().as_secs_f64() * 1000.0))
+        });
+        // the receiver is gone if the solver timed out
+        let _ = sender.send(outcome);
+    });
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+    match received {
+        Some(Ok((answer, elapsed))) => Outcome::Solved { answer, elapsed },
+        Some(Err(error)) => Outcome::Failed(error),
+        None => Outcome::TimedOut,
+    }
+}
+
+/// Runs solvers on a pool of `jobs` worker threads
+///
+/// The input of each solver is given by `read_input`. `on_done` is called as soon as a solver
+/// finishes (in completion order), and the runs are returned in the order of `entries`.
+pub fn run_all<F>(
+    entries: Vec<&'static Entry>,
+    read_input: fn(&Entry) -> Result<String>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut on_done: F,
+) -> Vec<Run>
+where
+    F: FnMut(&Run),
+{
+    let entries = Arc::new(entries);
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    let workers: Vec<_> = (0..jobs.max(1))
+        .map(|_| {
+            let entries = Arc::clone(&entries);
+            let next = Arc::clone(&next);
+            let sender = sender.clone();
+            thread::spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(&entry) = entries.get(index) else {
+                    break;
+                };
+                let outcome = run_with_timeout(entry, read_input, timeout);
+                if sender.send((index, Run { entry, outcome })).is_err() {
+                    break;
+                }
+            })
+        })
+        .collect();
+    drop(sender);
+
+    let mut runs: Vec<Option<Run>> = (0..entries.len()).map(|_| None).collect();
+    for (index, run) in receiver {
+        on_done(&run);
+        runs[index] = Some(run);
+    }
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    runs.into_iter().map(|run| run.unwrap()).collect()
+}
+
+/// Parses a duration such as `30s`, `500ms` or `2m` (a plain number is a number of seconds)
+pub fn parse_duration(s: &str) -> std::result::Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().map_err(|_| format!("invalid duration: {}", s))?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("invalid duration unit: {}", unit)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration out of range: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Registry;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration(&format!("{}s", "9".repeat(40))).is_err());
+        assert!(parse_duration("1e30s").is_err());
+    }
+
+    #[test]
+    fn test_run_all() {
+        let mut registry = Registry::new();
+        registry.add_part(1, 1, 1, |input: &str| input.len());
+        registry.add_part(1, 1, 2, |_: &str| -> u32 { panic!("failure") });
+        registry.add_part(1, 2, 1, |_: &str| {
+            thread::sleep(Duration::from_secs(5));
+            0
+        });
+        let registry: &'static Registry = Box::leak(Box::new(registry));
+
+        let mut done = 0;
+        let runs = run_all(
+            registry.iter().collect(),
+            |_| Ok("abc".to_string()),
+            2,
+            Some(Duration::from_millis(200)),
+            |_| done += 1,
+        );
+        assert_eq!(done, 3);
+        assert!(matches!(&runs[0].outcome, Outcome::Solved { answer: Answer::Int(3), .. }));
+        assert!(matches!(&runs[1].outcome, Outcome::Failed(Error::Solver(_))));
+        assert!(matches!(&runs[2].outcome, Outcome::TimedOut));
+        assert_eq!((runs[2].entry.day, runs[2].entry.part), (2, 1));
+    }
+}
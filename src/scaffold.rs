@@ -0,0 +1,331 @@
+//! Generation of the modules of new days (and years) from the `dayXX` templates
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::{Error, Result};
+
+/// Path of the source directory of the crate
+pub fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+    }
+    fs::write(path, contents).map_err(|e| Error::io(path, e))
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::io(path, e))
+}
+
+/// Finds the template of a day module: the `dayXX` template of the year, or else the one of the
+/// most recent year that has a template
+fn find_template(src: &Path, year: u32) -> Result<PathBuf> {
+    let own = src.join(format!("year{}/dayXX/mod.rs", year));
+    if own.exists() {
+        return Ok(own);
+    }
+    let year_dir = Regex::new(r"^year(\d+)$").unwrap();
+    let mut years: Vec<u32> = fs::read_dir(src)
+        .map_err(|e| Error::io(src, e))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            year_dir.captures(&name)?[1].parse().ok()
+        })
+        .collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years
+        .into_iter()
+        .map(|y| src.join(format!("year{}/dayXX/mod.rs", y)))
+        .find(|path| path.exists())
+        .ok_or_else(|| Error::io(src, io::Error::new(io::ErrorKind::NotFound, "no dayXX template found")))
+}
+
+/// Replaces the `YEAR` and `DAY` placeholders of a template
+fn instantiate(template: &str, year: u32, day: u32) -> String {
+    let year_re = Regex::new(r"\bYEAR\b").unwrap();
+    let day_re = Regex::new(r"\bDAY\b").unwrap();
+    let source = year_re.replace_all(template, year.to_string());
+    day_re.replace_all(&source, day.to_string()).into_owned()
+}
+
+/// Adds a `pub mod <name>;` declaration to a source file
+///
+/// The declaration is inserted so that the module declarations stay sorted, or replaces a
+/// commented out declaration of the same module. If the file declares no module, it is inserted
+/// before the first function.
+fn declare_module(source: &str, name: &str) -> Result<String> {
+    let declaration = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Ok(source.to_string());
+    }
+    let commented = format!("// {}", declaration);
+    if let Some(i) = lines.iter().position(|line| *line == commented) {
+        lines[i] = &declaration;
+    } else if let Some(last) = lines.iter().rposition(|line| line.starts_with("pub mod ")) {
+        let i = lines[..=last]
+            .iter()
+            .position(|line| line.starts_with("pub mod ") && line[8..] > declaration[8..])
+            .unwrap_or(last + 1);
+        lines.insert(i, &declaration);
+    } else {
+        let i = lines
+            .iter()
+            .position(|line| line.starts_with("pub fn "))
+            .ok_or_else(|| Error::parse_at(source, source.len(), "no function to declare the module before"))?;
+        lines.splice(i..i, [declaration.as_str(), ""]);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the registration of both parts of a day to the `register` function of a year module,
+/// keeping the registrations sorted by day
+fn register_day(source: &str, year: u32, day: u32) -> Result<String> {
+    let registration = Regex::new(r"^\s*registry\.\w+\(\d+, (\d+),").unwrap();
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub fn register("))
+        .ok_or_else(|| Error::parse_at(source, source.len(), "no register function"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or_else(|| Error::parse_at(source, source.len(), "unterminated register function"))?;
+    let mut i = end;
+    for (j, line) in lines.iter().enumerate().take(end).skip(start) {
+        if let Some(captures) = registration.captures(line) {
+            let registered: u32 = captures[1].parse().unwrap();
+            if registered == day {
+                return Ok(source.to_string());
+            }
+            if registered > day {
+                i = j;
+                break;
+            }
+        }
+    }
+    let line = format!("    registry.add({year}, {day}, day{day:02}::part1, day{day:02}::part2);");
+    lines.insert(i, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Finds where to insert `line` among the lines matching `is_item` so that they stay sorted
+///
+/// Returns `None` if no line matches. The attributes (`#[...]`) preceding an item are kept
+/// with it.
+fn sorted_position(lines: &[String], line: &str, is_item: impl Fn(&str) -> bool) -> Option<usize> {
+    let last = lines.iter().rposition(|l| is_item(l))?;
+    let mut i = lines[..=last]
+        .iter()
+        .position(|l| is_item(l) && l.as_str() > line)
+        .unwrap_or(last + 1);
+    if i <= last {
+        while i > 0 && lines[i - 1].trim_start().starts_with("#[") {
+            i -= 1;
+        }
+    }
+    Some(i)
+}
+
+/// Adds the declaration of a year module to `lib.rs`, behind the feature of the year
+fn declare_year(source: &str, year: u32) -> Result<String> {
+    let declaration = format!("pub mod year{};", year);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    if lines.contains(&declaration) {
+        return Ok(source.to_string());
+    }
+    let i = sorted_position(&lines, &declaration, |line| line.starts_with("pub mod "))
+        .ok_or_else(|| Error::parse_at(source, source.len(), "no module declarations"))?;
+    lines.splice(i..i, [format!("#[cfg(feature = \"y{}\")]", year), declaration]);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a year to `Registry::all`, behind the feature of the year
+fn register_year(source: &str, year: u32) -> Result<String> {
+    let call = format!("        crate::year{}::register(&mut registry);", year);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    if lines.contains(&call) {
+        return Ok(source.to_string());
+    }
+    let i = sorted_position(&lines, &call, |line| line.ends_with("::register(&mut registry);"))
+        .ok_or_else(|| Error::parse_at(source, source.len(), "no call to the register functions"))?;
+    lines.splice(i..i, [format!("        #[cfg(feature = \"y{}\")]", year), call]);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the feature of a year to `Cargo.toml` (enabled by default)
+fn add_year_feature(source: &str, year: u32) -> Result<String> {
+    let feature = format!("y{} = []", year);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    if lines.contains(&feature) {
+        return Ok(source.to_string());
+    }
+    let default = lines
+        .iter()
+        .position(|line| line.starts_with("default = ["))
+        .ok_or_else(|| Error::parse_at(source, source.len(), "no default features"))?;
+    let mut features: Vec<String> = lines[default]["default = [".len()..]
+        .trim_end_matches(']')
+        .split(',')
+        .map(|feature| feature.trim().to_string())
+        .filter(|feature| !feature.is_empty())
+        .collect();
+    features.push(format!("\"y{}\"", year));
+    features.sort_unstable();
+    lines[default] = format!("default = [{}]", features.join(", "));
+
+    let year_feature = Regex::new(r"^y\d+ = \[\]$").unwrap();
+    let i = sorted_position(&lines, &feature, |line| year_feature.is_match(line)).unwrap_or(default + 1);
+    lines.insert(i, feature);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the module of a new day from the `dayXX` template, declares it and registers its
+/// solvers (creating the year module first if needed)
+///
+/// Returns the list of files that were created or modified.
+pub fn new_day(src: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    let year_dir = src.join(format!("year{}", year));
+    let day_path = year_dir.join(format!("day{:02}/mod.rs", day));
+    if day_path.exists() {
+        return Err(Error::io(
+            &day_path,
+            io::Error::new(io::ErrorKind::AlreadyExists, "day module already exists"),
+        ));
+    }
+    let template_path = find_template(src, year)?;
+    let template = read_file(&template_path)?;
+    let mut changed = Vec::new();
+
+    let year_path = year_dir.join("mod.rs");
+    if !year_path.exists() {
+        write_file(&year_path, "use crate::solution::Registry;\n\npub fn register(registry: &mut Registry) {\n}\n")?;
+        write_file(&year_dir.join("dayXX/mod.rs"), &template)?;
+        changed.push(year_path.clone());
+        changed.push(year_dir.join("dayXX/mod.rs"));
+
+        let lib_path = src.join("lib.rs");
+        let lib = read_file(&lib_path)?;
+        write_file(&lib_path, &declare_year(&lib, year)?)?;
+        changed.push(lib_path);
+
+        let solution_path = src.join("solution.rs");
+        let solution = read_file(&solution_path)?;
+        write_file(&solution_path, &register_year(&solution, year)?)?;
+        changed.push(solution_path);
+
+        let cargo_path = src.with_file_name("Cargo.toml");
+        let cargo = read_file(&cargo_path)?;
+        write_file(&cargo_path, &add_year_feature(&cargo, year)?)?;
+        changed.push(cargo_path);
+    }
+
+    write_file(&day_path, &instantiate(&template, year, day))?;
+    changed.insert(0, day_path);
+
+    let year_mod = read_file(&year_path)?;
+    let year_mod = declare_module(&year_mod, &format!("day{:02}", day))?;
+    write_file(&year_path, &register_day(&year_mod, year, day)?)?;
+    if !changed.contains(&year_path) {
+        changed.push(year_path);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MOD: &str = "use crate::solution::Registry;
+
+pub mod day01;
+pub mod day03;
+// pub mod day04;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2020, 1, day01::part1, day01::part2);
+    registry.add_part(2020, 3, 1, day03::part1);
+}
+";
+
+    #[test]
+    fn test_instantiate() {
+        let template = "part1(&utils::read_day_input(YEAR, DAY).unwrap())";
+        assert_eq!(instantiate(template, 2024, 7), "part1(&utils::read_day_input(2024, 7).unwrap())");
+    }
+
+    #[test]
+    fn test_declare_module() {
+        let source = declare_module(YEAR_MOD, "day02").unwrap();
+        assert!(source.contains("pub mod day01;\npub mod day02;\npub mod day03;\n// pub mod day04;\n"));
+        let source = declare_module(YEAR_MOD, "day04").unwrap();
+        assert!(source.contains("pub mod day03;\npub mod day04;\n\n"));
+        let source = declare_module(YEAR_MOD, "day05").unwrap();
+        assert!(source.contains("pub mod day03;\npub mod day05;\n// pub mod day04;\n"));
+        assert_eq!(declare_module(YEAR_MOD, "day01").unwrap(), YEAR_MOD);
+
+        let source = "use crate::solution::Registry;\n\npub fn register(registry: &mut Registry) {\n}\n";
+        let source = declare_module(source, "day01").unwrap();
+        assert!(source.contains("Registry;\n\npub mod day01;\n\npub fn register"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let source = register_day(YEAR_MOD, 2020, 2).unwrap();
+        assert!(source.contains(
+            "day01::part2);\n    registry.add(2020, 2, day02::part1, day02::part2);\n    registry.add_part(2020, 3"
+        ));
+        let source = register_day(YEAR_MOD, 2020, 12).unwrap();
+        assert!(source.ends_with("day03::part1);\n    registry.add(2020, 12, day12::part1, day12::part2);\n}\n"));
+        assert_eq!(register_day(YEAR_MOD, 2020, 3).unwrap(), YEAR_MOD);
+    }
+
+    #[test]
+    fn test_declare_year() {
+        let source = "pub mod utils;\n#[cfg(feature = \"y2020\")]\npub mod year2020;\n\
+                      #[cfg(feature = \"y2024\")]\npub mod year2024;\n\npub use error::Error;\n";
+        let source = declare_year(source, 2022).unwrap();
+        assert!(source.contains(
+            "pub mod year2020;\n#[cfg(feature = \"y2022\")]\npub mod year2022;\n#[cfg(feature = \"y2024\")]"
+        ));
+        let source = declare_year(&source, 2025).unwrap();
+        assert!(source.contains("pub mod year2024;\n#[cfg(feature = \"y2025\")]\npub mod year2025;\n\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let source = "    pub fn all() -> Self {
+        let mut registry = Self::new();
+        #[cfg(feature = \"y2020\")]
+        crate::year2020::register(&mut registry);
+        #[cfg(feature = \"y2024\")]
+        crate::year2024::register(&mut registry);
+        registry
+    }
+";
+        let source = register_year(source, 2022).unwrap();
+        assert!(source.contains(
+            "year2020::register(&mut registry);\n        #[cfg(feature = \"y2022\")]\n        crate::year2022::register"
+        ));
+        let source = register_year(&source, 2025).unwrap();
+        assert!(source.ends_with("crate::year2025::register(&mut registry);\n        registry\n    }\n"));
+    }
+
+    #[test]
+    fn test_add_year_feature() {
+        let source = "[features]\ndefault = [\"y2020\", \"y2024\"]\ny2020 = []\ny2024 = []\n\n[dependencies]\n";
+        let source = add_year_feature(source, 2025).unwrap();
+        assert_eq!(
+            source,
+            "[features]\ndefault = [\"y2020\", \"y2024\", \"y2025\"]\ny2020 = []\ny2024 = []\ny2025 = []\n\n[dependencies]\n"
+        );
+    }
+}
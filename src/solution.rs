@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use lazy_static::lazy_static;
+
+use crate::answer::{Answer, IntoAnswer};
+use crate::{utils, Error, Result};
+
+/// A solver for one part of a puzzle, taking the puzzle input as text
+pub trait Solution: Send + Sync {
+    fn solve(&self, input: &str) -> Result<Answer>;
+}
+
+impl<F, T> Solution for F
+where
+    F: Fn(&str) -> T + Send + Sync,
+    T: IntoAnswer,
+{
+    fn solve(&self, input: &str) -> Result<Answer> {
+        self(input).into_answer()
+    }
+}
+
+/// Running time tier of a solver
+///
+/// Slow solvers are left out of the default test run (their tests are only run with the `slow`
+/// feature) and can be skipped by the `run --all` and `verify` commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Cost {
+    /// Runs in well under a second, even in a debug build
+    #[default]
+    Fast,
+    /// Takes seconds (or minutes) in a debug build
+    Slow,
+}
+
+/// A registered solver, along with the puzzle it solves
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Whether the solver is only a placeholder that does not compute the answer yet
+    pub stub: bool,
+    pub cost: Cost,
+    solution: Box<dyn Solution>,
+}
+
+impl Entry {
+    /// Runs the solver on the given input
+    ///
+    /// Solvers report invalid inputs as errors. A panic of the solver is still caught as a last
+    /// resort and returned as an [`Error::Solver`].
+    pub fn solve(&self, input: &str) -> Result<Answer> {
+        panic::catch_unwind(AssertUnwindSafe(|| self.solution.solve(input))).unwrap_or_else(|payload| {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "solver panicked".to_string()
+            };
+            Err(Error::Solver(message))
+        })
+    }
+
+    /// Path of the default input file of the puzzle
+    pub fn input_path(&self) -> PathBuf {
+        utils::input_path(self.year, self.day)
+    }
+
+    /// Path of the `n`-th example input of the puzzle (see [`utils::example_path`])
+    pub fn example_path(&self, n: u32) -> PathBuf {
+        utils::example_path(self.year, self.day, n)
+    }
+}
+
+/// Collection of all solvers, keyed by (year, day, part)
+#[derive(Default)]
+pub struct Registry {
+    entries: BTreeMap<(u32, u32, u32), Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a registry containing the solvers of all years (enabled by the `yXXXX` features)
+    #[allow(unused_mut)]
+    pub fn all() -> Self {
+        let mut registry = Self::new();
+        #[cfg(feature = "y2020")]
+        crate::year2020::register(&mut registry);
+        #[cfg(feature = "y2022")]
+        crate::year2022::register(&mut registry);
+        #[cfg(feature = "y2023")]
+        crate::year2023::register(&mut registry);
+        #[cfg(feature = "y2024")]
+        crate::year2024::register(&mut registry);
+        registry
+    }
+
+    fn insert(&mut self, year: u32, day: u32, part: u32, stub: bool, solution: Box<dyn Solution>) {
+        let entry = Entry { year, day, part, stub, cost: Cost::Fast, solution };
+        if self.entries.insert((year, day, part), entry).is_some() {
+            panic!("Solver for {} day {} part {} registered twice", year, day, part);
+        }
+    }
+
+    /// Registers both parts of a day
+    pub fn add<S1, S2>(&mut self, year: u32, day: u32, part1: S1, part2: S2)
+    where
+        S1: Solution + 'static,
+        S2: Solution + 'static,
+    {
+        self.add_part(year, day, 1, part1);
+        self.add_part(year, day, 2, part2);
+    }
+
+    /// Registers a single part of a day
+    pub fn add_part<S: Solution + 'static>(&mut self, year: u32, day: u32, part: u32, solution: S) {
+        self.insert(year, day, part, false, Box::new(solution));
+    }
+
+    /// Registers a part that is not solved yet (the solver is only a placeholder)
+    pub fn add_stub<S: Solution + 'static>(&mut self, year: u32, day: u32, part: u32, solution: S) {
+        self.insert(year, day, part, true, Box::new(solution));
+    }
+
+    /// Marks a registered part as slow (see [`Cost`])
+    pub fn mark_slow(&mut self, year: u32, day: u32, part: u32) {
+        match self.entries.get_mut(&(year, day, part)) {
+            Some(entry) => entry.cost = Cost::Slow,
+            None => panic!("No solver for {} day {} part {}", year, day, part),
+        }
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&Entry> {
+        self.entries.get(&(year, day, part))
+    }
+
+    /// Iterates over all entries, ordered by year, day and part
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.values()
+    }
+
+    /// Iterates over the entries of a given year, ordered by day and part
+    pub fn year(&self, year: u32) -> impl Iterator<Item = &Entry> {
+        self.entries.range((year, 0, 0)..(year + 1, 0, 0)).map(|(_, entry)| entry)
+    }
+
+    /// Iterates over the entries of a given day (both parts)
+    pub fn day(&self, year: u32, day: u32) -> impl Iterator<Item = &Entry> {
+        self.entries.range((year, day, 0)..(year, day + 1, 0)).map(|(_, entry)| entry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+lazy_static! {
+    static ref REGISTRY: Registry = Registry::all();
+}
+
+/// Returns the registry of all solvers
+pub fn registry() -> &'static Registry {
+    &REGISTRY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let mut registry = Registry::new();
+        registry.add(2022, 1, |input: &str| input.len(), |_: &str| 0);
+        registry.add_stub(2022, 2, 1, |_: &str| 0);
+        registry.mark_slow(2022, 1, 2);
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get(2022, 1, 1).unwrap().cost, Cost::Fast);
+        assert_eq!(registry.get(2022, 1, 2).unwrap().cost, Cost::Slow);
+        assert_eq!(registry.day(2022, 1).count(), 2);
+        assert!(registry.get(2022, 2, 1).unwrap().stub);
+        assert_eq!(registry.get(2022, 1, 1).unwrap().solve("abc").unwrap(), Answer::Int(3));
+    }
+
+    #[test]
+    #[cfg(all(feature = "y2022", feature = "y2023", feature = "y2024"))]
+    fn test_registry() {
+        let registry = registry();
+        assert!(registry.get(2022, 1, 1).is_some());
+        assert!(registry.get(2023, 22, 2).is_some());
+        assert!(registry.get(2020, 11, 1).is_none());
+        assert!(registry.get(2023, 21, 2).unwrap().stub);
+        assert!(!registry.get(2023, 21, 1).unwrap().stub);
+        assert_eq!(registry.year(2022).count(), 50);
+        assert_eq!(registry.day(2024, 5).count(), 2);
+        assert_eq!(registry.get(2022, 16, 2).unwrap().cost, Cost::Slow);
+        assert_eq!(registry.get(2022, 16, 1).unwrap().cost, Cost::Fast);
+    }
+
+    #[test]
+    #[cfg(feature = "y2022")]
+    fn test_solve() {
+        let entry = registry().get(2022, 1, 1).unwrap();
+        assert_eq!(entry.solve("1000\n2000\n\n4000\n\n").unwrap(), Answer::Int(4000));
+        let input = utils::read_input(entry.input_path()).unwrap();
+        assert_eq!(entry.solve(&input).unwrap(), Answer::Int(66719));
+        assert!(matches!(entry.solve("1000\nabc\n\n"), Err(Error::Parse { line: 2, column: 1, .. })));
+    }
+}
@@ -0,0 +1,273 @@
+//! Submission of answers to the website, with a local history of the attempts
+//!
+//! Every attempt is recorded in `submissions.json` in the data directory. The history is used to
+//! avoid submitting answers that are known to be wrong: values that were already rejected, or
+//! that are not within the bounds given by previous "too high" and "too low" answers.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::client::Client;
+use crate::config::Config;
+use crate::{utils, Error, Result};
+
+/// Response of the website to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong answer, without indication
+    Wrong,
+    /// The answer was submitted too soon after the previous one (with the time left to wait)
+    Wait(String),
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+    /// The response was not understood (with the text of the response)
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page returned by the website
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let left = Regex::new(r"You have (.+?) left to wait").unwrap();
+            let time = left.captures(&text).map_or("", |c| c.get(1).unwrap().as_str());
+            Verdict::Wait(time.to_string())
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    /// Whether the answer is known to be wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "right answer"),
+            Verdict::TooHigh => write!(f, "wrong answer (too high)"),
+            Verdict::TooLow => write!(f, "wrong answer (too low)"),
+            Verdict::Wrong => write!(f, "wrong answer"),
+            Verdict::Wait(time) => write!(f, "answer submitted too soon, {} left to wait", time),
+            Verdict::WrongLevel => write!(f, "part already solved or not unlocked"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` element of a page (or of the whole page), without the tags
+fn article_text(html: &str) -> String {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let content = article.captures(html).map_or(html, |c| c.get(1).unwrap().as_str());
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = tags.replace_all(content, "");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// An answer submitted to the website
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Time of the submission (in seconds since the Unix epoch)
+    pub time: u64,
+}
+
+/// Value of an integer answer, for comparisons with the bounds
+fn integer_value(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Int(v) => Some(*v as i128),
+        Answer::UInt(v) => Some(*v as i128),
+        _ => None,
+    }
+}
+
+/// All the answers submitted so far
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Path of the history file in the data directory
+    pub fn path(config: &Config) -> PathBuf {
+        config.data_dir.join("submissions.json")
+    }
+
+    /// Reads the history file (an empty history is returned if the file does not exist)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+        let contents = utils::read_input(path)?;
+        serde_json::from_str(&contents).map_err(|e| Error::Parse {
+            line: e.line(),
+            column: e.column(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+        let json = serde_json::to_string_pretty(&self.attempts).unwrap();
+        fs::write(path, json + "\n").map_err(|e| Error::io(path, e))
+    }
+
+    /// Iterates over the attempts for a given part, in chronological order
+    pub fn attempts(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks that an answer is worth submitting: the part is not solved yet, the answer was not
+    /// rejected before, and it lies within the known bounds
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &Answer) -> Result<()> {
+        let value = integer_value(answer);
+        for attempt in self.attempts(year, day, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Error::Refused(format!("already solved with answer {}", attempt.answer)));
+            }
+            if attempt.verdict.is_wrong() && &attempt.answer == answer {
+                return Err(Error::Refused(format!("{} was already rejected", answer)));
+            }
+            let bound = integer_value(&attempt.answer);
+            match (&attempt.verdict, value, bound) {
+                (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    let message = format!("{} is too high ({} was too high)", answer, attempt.answer);
+                    return Err(Error::Refused(message));
+                }
+                (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    let message = format!("{} is too low ({} was too low)", answer, attempt.answer);
+                    return Err(Error::Refused(message));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits the answer to a part of a puzzle, unless the history shows that it is wrong, and
+/// records the attempt in the history
+pub fn submit(client: &Client, year: u32, day: u32, part: u32, answer: &Answer) -> Result<Verdict> {
+    if answer.is_multiline() {
+        let message = "multi-line answers must be read and submitted by hand";
+        return Err(Error::Refused(message.to_string()));
+    }
+    let path = History::path(client.config());
+    let mut history = History::load(&path)?;
+    history.check(year, day, part, answer)?;
+
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", &answer.to_string())],
+    )?;
+    let verdict = Verdict::parse(&html);
+    history.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+        verdict: verdict.clone(),
+        time: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+    });
+    history.save(&path)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{serve, test_config};
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data.</p></article>\n</main>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 38s left to wait. \
+        <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are \
+        <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.\
+        </p></article>";
+
+    fn attempt(answer: i64, verdict: Verdict) -> Attempt {
+        Attempt { year: 2022, day: 1, part: 1, answer: Answer::from(answer), verdict, time: 0 }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(Verdict::parse(WAIT), Verdict::Wait("38s".to_string()));
+        assert_eq!(Verdict::parse(CORRECT), Verdict::Correct);
+        assert_eq!(Verdict::parse("<article><p>Oops</p></article>"), Verdict::Unknown("Oops".to_string()));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        history.record(attempt(500, Verdict::TooHigh));
+        history.record(attempt(100, Verdict::TooLow));
+        history.record(attempt(300, Verdict::Wrong));
+        history.record(attempt(200, Verdict::Wait("10s".to_string())));
+        assert!(history.check(2022, 1, 1, &Answer::from(200)).is_ok());
+        assert!(matches!(history.check(2022, 1, 1, &Answer::from(300)), Err(Error::Refused(_))));
+        assert!(matches!(history.check(2022, 1, 1, &Answer::from(500)), Err(Error::Refused(_))));
+        assert!(matches!(history.check(2022, 1, 1, &Answer::from(99)), Err(Error::Refused(_))));
+        assert!(history.check(2022, 1, 2, &Answer::from(500)).is_ok());
+
+        history.record(attempt(250, Verdict::Correct));
+        assert!(matches!(history.check(2022, 1, 1, &Answer::from(200)), Err(Error::Refused(_))));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(test_config("submit", url));
+        assert_eq!(submit(&client, 2022, 1, 2, &Answer::from(1000)).unwrap(), Verdict::TooHigh);
+        assert!(matches!(submit(&client, 2022, 1, 2, &Answer::from(1200)), Err(Error::Refused(_))));
+        assert_eq!(submit(&client, 2022, 1, 2, &Answer::from(900)).unwrap(), Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=1000"));
+        let history = History::load(History::path(client.config())).unwrap();
+        let verdicts: Vec<_> = history.attempts(2022, 1, 2).map(|a| a.verdict.clone()).collect();
+        assert_eq!(verdicts, vec![Verdict::TooHigh, Verdict::Correct]);
+        fs::remove_dir_all(&client.config().data_dir).unwrap();
+    }
+}
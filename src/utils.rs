@@ -1,45 +1,75 @@
-use nom::branch::alt;
-use nom::character::complete::{char, digit1};
-use nom::combinator::{map, opt};
-use nom::sequence::pair;
-use nom::IResult;
-use std::fs::File;
-use std::io::Read;
-use std::ops::Neg;
-use std::str::FromStr;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-pub fn read_input(filename: &str) -> Result<String, std::io::Error> {
-    // open file given as input and returns its content as a String
-    let mut file = File::open(filename)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    Ok(contents)
-}
+use crate::config::Config;
+use crate::{Error, Result};
+
+pub mod cycle;
+pub mod direction;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod linalg;
+pub mod memo;
+pub mod number;
+pub mod parse;
+pub mod polygon;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use number::{bezout, gcd};
+pub use parse::{parse_all, parse_int, parse_unsigned_int};
 
-pub fn parse_unsigned_int<T: FromStr>(input: &str) -> IResult<&str, T> {
-    map(digit1, |number: &str| {
-        let value = match number.parse::<T>() {
-            Ok(v) => v,
-            Err(_) => panic!("Could not parse int"),
-        };
-        return value;
-    })(input)
+/// Reads the whole content of a file
+pub fn read_input<P: AsRef<Path>>(filename: P) -> Result<String> {
+    let filename = filename.as_ref();
+    fs::read_to_string(filename).map_err(|e| Error::io(filename, e))
 }
 
-pub fn parse_int<T: FromStr + Neg<Output = T>>(input: &str) -> IResult<&str, T> {
-    map(
-        pair(opt(alt((char('-'), char('+')))), digit1),
-        |(sign, number): (Option<char>, &str)| {
-            let value = match number.parse::<T>() {
-                Ok(v) => v,
-                Err(_) => panic!("Could not parse int"),
-            };
-            if sign == Some('-') {
-                return -value;
+/// Returns the path of the input file of a given day
+///
+/// The path is absolute (based on the crate root) so that inputs can be found regardless of the
+/// current working directory. The input stored next to the module of the day takes precedence
+/// over the one downloaded by `advent fetch` in the data directory.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    let path = module_input_path(year, day);
+    if !path.exists() {
+        if let Ok(config) = Config::load() {
+            let cached = config.input_cache_path(year, day);
+            if cached.exists() {
+                return cached;
             }
-            return value;
-        },
-    )(input)
+        }
+    }
+    path
+}
+
+/// Returns the path of the input file stored next to the module of a given day
+pub fn module_input_path(year: u32, day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/year{}/day{:02}/input.txt", year, day))
+}
+
+/// Reads the input file of a given day
+pub fn read_day_input(year: u32, day: u32) -> Result<String> {
+    read_input(input_path(year, day))
+}
+
+/// Returns the path of the `n`-th example input of a given day (numbered from 1)
+///
+/// The first example is stored in `example.txt` and the next ones in `example2.txt`,
+/// `example3.txt`, etc.
+pub fn example_path(year: u32, day: u32, n: u32) -> PathBuf {
+    let filename = match n {
+        1 => "example.txt".to_string(),
+        n => format!("example{}.txt", n),
+    };
+    module_input_path(year, day).with_file_name(filename)
+}
+
+/// Reads the `n`-th example input of a given day
+pub fn read_example(year: u32, day: u32, n: u32) -> Result<String> {
+    read_input(example_path(year, day, n))
 }
 
 /// Divides a by b and rounds up
@@ -55,25 +85,3 @@ pub fn parse_int<T: FromStr + Neg<Output = T>>(input: &str) -> IResult<&str, T>
 pub fn div_up(a: i32, b: i32) -> i32 {
     (a + (b - 1)) / b
 }
-
-pub fn gcd(mut a: i32, mut b: i32) -> i32 {
-    while b != 0 {
-        let t = b;
-        b = a % b;
-        a = t;
-    }
-    a
-}
-
-pub fn bezout(a: i32, b: i32) -> (i32, i32, i32) {
-    let mut r = (a, b);
-    let mut s = (1, 0);
-    let mut t = (0, 1);
-    while r.1 != 0 {
-        let q = r.0 / r.1;
-        r = (r.1, r.0 - q * r.1);
-        s = (s.1, s.0 - q * s.1);
-        t = (t.1, t.0 - q * t.1);
-    }
-    (r.0, s.0, t.0)
-}
\ No newline at end of file
@@ -0,0 +1,128 @@
+//! Detection of cycles in sequences of states
+//!
+//! A sequence obtained by repeatedly applying a step function to a state from a finite set is
+//! eventually periodic. Once its cycle is known, the state (or any quantity that changes by the
+//! same amount on each cycle) after a very large number of steps can be computed directly.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter;
+
+use num::PrimInt;
+
+/// Cycle of an eventually periodic sequence: the state at index `start + length` is the same as
+/// the state at index `start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first state of the cycle
+    pub start: usize,
+    /// Number of steps after which the states repeat
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Index of a state of the first cycle (or of the states before it) that is the same as the
+    /// state at index `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start + self.length {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Value at index `n` of a quantity that increases by the same amount on each cycle, given its
+    /// values on the states until the end of the first cycle (indices `0..=start + length`)
+    pub fn extrapolate<T: PrimInt>(&self, values: &[T], n: usize) -> T {
+        if n < values.len() {
+            return values[n];
+        }
+        let nb_cycles = T::from((n - self.start) / self.length).unwrap();
+        let increase = values[self.start + self.length] - values[self.start];
+        values[self.reduce(n)] + increase * nb_cycles
+    }
+}
+
+/// Finds the cycle of a sequence where items are compared by their key
+///
+/// Returns the cycle with the items until the end of the first cycle (the last item being the
+/// first repetition), or `None` if the sequence ends before an item repeats.
+pub fn find_cycle_by_key<T, K>(
+    sequence: impl IntoIterator<Item = T>,
+    mut key: impl FnMut(&T) -> K,
+) -> Option<(Cycle, Vec<T>)>
+where
+    K: Eq + Hash,
+{
+    let mut indices = HashMap::new();
+    let mut items = Vec::new();
+    for item in sequence {
+        let index = items.len();
+        let previous = indices.insert(key(&item), index);
+        items.push(item);
+        if let Some(start) = previous {
+            return Some((Cycle { start, length: index - start }, items));
+        }
+    }
+    None
+}
+
+/// Finds the cycle of the sequence of states obtained by applying `step` from the initial state
+///
+/// Returns the cycle with the states until the end of the first cycle (the last state being the
+/// first repetition).
+pub fn find_cycle<S>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>)
+where
+    S: Clone + Eq + Hash,
+{
+    let states = iter::successors(Some(initial), |state| Some(step(state)));
+    find_cycle_by_key(states, S::clone).unwrap()
+}
+
+/// State obtained after applying `step` `n` times from the initial state
+pub fn state_after<S>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    let (cycle, mut states) = find_cycle(initial, step);
+    states.swap_remove(cycle.reduce(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 2, 4, 16, 56, 36, 96, 16, 56, ... (squares modulo 100)
+        let (cycle, states) = find_cycle(2, |&n: &u32| n * n % 100);
+        assert_eq!(cycle, Cycle { start: 2, length: 4 });
+        assert_eq!(states, vec![2, 4, 16, 56, 36, 96, 16]);
+        assert_eq!(cycle.reduce(3), 3);
+        assert_eq!(cycle.reduce(1001), 5);
+        assert_eq!(state_after(2, |&n: &u32| n * n % 100, 1_000_000_000_000), 36);
+    }
+
+    #[test]
+    fn test_find_cycle_by_key() {
+        // positions on a circle of size 7, compared modulo 7
+        let (cycle, items) = find_cycle_by_key((0..).step_by(3), |n| n % 7).unwrap();
+        assert_eq!(cycle, Cycle { start: 0, length: 7 });
+        assert_eq!(items.len(), 8);
+        assert_eq!(find_cycle_by_key([1, 2, 3], |&n| n), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // values growing by 3 on each step, with a cycle from the start
+        let (cycle, items) = find_cycle_by_key((0..).step_by(3), |n| n % 7).unwrap();
+        assert_eq!(cycle.extrapolate(&items, 5), 15);
+        assert_eq!(cycle.extrapolate(&items, 1_000_000), 3_000_000);
+        // linear growth after a prefix
+        let values = [5, 1, 4, 6, 9];
+        let cycle = Cycle { start: 1, length: 3 };
+        assert_eq!(cycle.extrapolate(&values, 4), 9);
+        assert_eq!(cycle.extrapolate(&values, 7), 17);
+        assert_eq!(cycle.extrapolate(&values, 9), 22);
+    }
+}
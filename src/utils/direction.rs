@@ -0,0 +1,191 @@
+//! Directions of moves on a grid
+//!
+//! Grid offsets are given as `(di, dj)` (rows grow downwards, like the positions of a `Grid`),
+//! while vectors are given as `(x, y)` points (the y axis points up, like the rotations of
+//! `Point2`). In both cases north is up.
+
+use super::geometry::Point2;
+
+/// One of the 4 orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north (in the order of `index`)
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Reads a direction given as an arrow (`^v<>`), a move (`UDLR`) or a cardinal point (`NSEW`)
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction::North),
+            '>' | 'R' | 'E' => Some(Direction::East),
+            'v' | 'D' | 'S' => Some(Direction::South),
+            '<' | 'L' | 'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Position of the direction in `ALL` (to index arrays by direction)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns by a number of quarter turns (clockwise for positive numbers)
+    pub fn turn(self, quarter_turns: i32) -> Self {
+        Self::ALL[(self.index() as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Offset `(di, dj)` of a move in this direction on a grid
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+impl From<Direction> for Point2 {
+    fn from(direction: Direction) -> Point2 {
+        let (di, dj) = direction.offset();
+        Point2::new(dj as i32, -di as i32)
+    }
+}
+
+/// One of the 8 orthogonal or diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from north (in the order of `index`)
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Position of the direction in `ALL` (to index arrays by direction)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns by a number of eighths of a turn (clockwise for positive numbers)
+    pub fn turn(self, eighth_turns: i32) -> Self {
+        Self::ALL[(self.index() as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    /// Turns by 45° counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    /// Turns by 45° clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Offset `(di, dj)` of a move in this direction on a grid
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[2 * direction.index()]
+    }
+}
+
+impl From<Direction8> for Point2 {
+    fn from(direction: Direction8) -> Point2 {
+        let (di, dj) = direction.offset();
+        Point2::new(dj as i32, -di as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert_eq!(Direction::South.turn(-3), Direction::West);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthEast.reverse(), Direction8::NorthWest);
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d.turn_right()), Direction8::from(d).turn(2));
+        }
+    }
+
+    #[test]
+    fn test_from_char() {
+        let directions: Vec<_> = "^>v<".chars().map(|c| Direction::from_char(c).unwrap()).collect();
+        assert_eq!(directions, Direction::ALL);
+        assert_eq!(Direction::from_char('U'), Some(Direction::North));
+        assert_eq!(Direction::from_char('W'), Some(Direction::West));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(Direction::North.offset(), (-1, 0));
+        assert_eq!(Point2::from(Direction::North), Point2::new(0, 1));
+        for d in Direction::ALL {
+            assert_eq!(Point2::from(d.turn_left()), Point2::from(d).rotate_left());
+        }
+        assert_eq!(Point2::from(Direction8::SouthWest), Point2::new(-1, -1));
+        assert!(Direction8::ALL.iter().all(|d| d.is_diagonal() == (Point2::from(*d).norm1() == 2)));
+    }
+}
@@ -0,0 +1,302 @@
+//! Points and vectors with integer coordinates, in any dimension
+//!
+//! The same type is used for points and for the vectors between them, so that a point can be moved
+//! by adding a vector (`p + v`) and the vector between two points is their difference (`q - p`).
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A point (or vector) with `N` integer coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i32; N]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+    /// The origin (or null vector)
+    pub const ZERO: Self = Point([0; N]);
+
+    /// Unit vector along the given axis
+    pub fn unit(axis: usize) -> Self {
+        let mut p = Self::ZERO;
+        p.0[axis] = 1;
+        p
+    }
+
+    /// Sum of the absolute values of the coordinates
+    pub fn norm1(&self) -> i32 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    /// Largest absolute value of the coordinates
+    pub fn norm_inf(&self) -> i32 {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// Manhattan (taxicab) distance to another point
+    pub fn manhattan(&self, other: &Self) -> i32 {
+        (*self - *other).norm1()
+    }
+
+    /// Chebyshev (king move) distance to another point
+    pub fn chebyshev(&self, other: &Self) -> i32 {
+        (*self - *other).norm_inf()
+    }
+
+    /// Vector with the sign (-1, 0 or 1) of each coordinate
+    pub fn signum(&self) -> Self {
+        Point(self.0.map(i32::signum))
+    }
+
+    /// Rotates by 90° in the plane of the two given axes, so that the unit vector of axis `from`
+    /// is sent to the unit vector of axis `to`
+    pub fn rotate(&self, from: usize, to: usize) -> Self {
+        let mut p = *self;
+        p.0[to] = self.0[from];
+        p.0[from] = -self.0[to];
+        p
+    }
+
+    /// Iterates over the `2 * N` points at distance 1 along a single axis
+    pub fn orthogonal_neighbors(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..N).flat_map(move |axis| [*self - Self::unit(axis), *self + Self::unit(axis)])
+    }
+
+    /// Iterates over the `3^N - 1` points at Chebyshev distance 1 (orthogonal and diagonal
+    /// neighbours)
+    pub fn all_neighbors(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..3usize.pow(N as u32)).filter_map(move |mut k| {
+            let mut p = *self;
+            for c in p.0.iter_mut() {
+                *c += (k % 3) as i32 - 1;
+                k /= 3;
+            }
+            (p != *self).then_some(p)
+        })
+    }
+}
+
+impl Point<2> {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point([x, y])
+    }
+
+    pub fn x(&self) -> i32 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i32 {
+        self.0[1]
+    }
+
+    /// Rotates by 90° counterclockwise (when the y axis points up)
+    pub fn rotate_left(&self) -> Self {
+        self.rotate(0, 1)
+    }
+
+    /// Rotates by 90° clockwise (when the y axis points up)
+    pub fn rotate_right(&self) -> Self {
+        self.rotate(1, 0)
+    }
+}
+
+impl Point<3> {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point([x, y, z])
+    }
+
+    pub fn x(&self) -> i32 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i32 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> i32 {
+        self.0[2]
+    }
+}
+
+impl Point<4> {
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Point([x, y, z, w])
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Point<N> {
+    fn from(coordinates: [i32; N]) -> Self {
+        Point(coordinates)
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i32;
+
+    fn index(&self, axis: usize) -> &i32 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i32 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point(std::array::from_fn(|k| self.0[k] + other.0[k]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point(std::array::from_fn(|k| self.0[k] - other.0[k]))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i32> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, k: i32) -> Self {
+        Point(self.0.map(|c| c * k))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> MulAssign<i32> for Point<N> {
+    fn mul_assign(&mut self, k: i32) {
+        *self = *self * k;
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (k, c) in self.0.iter().enumerate() {
+            if k > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// serde only implements its traits for arrays of a given size, so points are (de)serialized by hand,
+// as tuples of coordinates
+
+impl<const N: usize> Serialize for Point<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for c in &self.0 {
+            tuple.serialize_element(c)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Point<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct PointVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for PointVisitor<N> {
+            type Value = Point<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of {} integers", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Point<N>, A::Error> {
+                let mut p = Point::ZERO;
+                for k in 0..N {
+                    p.0[k] = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(k, &self))?;
+                }
+                Ok(p)
+            }
+        }
+
+        deserializer.deserialize_tuple(N, PointVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operations() {
+        let p = Point2::new(3, -4);
+        let q = Point2::new(1, 2);
+        assert_eq!(p + q, Point2::new(4, -2));
+        assert_eq!(p - q, Point2::new(2, -6));
+        assert_eq!(-p * 2, Point2::new(-6, 8));
+        assert_eq!(p.manhattan(&q), 8);
+        assert_eq!(p.chebyshev(&q), 6);
+        assert_eq!(p.signum(), Point2::new(1, -1));
+        assert_eq!(p.to_string(), "(3, -4)");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let p = Point2::new(1, 0);
+        assert_eq!(p.rotate_left(), Point2::new(0, 1));
+        assert_eq!(p.rotate_right(), Point2::new(0, -1));
+        assert_eq!(p.rotate_left().rotate_left(), -p);
+        assert_eq!(Point3::new(1, 2, 3).rotate(2, 0), Point3::new(3, 2, -1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let p = Point3::new(0, 0, 0);
+        assert_eq!(p.orthogonal_neighbors().count(), 6);
+        assert!(p.orthogonal_neighbors().all(|n| n.norm1() == 1));
+        assert_eq!(p.all_neighbors().count(), 26);
+        assert!(p.all_neighbors().all(|n| n.norm_inf() == 1));
+        assert_eq!(Point4::ZERO.all_neighbors().count(), 80);
+    }
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&Point3::new(1, -2, 3)).unwrap();
+        assert_eq!(json, "[1,-2,3]");
+        assert_eq!(serde_json::from_str::<Point3>(&json).unwrap(), Point3::new(1, -2, 3));
+        assert!(serde_json::from_str::<Point3>("[1,2]").is_err());
+    }
+}
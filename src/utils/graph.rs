@@ -0,0 +1,309 @@
+//! Shortest paths in graphs given by a successor function
+//!
+//! States can be of any (hashable) type, and the graph is explored lazily: `successors` is only
+//! called on the states that are reached. Searches can start from several states at once.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+use super::Grid;
+
+/// Distances from the start states to the reached states, with the predecessor of each state on a
+/// shortest path
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    fn new() -> Self {
+        Self { distances: HashMap::new(), predecessors: HashMap::new() }
+    }
+
+    /// Distance from the closest start state (`None` if the state was not reached)
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// Previous state on a shortest path (`None` for start states and states not reached)
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.predecessors.get(state)
+    }
+
+    /// Shortest path from a start state to the given state (both included)
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, until all reachable states are visited or a goal state is reached
+fn breadth_first<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Paths<S, usize>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.distances.contains_key(&start) {
+            paths.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return (paths, Some(state));
+        }
+        let distance = paths.distances[&state];
+        for next in successors(&state) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    (paths, None)
+}
+
+/// Distances (in number of moves) to all states reachable from the start states
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(starts, successors, |_| false).0
+}
+
+/// Shortest path (in number of moves) from one of the start states to a goal state, with its length
+pub fn bfs_to<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (paths, goal) = breadth_first(starts, successors, is_goal);
+    let goal = goal?;
+    Some((paths.path_to(&goal).unwrap(), paths.distances[&goal]))
+}
+
+/// State in the priority queue of a best-first search, ordered by lowest priority first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Best-first search (A* with the given heuristic, Dijkstra's algorithm if it is always zero),
+/// until all reachable states are visited or a goal state is reached
+fn best_first<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Paths<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        paths.distances.insert(start.clone(), C::zero());
+        queue.push(Queued { priority: heuristic(&start), cost: C::zero(), state: start });
+    }
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if cost > paths.distances[&state] {
+            // the state was already reached with a lower cost
+            continue;
+        }
+        if is_goal(&state) {
+            return (paths, Some(state));
+        }
+        for (next, weight) in successors(&state) {
+            let next_cost = cost + weight;
+            if paths.distances.get(&next).is_none_or(|&c| next_cost < c) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.predecessors.insert(next.clone(), state.clone());
+                queue.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+    (paths, None)
+}
+
+/// Distances to all states reachable from the start states, when moves have non-negative costs
+/// (`successors` gives the next states with the cost of the move)
+pub fn dijkstra<S, C, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(starts, successors, |_| C::zero(), |_| false).0
+}
+
+/// Cheapest path from one of the start states to a goal state, with its cost
+pub fn dijkstra_to<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// Cheapest path from one of the start states to a goal state, with its cost, using A* search
+///
+/// The heuristic must never overestimate the cost of reaching a goal from a state, otherwise the
+/// path that is found might not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (paths, goal) = best_first(starts, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.path_to(&goal).unwrap(), paths.distances[&goal]))
+}
+
+/// Distances between all pairs of nodes `0..n` of a graph given by its weighted directed edges
+/// `(from, to, weight)` (Floyd–Warshall algorithm)
+///
+/// The distance from `i` to `j` is at position `(i, j)` of the result (`None` if there is no path).
+pub fn all_pairs<C>(n: usize, edges: impl IntoIterator<Item = (usize, usize, C)>) -> Grid<Option<C>>
+where
+    C: Copy + Ord + Add<Output = C> + Zero,
+{
+    let mut distances = Grid::new(n, n, None);
+    for i in 0..n {
+        distances[(i, i)] = Some(C::zero());
+    }
+    for (i, j, weight) in edges {
+        if distances[(i, j)].is_none_or(|d| weight < d) {
+            distances[(i, j)] = Some(weight);
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            let Some(dik) = distances[(i, k)] else { continue };
+            for j in 0..n {
+                if let Some(dkj) = distances[(k, j)] {
+                    if distances[(i, j)].is_none_or(|d| dik + dkj < d) {
+                        distances[(i, j)] = Some(dik + dkj);
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small weighted graph where node 4 is not reachable
+    const EDGES: [(usize, usize, u32); 5] = [(0, 1, 1), (0, 2, 4), (1, 2, 2), (2, 3, 1), (4, 0, 1)];
+
+    fn successors(node: &usize) -> Vec<(usize, u32)> {
+        EDGES.iter().filter(|e| e.0 == *node).map(|e| (e.1, e.2)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        // numbers from 1, moving to 2n or n + 3
+        let paths = bfs([1], |&n: &u32| [2 * n, n + 3].into_iter().filter(|&m| m <= 20));
+        assert_eq!(paths.distance(&10), Some(3));
+        assert_eq!(paths.path_to(&10), Some(vec![1, 2, 5, 10]));
+        assert_eq!(paths.predecessor(&1), None);
+        assert_eq!(paths.distance(&3), None);
+        let (path, length) = bfs_to([1, 3], |&n: &u32| [2 * n, n + 3], |&n| n == 12).unwrap();
+        assert_eq!((path, length), (vec![3, 6, 12], 2));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra([0], successors);
+        assert_eq!(paths.distance(&3), Some(4));
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(paths.distance(&4), None);
+        assert_eq!(dijkstra_to([0], successors, |&n| n == 2), Some((vec![0, 1, 2], 3)));
+        assert_eq!(dijkstra_to([1], successors, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // shortest path on an open grid, with the Manhattan distance as heuristic
+        let target = (5, 7);
+        let successors = |&(i, j): &(i32, i32)| [(i + 1, j), (i, j + 1), (i - 1, j), (i, j - 1)].map(|p| (p, 1));
+        let heuristic = |&(i, j): &(i32, i32)| (target.0 - i).abs() + (target.1 - j).abs();
+        let (path, cost) = astar([(0, 0)], successors, heuristic, |&p| p == target).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let distances = all_pairs(5, EDGES);
+        assert_eq!(distances[(0, 3)], Some(4));
+        assert_eq!(distances[(4, 3)], Some(5));
+        assert_eq!(distances[(3, 0)], None);
+        assert_eq!(distances[(2, 2)], Some(0));
+    }
+}
@@ -0,0 +1,248 @@
+//! Two-dimensional grids, as found in most puzzle maps
+//!
+//! Positions are given as `(i, j)` where `i` is the row (from the top) and `j` the column (from
+//! the left), like the indices of a `Vec<Vec<T>>`.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// Offsets of the 4 orthogonal neighbours of a cell
+pub const NEIGHBORS_4: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the 8 neighbours of a cell (orthogonal and diagonal)
+pub const NEIGHBORS_8: [(i64, i64); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1)];
+
+/// A rectangular grid of values, stored row by row in a single vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Makes a grid filled with the given value
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { height, width, cells: vec![value; height * width] }
+    }
+
+    /// Makes a grid from its rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "rows of different lengths");
+        Self { height, width, cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// Parses a grid with one row per line, converting each character with `f`
+    ///
+    /// Fails if the lines do not all have the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self> {
+        Self::try_parse(input, |c| Some(f(c)))
+    }
+
+    /// Same as `parse`, except that `f` returns `None` for characters that are not valid cells
+    ///
+    /// Fails with the position of the first invalid character.
+    pub fn try_parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let mut offset = 0;
+        for chunk in input.split_inclusive('\n') {
+            // same line endings as `str::lines` (`\n` or `\r\n`)
+            let line = chunk.strip_suffix('\n').unwrap_or(chunk);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let length = line.chars().count();
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    let message = format!("expected a row of length {}, found {}", width, length);
+                    return Err(Error::parse_at(input, offset, message));
+                }
+                _ => {}
+            }
+            for (k, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| Error::parse_at(input, offset + k, format!("unexpected character {:?}", c)))?;
+                cells.push(cell);
+            }
+            height += 1;
+            offset += chunk.len();
+        }
+        Ok(Self { height, width: width.unwrap_or(0), cells })
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i < self.height && j < self.width {
+            Some(&self.cells[i * self.width + j])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.height && j < self.width {
+            Some(&mut self.cells[i * self.width + j])
+        } else {
+            None
+        }
+    }
+
+    /// Same as `get`, with signed coordinates (negative coordinates are out of the grid)
+    pub fn get_signed(&self, i: i64, j: i64) -> Option<&T> {
+        if i < 0 || j < 0 {
+            return None;
+        }
+        self.get(i as usize, j as usize)
+    }
+
+    /// Moves from a position by the given offset, if the destination is in the grid
+    pub fn step(&self, (i, j): (usize, usize), (di, dj): (i64, i64)) -> Option<(usize, usize)> {
+        let i = i.checked_add_signed(di as isize)?;
+        let j = j.checked_add_signed(dj as isize)?;
+        if i < self.height && j < self.width {
+            Some((i, j))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the orthogonal neighbours of a position that are in the grid
+    pub fn neighbors4(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_4.iter().filter_map(move |&delta| self.step((i, j), delta))
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of a position that are in the grid
+    pub fn neighbors8(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_8.iter().filter_map(move |&delta| self.step((i, j), delta))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width, "column {} out of a grid of width {}", j, self.width);
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// Iterates over all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// Iterates over all positions and values, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the first position (row by row) holding the given value
+    pub fn find(&self, value: T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| **v == value).map(|(position, _)| position)
+    }
+
+    /// Makes a grid of the same size by converting each value
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { height: self.height, width: self.width, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(j < self.width, "column {} out of a grid of width {}", j, self.width);
+        &self.cells[i * self.width + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(j < self.width, "column {} out of a grid of width {}", j, self.width);
+        &mut self.cells[i * self.width + j]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.S\n..#\n", |c| c).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], '#');
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.find('S'), Some((0, 2)));
+        assert_eq!(grid.to_string(), "#.S\n..#\n");
+        assert!(matches!(Grid::parse("##\n#\n", |c| c), Err(Error::Parse { line: 2, column: 1, .. })));
+        let grid = Grid::parse("#.\r\n.#\r\n", |c| c).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 2));
+        assert!(matches!(Grid::parse("##\r\n##\r\n#\r\n", |c| c), Err(Error::Parse { line: 3, column: 1, .. })));
+        let grid = Grid::try_parse("12\n34\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid[(1, 0)], 3);
+        assert!(matches!(Grid::try_parse("12\n3x\n", |c| c.to_digit(10)), Err(Error::Parse { line: 2, column: 2, .. })));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 4, 0);
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(0, 3).collect::<Vec<_>>(), vec![(1, 3), (1, 2), (0, 2)]);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.step((2, 3), (0, 1)), None);
+        assert_eq!(grid.step((2, 3), (-2, -3)), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        grid[(0, 0)] = 7;
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[7, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.map(|v| v % 2 == 0).iter().filter(|(_, &even)| even).count(), 3);
+    }
+}
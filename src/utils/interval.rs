@@ -0,0 +1,387 @@
+//! Intervals of integers, sets of intervals and maps translating intervals
+//!
+//! All intervals are half-open: `Interval::new(a, b)` contains the values `a <= x < b` (use
+//! `Interval::closed` for puzzles that give both bounds).
+
+use std::fmt;
+
+use num::{PrimInt, Signed};
+
+/// Half-open interval `[start, end)` (empty when `start >= end`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Interval `[first, last]`, with both bounds included
+    ///
+    /// A half-open interval cannot contain the maximal value of `T`, so when `last` is that value
+    /// the interval stops just before it.
+    pub fn closed(first: T, last: T) -> Self {
+        Self::new(first, last.checked_add(&T::one()).unwrap_or(last))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether all values of `other` are in the interval
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let interval = Self::new(self.start.max(other.start), self.end.min(other.end));
+        non_empty(interval)
+    }
+
+    /// Values of the interval that are not in `other` (at most two intervals)
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if other.is_empty() {
+            return non_empty(*self).into_iter().collect();
+        }
+        let before = Self::new(self.start, self.end.min(other.start));
+        let after = Self::new(self.start.max(other.end), self.end);
+        non_empty(before).into_iter().chain(non_empty(after)).collect()
+    }
+
+    /// Splits the interval into the values lower than `value` and the others
+    pub fn split_at(&self, value: T) -> (Option<Self>, Option<Self>) {
+        let low = Self::new(self.start, self.end.min(value));
+        let high = Self::new(self.start.max(value), self.end);
+        (non_empty(low), non_empty(high))
+    }
+
+    /// Interval translated by `offset`
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+fn non_empty<T: PrimInt>(interval: Interval<T>) -> Option<Interval<T>> {
+    (!interval.is_empty()).then_some(interval)
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// Set of integers, stored as a sorted list of disjoint, non-adjacent and non-empty intervals
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Makes a set from any intervals, by sorting and merging them
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.end >= interval.start => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// The intervals of the set, in increasing order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::zero(), |total, i| total + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        index < self.intervals.len() && self.intervals[index].start <= value
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.intervals.iter().chain(&other.intervals).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for a in &self.intervals {
+            intervals.extend(other.intervals.iter().filter_map(|b| a.intersection(b)));
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for a in &self.intervals {
+            let mut start = a.start;
+            for b in other.intervals.iter().filter(|b| b.overlaps(a)) {
+                if start < b.start {
+                    intervals.push(Interval::new(start, b.start));
+                }
+                start = b.end;
+            }
+            if start < a.end {
+                intervals.push(Interval::new(start, a.end));
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Splits the set into the values lower than `value` and the others
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let mut low = Vec::new();
+        let mut high = Vec::new();
+        for interval in &self.intervals {
+            let (l, h) = interval.split_at(value);
+            low.extend(l);
+            high.extend(h);
+        }
+        (Self { intervals: low }, Self { intervals: high })
+    }
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+/// Map translating the values of some disjoint intervals, each by its own offset (values outside of
+/// the intervals are left unchanged)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<T> {
+    /// Source intervals with their offsets, sorted and disjoint
+    pieces: Vec<(Interval<T>, T)>,
+    /// Values inserted with a zero offset, which are left unchanged but keep priority over later
+    /// insertions (maps made by `then` do not keep them)
+    fixed: IntervalSet<T>,
+}
+
+impl<T: PrimInt + Signed> IntervalMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new(), fixed: IntervalSet::new() }
+    }
+
+    /// Makes a map from pieces that are already disjoint, removing those that do nothing
+    fn from_disjoint(mut pieces: Vec<(Interval<T>, T)>) -> Self {
+        pieces.retain(|(source, offset)| !source.is_empty() && !offset.is_zero());
+        pieces.sort_by_key(|(source, _)| source.start);
+        Self { pieces, fixed: IntervalSet::new() }
+    }
+
+    /// Source intervals with their offsets, in increasing order
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    fn sources(&self) -> IntervalSet<T> {
+        self.pieces.iter().map(|(source, _)| *source).collect()
+    }
+
+    /// Translates the values of `source` by `offset` (values that were in the source of a previous
+    /// insertion are left as they are, even if its offset was zero)
+    pub fn insert(&mut self, source: Interval<T>, offset: T) {
+        let new_parts = IntervalSet::from(source).difference(&self.sources().union(&self.fixed));
+        if offset.is_zero() {
+            self.fixed = self.fixed.union(&new_parts);
+            return;
+        }
+        let mut pieces = std::mem::take(&mut self.pieces);
+        pieces.extend(new_parts.iter().map(|&part| (part, offset)));
+        self.pieces = Self::from_disjoint(pieces).pieces;
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        let index = self.pieces.partition_point(|(source, _)| source.end <= value);
+        match self.pieces.get(index) {
+            Some((source, offset)) if source.contains(value) => value + *offset,
+            _ => value,
+        }
+    }
+
+    /// Image of a set of values
+    pub fn image(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut images = set.difference(&self.sources()).intervals;
+        for (source, offset) in &self.pieces {
+            images.extend(set.iter().filter_map(|i| i.intersection(source)).map(|i| i.shift(*offset)));
+        }
+        IntervalSet::normalize(images)
+    }
+
+    /// Composition of the maps: applying the result is the same as applying `self` and then `other`
+    pub fn then(&self, other: &Self) -> Self {
+        let other_sources = other.sources();
+        let mut pieces = Vec::new();
+        for (source, offset) in &self.pieces {
+            let image = IntervalSet::from(source.shift(*offset));
+            for (source2, offset2) in &other.pieces {
+                for part in image.intersection(&IntervalSet::from(*source2)).iter() {
+                    pieces.push((part.shift(-*offset), *offset + *offset2));
+                }
+            }
+            for part in image.difference(&other_sources).iter() {
+                pieces.push((part.shift(-*offset), *offset));
+            }
+        }
+        // values that are not moved by `self`
+        let self_sources = self.sources();
+        for (source2, offset2) in &other.pieces {
+            for part in IntervalSet::from(*source2).difference(&self_sources).iter() {
+                pieces.push((*part, *offset2));
+            }
+        }
+        Self::from_disjoint(pieces)
+    }
+}
+
+impl<T: PrimInt + Signed> Default for IntervalMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt + Signed> FromIterator<(Interval<T>, T)> for IntervalMap<T> {
+    /// Makes a map from pieces given in order of priority (values in several source intervals are
+    /// translated by the first one)
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, offset) in iter {
+            map.insert(source, offset);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 8);
+        assert_eq!(a.len(), 6);
+        assert_eq!(Interval::new(5, 3).len(), 0);
+        assert_eq!(Interval::closed(2, 7), a);
+        assert_eq!(Interval::closed(2, i32::MAX), Interval::new(2, i32::MAX));
+        assert!(a.contains(2) && !a.contains(8));
+        assert!(a.contains_interval(&Interval::new(3, 8)));
+        assert!(!a.overlaps(&Interval::new(8, 10)));
+        assert_eq!(a.intersection(&Interval::new(6, 10)), Some(Interval::new(6, 8)));
+        assert_eq!(a.difference(&Interval::new(4, 5)), vec![Interval::new(2, 4), Interval::new(5, 8)]);
+        assert_eq!(a.difference(&Interval::new(0, 10)), vec![]);
+        assert_eq!(a.split_at(2), (None, Some(a)));
+        assert_eq!(a.split_at(5), (Some(Interval::new(2, 5)), Some(Interval::new(5, 8))));
+    }
+
+    #[test]
+    fn test_set() {
+        let a = set(&[(5, 8), (0, 2), (1, 3), (3, 4)]);
+        assert_eq!(a.intervals(), set(&[(0, 4), (5, 8)]).intervals());
+        assert_eq!(a.len(), 7);
+        assert!(a.contains(3) && !a.contains(4) && a.contains(7));
+        assert_eq!((a.min(), a.max()), (Some(0), Some(7)));
+        let b = set(&[(2, 6), (10, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 8), (10, 12)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 4), (5, 6)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (6, 8)]));
+        assert_eq!(a.split_at(6), (set(&[(0, 4), (5, 6)]), set(&[(6, 8)])));
+    }
+
+    #[test]
+    fn test_map() {
+        // the first piece has priority over the second one on [5, 6)
+        let f: IntervalMap<i64> = [(Interval::new(0, 6), 10), (Interval::new(5, 10), -5)].into_iter().collect();
+        assert_eq!((f.apply(0), f.apply(5), f.apply(6), f.apply(10)), (10, 15, 1, 10));
+        let image = f.image(&[Interval::new(4, 8), Interval::new(20, 21)].into_iter().collect());
+        assert_eq!(image.intervals(), &[Interval::new(1, 3), Interval::new(14, 16), Interval::new(20, 21)]);
+
+        // a piece with a zero offset also has priority
+        let k: IntervalMap<i64> = [(Interval::new(0, 10), 0), (Interval::new(5, 15), 3)].into_iter().collect();
+        assert_eq!((k.apply(7), k.apply(12)), (7, 15));
+        assert_eq!(k.pieces(), &[(Interval::new(10, 15), 3)]);
+        // same when the pieces are inserted one by one
+        let mut m = IntervalMap::new();
+        m.insert(Interval::new(0, 10), 0);
+        m.insert(Interval::new(5, 15), 3);
+        m.insert(Interval::new(12, 20), -1);
+        assert_eq!((m.apply(7), m.apply(12), m.apply(17)), (7, 15, 16));
+        assert_eq!(m.pieces(), &[(Interval::new(10, 15), 3), (Interval::new(15, 20), -1)]);
+
+        let g: IntervalMap<i64> = [(Interval::new(12, 20), 100)].into_iter().collect();
+        let h = f.then(&g);
+        for x in -5..25 {
+            assert_eq!(h.apply(x), g.apply(f.apply(x)), "x = {}", x);
+        }
+    }
+}
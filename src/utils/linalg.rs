@@ -0,0 +1,375 @@
+//! Dense matrices and exact linear algebra
+//!
+//! Matrices can hold any numbers, but elimination (rank, determinant, solving systems) is done
+//! on rationals so that results are exact. Integer matrices are converted with `to_rational`.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use num::{Num, Rational64, Zero};
+
+use super::number;
+
+/// A matrix stored row by row in a single vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    values: Vec<T>,
+}
+
+impl<T: Clone + Num> Matrix<T> {
+    /// Makes a matrix filled with zeros
+    pub fn new(nb_rows: usize, nb_cols: usize) -> Self {
+        Self { nb_rows, nb_cols, values: vec![T::zero(); nb_rows * nb_cols] }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::new(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = T::one();
+        }
+        matrix
+    }
+
+    /// Makes a matrix from its rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let nb_rows = rows.len();
+        let nb_cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == nb_cols), "rows of different lengths");
+        Self { nb_rows, nb_cols, values: rows.into_iter().flatten().collect() }
+    }
+
+    pub fn nb_rows(&self) -> usize {
+        self.nb_rows
+    }
+
+    pub fn nb_cols(&self) -> usize {
+        self.nb_cols
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.values[i * self.nb_cols..(i + 1) * self.nb_cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::new(self.nb_cols, self.nb_rows);
+        for i in 0..self.nb_rows {
+            for j in 0..self.nb_cols {
+                result[(j, i)] = self[(i, j)].clone();
+            }
+        }
+        result
+    }
+
+    /// Matrix product `self * other`
+    pub fn multiply(&self, other: &Self) -> Self {
+        assert_eq!(self.nb_cols, other.nb_rows, "incompatible matrix sizes");
+        let mut result = Self::new(self.nb_rows, other.nb_cols);
+        for i in 0..self.nb_rows {
+            for k in 0..self.nb_cols {
+                let a = &self[(i, k)];
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..other.nb_cols {
+                    result[(i, j)] = result[(i, j)].clone() + a.clone() * other[(k, j)].clone();
+                }
+            }
+        }
+        result
+    }
+
+    /// Product `self * x` of the matrix with a column vector
+    pub fn apply(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(self.nb_cols, x.len(), "incompatible vector size");
+        (0..self.nb_rows)
+            .map(|i| {
+                let products = self.row(i).iter().zip(x).map(|(a, b)| a.clone() * b.clone());
+                products.fold(T::zero(), |sum, p| sum + p)
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone + Num + Into<i64>> Matrix<T> {
+    pub fn to_rational(&self) -> Matrix<Rational64> {
+        Matrix {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            values: self.values.iter().map(|x| Rational64::from_integer(x.clone().into())).collect(),
+        }
+    }
+}
+
+impl Matrix<Rational64> {
+    /// Puts the matrix in reduced row echelon form (Gauss-Jordan elimination)
+    ///
+    /// Returns the columns of the pivots and the determinant of the transformation that was
+    /// applied (the product of the pivots, with a sign for each row swap).
+    fn eliminate(&mut self) -> (Vec<usize>, Rational64) {
+        let mut pivot_columns = Vec::new();
+        let mut factor = Rational64::from_integer(1);
+        for j in 0..self.nb_cols {
+            let i = pivot_columns.len();
+            if i == self.nb_rows {
+                break;
+            }
+            let Some(pivot_row) = (i..self.nb_rows).find(|&r| !self[(r, j)].is_zero()) else {
+                continue;
+            };
+            if pivot_row != i {
+                for k in 0..self.nb_cols {
+                    self.values.swap(i * self.nb_cols + k, pivot_row * self.nb_cols + k);
+                }
+                factor = -factor;
+            }
+            let pivot = self[(i, j)];
+            factor *= pivot;
+            for k in 0..self.nb_cols {
+                self[(i, k)] /= pivot;
+            }
+            for r in 0..self.nb_rows {
+                let coefficient = self[(r, j)];
+                if r != i && !coefficient.is_zero() {
+                    for k in 0..self.nb_cols {
+                        let value = self[(i, k)];
+                        self[(r, k)] -= coefficient * value;
+                    }
+                }
+            }
+            pivot_columns.push(j);
+        }
+        (pivot_columns, factor)
+    }
+
+    /// Reduced row echelon form of the matrix, with the columns of its pivots
+    pub fn row_echelon_form(&self) -> (Self, Vec<usize>) {
+        let mut matrix = self.clone();
+        let (pivot_columns, _) = matrix.eliminate();
+        (matrix, pivot_columns)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.row_echelon_form().1.len()
+    }
+
+    /// Determinant of a square matrix
+    pub fn determinant(&self) -> Rational64 {
+        assert_eq!(self.nb_rows, self.nb_cols, "determinant of a non-square matrix");
+        let mut matrix = self.clone();
+        let (pivot_columns, factor) = matrix.eliminate();
+        if pivot_columns.len() < self.nb_rows {
+            Rational64::zero()
+        } else {
+            factor
+        }
+    }
+
+    /// All solutions `x` of the system `self * x = b`
+    pub fn solve(&self, b: &[Rational64]) -> Solutions {
+        assert_eq!(self.nb_rows, b.len(), "incompatible vector size");
+        let n = self.nb_cols;
+        // augmented matrix (A | b)
+        let mut augmented = Self::new(self.nb_rows, n + 1);
+        for i in 0..self.nb_rows {
+            augmented.values[i * (n + 1)..i * (n + 1) + n].copy_from_slice(self.row(i));
+            augmented[(i, n)] = b[i];
+        }
+        let (pivot_columns, _) = augmented.eliminate();
+        if pivot_columns.last() == Some(&n) {
+            // a row reads 0 = 1
+            return Solutions::None;
+        }
+
+        let mut particular = vec![Rational64::zero(); n];
+        for (i, &j) in pivot_columns.iter().enumerate() {
+            particular[j] = augmented[(i, n)];
+        }
+        let free_columns: Vec<usize> = (0..n).filter(|j| !pivot_columns.contains(j)).collect();
+        if free_columns.is_empty() {
+            return Solutions::Unique(particular);
+        }
+        let kernel = free_columns
+            .into_iter()
+            .map(|free| {
+                let mut vector = vec![Rational64::zero(); n];
+                vector[free] = Rational64::from_integer(1);
+                for (i, &j) in pivot_columns.iter().enumerate() {
+                    vector[j] = -augmented[(i, free)];
+                }
+                vector
+            })
+            .collect();
+        Solutions::Infinite { particular, kernel }
+    }
+}
+
+impl Matrix<i64> {
+    pub fn rank(&self) -> usize {
+        self.to_rational().rank()
+    }
+
+    pub fn determinant(&self) -> i64 {
+        self.to_rational().determinant().to_integer()
+    }
+
+    /// All rational solutions `x` of the system `self * x = b`
+    pub fn solve(&self, b: &[i64]) -> Solutions {
+        let b: Vec<Rational64> = b.iter().map(|&x| Rational64::from_integer(x)).collect();
+        self.to_rational().solve(&b)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.nb_rows && j < self.nb_cols, "index out of bounds");
+        &self.values[i * self.nb_cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.nb_rows && j < self.nb_cols, "index out of bounds");
+        &mut self.values[i * self.nb_cols + j]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.values.chunks(self.nb_cols.max(1)) {
+            let row: Vec<String> = row.iter().map(|x| format!("{:>2}", x)).collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Set of solutions of a linear system
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    Unique(Vec<Rational64>),
+    /// All vectors `particular + t_1 * kernel[0] + ... + t_k * kernel[k - 1]` for any rationals
+    /// `t_1, ..., t_k`
+    Infinite { particular: Vec<Rational64>, kernel: Vec<Vec<Rational64>> },
+}
+
+impl Solutions {
+    /// The unique solution, if it has integer coordinates
+    pub fn integer_solution(&self) -> Option<Vec<i64>> {
+        match self {
+            Solutions::Unique(x) => to_integers(x),
+            _ => None,
+        }
+    }
+
+    /// Integer solutions of a system whose solutions form a line, as a point and a step such
+    /// that the integer solutions are exactly `point + n * step` for all integers `n` (`None` if
+    /// there are no integer solutions, or if the solutions do not form a line)
+    pub fn integer_line(&self) -> Option<(Vec<i64>, Vec<i64>)> {
+        let (particular, direction) = match self {
+            Solutions::Infinite { particular, kernel } if kernel.len() == 1 => (particular, &kernel[0]),
+            _ => return None,
+        };
+        // smallest integer vector with the direction of the line
+        let scale = direction.iter().fold(1, |m, x| number::lcm(m, *x.denom()));
+        let step: Vec<i64> = direction.iter().map(|x| (x * scale).to_integer()).collect();
+        let divisor = step.iter().fold(0, |g, x| number::gcd(g, x.abs()));
+        let step: Vec<i64> = step.into_iter().map(|x| x / divisor).collect();
+
+        // integer coefficients c such that c.step = 1, so that c.x is an integer for all integer
+        // solutions x = particular + t * step (which fixes t modulo 1)
+        let mut coefficients = vec![0; step.len()];
+        let mut g = 0;
+        for (k, &x) in step.iter().enumerate() {
+            let (new_g, s, t) = number::bezout(g, x);
+            coefficients.iter_mut().for_each(|c| *c *= s);
+            coefficients[k] = t;
+            g = new_g;
+        }
+        coefficients.iter_mut().for_each(|c| *c *= g.signum());
+
+        let t = -particular
+            .iter()
+            .zip(&coefficients)
+            .map(|(x, &c)| x * c)
+            .fold(Rational64::zero(), |sum, x| sum + x);
+        let point: Vec<Rational64> =
+            particular.iter().zip(&step).map(|(x, &s)| x + t * s).collect();
+        Some((to_integers(&point)?, step))
+    }
+}
+
+/// Converts a vector of rationals to integers (`None` if one of them is not an integer)
+fn to_integers(x: &[Rational64]) -> Option<Vec<i64>> {
+    x.iter().map(|x| x.is_integer().then(|| x.to_integer())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[i64]) -> Vec<Rational64> {
+        values.iter().map(|&x| Rational64::from_integer(x)).collect()
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from_rows(vec![vec![1, 0], vec![0, 1], vec![1, 1]]);
+        assert_eq!(a.multiply(&b), Matrix::from_rows(vec![vec![4, 5], vec![10, 11]]));
+        assert_eq!(a.transpose().multiply(&Matrix::identity(2)), a.transpose());
+        assert_eq!(a.apply(&[1, 1, 1]), vec![6, 15]);
+    }
+
+    #[test]
+    fn test_elimination() {
+        let a: Matrix<i64> = Matrix::from_rows(vec![vec![2, 1, 1], vec![1, 3, 2], vec![1, 0, 0]]);
+        assert_eq!(a.determinant(), -1);
+        assert_eq!(a.rank(), 3);
+        let singular: Matrix<i64> = Matrix::from_rows(vec![vec![1, 2], vec![2, 4]]);
+        assert_eq!(singular.determinant(), 0);
+        assert_eq!(singular.rank(), 1);
+        let (reduced, pivots) = singular.to_rational().row_echelon_form();
+        assert_eq!(pivots, vec![0]);
+        assert_eq!(reduced.row(0), rationals(&[1, 2]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<i64> = Matrix::from_rows(vec![vec![2, 1], vec![1, 3]]);
+        let solutions = a.solve(&[3, 4]);
+        assert_eq!(solutions, Solutions::Unique(rationals(&[1, 1])));
+        assert_eq!(solutions.integer_solution(), Some(vec![1, 1]));
+        assert_eq!(a.solve(&[1, 1]).integer_solution(), None);
+
+        let singular: Matrix<i64> = Matrix::from_rows(vec![vec![1, 2], vec![2, 4]]);
+        assert_eq!(singular.solve(&[1, 3]), Solutions::None);
+        let solutions = singular.solve(&[3, 6]);
+        let particular = rationals(&[3, 0]);
+        let kernel = vec![rationals(&[-2, 1])];
+        assert_eq!(solutions, Solutions::Infinite { particular, kernel });
+    }
+
+    #[test]
+    fn test_integer_line() {
+        // 4x + 6y = 10
+        let a: Matrix<i64> = Matrix::from_rows(vec![vec![4, 6]]);
+        let (point, step) = a.solve(&[10]).integer_line().unwrap();
+        assert_eq!(4 * point[0] + 6 * point[1], 10);
+        assert_eq!(step.iter().map(|x| x.abs()).collect::<Vec<_>>(), vec![3, 2]);
+        // 4x + 6y = 5 has rational solutions only
+        assert_eq!(a.solve(&[5]).integer_line(), None);
+        // x + 2y + 3z = 0 and x + y + z = 0
+        let a: Matrix<i64> = Matrix::from_rows(vec![vec![1, 2, 3], vec![1, 1, 1]]);
+        let (point, step) = a.solve(&[0, 0]).integer_line().unwrap();
+        assert_eq!(point, vec![0, 0, 0]);
+        assert_eq!(step.iter().map(|x| x.abs()).collect::<Vec<_>>(), vec![1, 2, 1]);
+        // solutions forming a point or a plane
+        assert_eq!(Matrix::<i64>::identity(2).solve(&[1, 2]).integer_line(), None);
+        assert_eq!(Matrix::<i64>::from_rows(vec![vec![0, 0]]).solve(&[0]).integer_line(), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Memoization of recursive functions
+//!
+//! The function computing a value receives the memo, so that it can recursively get the values it
+//! depends on (see the tests for an example).
+//!
+//! Keys should be cheap to hash and compare (indices in slices, bitmasks, etc.) rather than
+//! copies of the arguments.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Values of a function that were already computed
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self { values: HashMap::new() }
+    }
+
+    /// Value for the given key, computed with `compute` if it is not known yet
+    ///
+    /// `compute` receives the memo and the key, and can call `get_or_compute` recursively (but it
+    /// must not depend on itself, as the value is only stored once it is computed).
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self, &key);
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// Value for the given key, if it was already computed
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Number of values stored
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>, nb_calls: &mut usize) -> u64 {
+        memo.get_or_compute(n, |memo, &n| {
+            *nb_calls += 1;
+            if n < 2 {
+                n
+            } else {
+                fibonacci(n - 1, memo, nb_calls) + fibonacci(n - 2, memo, nb_calls)
+            }
+        })
+    }
+
+    #[test]
+    fn test_recursion() {
+        let mut memo = Memo::new();
+        let mut nb_calls = 0;
+        assert_eq!(fibonacci(90, &mut memo, &mut nb_calls), 2880067194370816120);
+        assert_eq!(nb_calls, 91);
+        assert_eq!(memo.len(), 91);
+        assert_eq!(memo.get(&10), Some(&55));
+        assert_eq!(fibonacci(50, &mut memo, &mut nb_calls), 12586269025);
+        assert_eq!(nb_calls, 91);
+    }
+
+    #[test]
+    fn test_cheap_keys() {
+        // number of ways to climb stairs by 1 or 2 steps, with the key being the remaining steps
+        fn nb_ways(steps: &[bool], memo: &mut Memo<usize, u64>) -> u64 {
+            memo.get_or_compute(steps.len(), |memo, _| match steps {
+                [] => 1,
+                [_] => 1,
+                _ => nb_ways(&steps[1..], memo) + nb_ways(&steps[2..], memo),
+            })
+        }
+        let mut memo = Memo::new();
+        assert_eq!(nb_ways(&[true; 10], &mut memo), 89);
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
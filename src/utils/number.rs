@@ -0,0 +1,128 @@
+//! Number theory on integers of any type
+//!
+//! Intermediate products are computed in the type of the arguments, so moduli should stay below
+//! the square root of its maximal value (`mod_pow` and `crt` multiply values smaller than the
+//! moduli).
+
+use num::{Integer, Signed};
+
+/// Greatest common divisor
+pub fn gcd<T: Integer + Copy>(mut a: T, mut b: T) -> T {
+    while b != T::zero() {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Least common multiple (0 if one of the numbers is 0)
+pub fn lcm<T: Integer + Copy>(a: T, b: T) -> T {
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+    a / gcd(a, b) * b
+}
+
+/// Greatest common divisor `g` of `a` and `b`, with coefficients `(g, s, t)` such that
+/// `a * s + b * t = g` (extended Euclidean algorithm)
+pub fn bezout<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+    let mut r = (a, b);
+    let mut s = (T::one(), T::zero());
+    let mut t = (T::zero(), T::one());
+    while r.1 != T::zero() {
+        let q = r.0 / r.1;
+        r = (r.1, r.0 - q * r.1);
+        s = (s.1, s.0 - q * s.1);
+        t = (t.1, t.0 - q * t.1);
+    }
+    (r.0, s.0, t.0)
+}
+
+/// Inverse of `a` modulo `modulus`, in `0..modulus` (`None` if they are not coprime)
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, modulus: T) -> Option<T> {
+    let (g, s, _) = bezout(a.mod_floor(&modulus), modulus);
+    if g != T::one() {
+        return None;
+    }
+    Some(s.mod_floor(&modulus))
+}
+
+/// `base` to the power `exponent` modulo `modulus`, in `0..modulus` (by repeated squaring)
+pub fn mod_pow<T: Integer + Copy>(base: T, mut exponent: T, modulus: T) -> T {
+    let two = T::one() + T::one();
+    let mut base = base.mod_floor(&modulus);
+    let mut result = T::one().mod_floor(&modulus);
+    while exponent > T::zero() {
+        if exponent.is_odd() {
+            result = (result * base).mod_floor(&modulus);
+        }
+        base = (base * base).mod_floor(&modulus);
+        exponent = exponent / two;
+    }
+    result
+}
+
+/// Solves a system of congruences `x = residue (mod modulus)` (Chinese remainder theorem)
+///
+/// Moduli do not need to be coprime. Returns the solutions as `(x, m)` where `x` is the smallest
+/// non-negative solution and `m` the least common multiple of the moduli (all solutions are
+/// `x + k * m`), or `None` if the congruences are inconsistent.
+pub fn crt<T: Integer + Signed + Copy>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let mut solution = (T::zero(), T::one());
+    for (residue, modulus) in congruences {
+        let (x, m) = solution;
+        let (g, s, _) = bezout(m, modulus);
+        let difference = residue - x;
+        if !difference.is_multiple_of(&g) {
+            return None;
+        }
+        // x + m * k is a solution if m * k = difference (mod modulus), and m * s = g (mod modulus)
+        let reduced_modulus = modulus / g;
+        let k = ((difference / g).mod_floor(&reduced_modulus) * s.mod_floor(&reduced_modulus))
+            .mod_floor(&reduced_modulus);
+        let combined_modulus = m * reduced_modulus;
+        solution = ((x + m * k).mod_floor(&combined_modulus), combined_modulus);
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(17u64, 5), 1);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(123_456_789_012i64, 1_000_000), 30_864_197_253_000_000);
+        let (g, s, t) = bezout(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * s + 46 * t, 2);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(3i64, 1_000_000_006, 1_000_000_007), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli that are not coprime
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt::<i32>([]), Some((0, 1)));
+        // buses leaving at times that are multiples of their ids, with given offsets
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences = buses.map(|(id, offset): (i64, i64)| (-offset, id));
+        assert_eq!(crt(congruences), Some((1068781, 7 * 13 * 59 * 31 * 19)));
+    }
+}
@@ -0,0 +1,148 @@
+//! Nom parsers for the formats that come up in most inputs
+//!
+//! Parsers return nom errors instead of panicking, and `parse_all` turns them into an `Error`
+//! that gives the line and column where the input could not be parsed.
+
+use std::str::FromStr;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_till1};
+use nom::character::complete::{char, digit1, line_ending, space0, space1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair};
+use nom::{IResult, Parser};
+
+use crate::{Error, Result};
+
+type NomError<'a> = nom::error::Error<&'a str>;
+
+/// Parses a sequence of digits as an unsigned integer
+///
+/// Fails (without consuming the input) if the value does not fit in `T`.
+pub fn parse_unsigned_int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, |number: &str| number.parse::<T>())(input)
+}
+
+/// Parses an integer with an optional sign (`+` or `-`)
+///
+/// Fails (without consuming the input) if the value does not fit in `T`.
+pub fn parse_int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(
+        recognize(pair(opt(alt((char('-'), char('+')))), digit1)),
+        |number: &str| number.parse::<T>(),
+    )(input)
+}
+
+/// Parses `text`, a slice of `input` (like a word of a line), with its `FromStr` implementation
+///
+/// Fails with the position of `text` in `input` if it is not a valid value.
+pub fn value<T: FromStr>(input: &str, text: &str) -> Result<T> {
+    text.parse().map_err(|_| Error::parse_in(input, text, format!("invalid value {:?}", text)))
+}
+
+/// Parses integers separated by commas (with optional spaces after the commas)
+pub fn comma_separated_ints<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(pair(char(','), space0), parse_int)(input)
+}
+
+/// Parses integers separated by one or more spaces
+pub fn space_separated_ints<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, parse_int)(input)
+}
+
+/// Applies a parser on consecutive lines
+pub fn lines<'a, O>(
+    parser: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, parser)
+}
+
+/// Applies a parser on blocks separated by blank lines
+pub fn blocks<'a, O>(
+    parser: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), parser)
+}
+
+/// Parses a `key: value` line, where the key is any text before the colon
+pub fn key_value<'a, O>(
+    value: impl Parser<&'a str, O, NomError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)> {
+    separated_pair(take_till1(|c| c == ':' || c == '\n'), pair(tag(":"), space0), value)
+}
+
+/// Runs a parser on the whole input
+///
+/// Fails if the parser fails or if it does not consume all of the input (apart from trailing
+/// whitespace), with the position of the first character that could not be parsed.
+pub fn parse_all<'a, O>(parser: impl Parser<&'a str, O, NomError<'a>>, input: &'a str) -> Result<O> {
+    parse_all_in(parser, input, input)
+}
+
+/// Runs a parser on the whole of `part`, a slice of `input` (like a section of the input)
+///
+/// Same as `parse_all`, except that the positions of the errors are given in `input`.
+pub fn parse_all_in<'a, O>(
+    mut parser: impl Parser<&'a str, O, NomError<'a>>,
+    input: &str,
+    part: &'a str,
+) -> Result<O> {
+    let (remaining, output) = parser.parse(part).map_err(|e| Error::from_nom(input, e))?;
+    if !remaining.trim().is_empty() {
+        return Err(Error::parse_in(input, remaining.trim_start(), "unexpected input"));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(error: Error) -> (usize, usize) {
+        match error {
+            Error::Parse { line, column, .. } => (line, column),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(parse_int::<i64>("-12, 3"), Ok((", 3", -12)));
+        assert_eq!(parse_int::<u8>("+255"), Ok(("", 255)));
+        assert!(parse_int::<u8>("256").is_err());
+        assert!(parse_unsigned_int::<u32>("-1").is_err());
+        assert_eq!(comma_separated_ints("1,-2, 3\n4"), Ok(("\n4", vec![1, -2, 3])));
+        assert_eq!(space_separated_ints("7  15   30 x"), Ok((" x", vec![7u64, 15, 30])));
+    }
+
+    #[test]
+    fn test_value() {
+        let input = "12 34\n56 7a";
+        let words: Vec<&str> = input.split_whitespace().collect();
+        assert_eq!(value::<u8>(input, words[2]).unwrap(), 56);
+        assert_eq!(position(value::<u8>(input, words[3]).unwrap_err()), (2, 4));
+    }
+
+    #[test]
+    fn test_structure() {
+        let input = "1,2\n3\n\n4\n5,6\n";
+        let expected = vec![vec![vec![1, 2], vec![3]], vec![vec![4], vec![5, 6]]];
+        assert_eq!(parse_all(blocks(lines(comma_separated_ints::<i32>)), input).unwrap(), expected);
+        let (_, (key, value)) = key_value(space_separated_ints::<i32>)("Time:   7  15").unwrap();
+        assert_eq!((key, value), ("Time", vec![7, 15]));
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let parser = || lines(comma_separated_ints::<u8>);
+        assert_eq!(parse_all(parser(), "1,2\n3\n").unwrap(), vec![vec![1, 2], vec![3]]);
+        // the line parser stops before the invalid line
+        assert_eq!(position(parse_all(parser(), "1,2\n3,4\nx").unwrap_err()), (3, 1));
+        assert_eq!(position(parse_all(parser(), "1,2\n3,4 x").unwrap_err()), (2, 5));
+        assert_eq!(position(parse_all(parser(), "1000").unwrap_err()), (1, 1));
+        let input = "numbers:\n1,2\n3 4";
+        let part = &input[9..];
+        assert_eq!(position(parse_all_in(parser(), input, part).unwrap_err()), (3, 3));
+    }
+}
@@ -0,0 +1,120 @@
+//! Polygons with integer vertices
+//!
+//! Areas are computed with the shoelace formula and lattice points are counted with Pick's
+//! theorem, so the cost only depends on the number of vertices and not on the size of the polygon.
+
+use super::number;
+use super::Direction;
+
+/// A closed polygon with integer vertices, the last vertex being joined to the first one
+///
+/// The polygon must be simple (its edges do not cross), but vertices can be given in either
+/// orientation and consecutive edges can be aligned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<(i64, i64)>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(i64, i64)>) -> Self {
+        Self { vertices }
+    }
+
+    /// Polygon drawn on a grid by following moves (direction and number of steps) from `(0, 0)`
+    ///
+    /// Vertices are grid positions `(i, j)`. Returns `None` if the moves do not come back to the
+    /// start.
+    pub fn from_moves(moves: impl IntoIterator<Item = (Direction, i64)>) -> Option<Self> {
+        let mut vertices = vec![(0, 0)];
+        let (mut i, mut j) = (0, 0);
+        for (direction, length) in moves {
+            let (di, dj) = direction.offset();
+            (i, j) = (i + di * length, j + dj * length);
+            vertices.push((i, j));
+        }
+        if vertices.pop() != Some((0, 0)) {
+            return None;
+        }
+        Some(Self { vertices })
+    }
+
+    pub fn vertices(&self) -> &[(i64, i64)] {
+        &self.vertices
+    }
+
+    /// Iterates over the edges, as pairs of consecutive vertices
+    fn edges(&self) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+        let next_vertices = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next_vertices.copied())
+    }
+
+    /// Twice the area of the polygon (which is an integer, unlike the area itself)
+    pub fn double_area(&self) -> i64 {
+        self.edges().map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1).sum::<i64>().abs()
+    }
+
+    /// Number of lattice points on the edges of the polygon
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|((x1, y1), (x2, y2))| number::gcd((x2 - x1).abs(), (y2 - y1).abs())).sum()
+    }
+
+    /// Number of lattice points strictly inside the polygon (Pick's theorem)
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// Number of lattice points inside the polygon or on its edges (which is the number of cells
+    /// covered when the vertices are the centers of cells of a grid)
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle() {
+        let triangle = Polygon::new(vec![(0, 0), (4, 0), (0, 3)]);
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+        assert_eq!(triangle.lattice_points(), 11);
+        // same triangle in the other orientation
+        let triangle = Polygon::new(vec![(0, 3), (4, 0), (0, 0)]);
+        assert_eq!(triangle.double_area(), 12);
+    }
+
+    #[test]
+    fn test_from_moves() {
+        // L-shaped polygon, with an aligned vertex
+        let moves = "R 4,D 2,D 2,L 2,U 2,L 2,U 2";
+        let moves = moves.split(',').map(|m| {
+            let (direction, length) = m.split_once(' ').unwrap();
+            (Direction::from_char(direction.chars().next().unwrap()).unwrap(), length.parse().unwrap())
+        });
+        let polygon = Polygon::from_moves(moves).unwrap();
+        assert_eq!(polygon.vertices().len(), 7);
+        assert_eq!(polygon.double_area(), 24);
+        assert_eq!(polygon.boundary_points(), 16);
+        assert_eq!(polygon.interior_points(), 5);
+        assert_eq!(polygon.lattice_points(), 21);
+        // open path
+        assert_eq!(Polygon::from_moves([(Direction::East, 2), (Direction::South, 1)]), None);
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let size = 1_000_000_000;
+        let square = Polygon::from_moves([
+            (Direction::East, size),
+            (Direction::South, size),
+            (Direction::West, size),
+            (Direction::North, size),
+        ])
+        .unwrap();
+        assert_eq!(square.double_area(), 2 * size * size);
+        assert_eq!(square.lattice_points(), (size + 1) * (size + 1));
+    }
+}
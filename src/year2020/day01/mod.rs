@@ -1,6 +1,10 @@
-use crate::utils;
+use crate::utils::parse;
+use crate::{utils, Error, Result};
 
-fn find_sum(values: &Vec<i32>, target: i32) -> Option<(i32, i32)> {
+fn find_sum(values: &[i32], target: i32) -> Option<(i32, i32)> {
+    if values.len() < 2 {
+        return None;
+    }
     let mut i = 0;
     let mut j = values.len() - 1;
 
@@ -17,34 +21,41 @@ fn find_sum(values: &Vec<i32>, target: i32) -> Option<(i32, i32)> {
     None
 }
 
-fn parse_input(filename: &str) -> Vec<i32> {
+fn parse_input(input: &str) -> Result<Vec<i32>> {
     let mut values = Vec::new();
-    let input = utils::read_input(filename).unwrap();
     for line in input.lines() {
-        values.push(line.parse::<i32>().unwrap());
+        values.push(parse::value(input, line)?);
     }
-    values
+    Ok(values)
 }
-pub fn solve1() -> i32 {
-    let mut values = parse_input("src/year2020/day01/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let mut values = parse_input(input)?;
     values.sort();
 
-    if let Some((x, y)) = find_sum(&values, 2020) {
-        return x * y;
+    match find_sum(&values, 2020) {
+        Some((x, y)) => Ok(x * y),
+        None => Err(Error::no_solution("no two entries sum to 2020")),
     }
-    0
 }
 
-pub fn solve2() -> i32 {
-    let mut values = parse_input("src/year2020/day01/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let mut values = parse_input(input)?;
     values.sort();
 
     for v in &values {
         if let Some((a, b)) = find_sum(&values, 2020 - v) {
-            return a * b * v;
+            return Ok(a * b * v);
         }
     }
-    0
+    Err(Error::no_solution("no three entries sum to 2020"))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 1).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 1).unwrap()).unwrap()
 }
 
 #[cfg(test)]
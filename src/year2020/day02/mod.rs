@@ -1,4 +1,5 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, anychar, char},
@@ -15,27 +16,27 @@ fn parse_line(input: &str) -> IResult<&str, (i32, i32, char, &str)> {
     ))(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day02/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<(i32, i32, char, &str)>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut total = 0;
     
-    for line in input.lines() {
-        let (_, (min, max, letter, password)) = parse_line(line).unwrap();
+    for (min, max, letter, password) in parse_input(input)? {
         let n = password.chars().filter(|c| c == &letter).count();
         if (min as usize..=max as usize).contains(&n) {
             total += 1;
         }
     }
     
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/day02/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut total = 0;
     
-    for line in input.lines() {
-        let (_, (min, max, letter, password)) = parse_line(line).unwrap();
+    for (min, max, letter, password) in parse_input(input)? {
         let a = password.chars().nth(min as usize - 1).unwrap();
         let b = password.chars().nth(max as usize - 1).unwrap();
         if (a == letter) ^ (b == letter) {
@@ -43,7 +44,15 @@ pub fn solve2() -> i32 {
         }
     }
     
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 2).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 2).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,38 +1,32 @@
-use crate::utils;
+use crate::utils::{self, Grid};
+use crate::{Error, Result};
 
 struct Map {
-    grid: Vec<Vec<bool>>,
-    width: usize,
-    height: usize,
+    grid: Grid<bool>,
 }
 
 impl Map {
-    fn new(filename: &str) -> Self {
-        let input = utils::read_input(filename).unwrap();
-        let mut grid = Vec::new();
-        for line in input.lines() {
-            let mut row = Vec::new();
-            for c in line.chars() {
-                row.push(c == '#');
-            }
-            grid.push(row);
-        }
-        Map {
-            height: grid.len(),
-            width: grid[0].len(),
-            grid,
+    fn new(input: &str) -> Result<Self> {
+        let grid = Grid::try_parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if grid.width() == 0 {
+            return Err(Error::parse_at(input, 0, "empty map"));
         }
+        Ok(Map { grid })
     }
 
     fn get(&self, i: usize, j: usize) -> bool {
-        self.grid[i][j % self.width]
+        self.grid[(i, j % self.grid.width())]
     }
 
     fn check_slope(&self, di: usize, dj: usize) -> i32 {
         let mut total = 0;
         let (mut i, mut j) = (0, 0);
 
-        while i < self.height {
+        while i < self.grid.height() {
             if self.get(i, j) {
                 total += 1;
             }
@@ -44,19 +38,26 @@ impl Map {
     }
 }
 
-pub fn solve1() -> i32 {
-    let map = Map::new("src/year2020/day03/input.txt");
-    
-    map.check_slope(1, 3)
+pub fn part1(input: &str) -> Result<i32> {
+    let map = Map::new(input)?;
+    Ok(map.check_slope(1, 3))
 }
 
-pub fn solve2() -> i64 {
-    let map = Map::new("src/year2020/day03/input.txt");
+pub fn part2(input: &str) -> Result<i64> {
+    let map = Map::new(input)?;
     let mut total = 1;
     for (di, dj) in [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)] {
         total *= map.check_slope(di, dj) as i64;
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 3).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 3).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -8,16 +8,13 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::{utils, Result};
 
 fn as_date(input: &str) -> Option<i32> {
     if input.len() != 4 {
         return None;
     }
-    match input.parse::<i32>() {
-        Ok(v) => Some(v),
-        _ => None,
-    }
+    input.parse::<i32>().ok()
 }
 
 fn check_items(items: HashMap<&str, &str>) -> bool {
@@ -43,19 +40,19 @@ fn check_items(items: HashMap<&str, &str>) -> bool {
     }
 
     let hgt = items.get("hgt").unwrap();
-    if hgt.ends_with("cm") {
-        match hgt[..hgt.len() - 2].parse::<i32>() {
+    if let Some(hgt) = hgt.strip_suffix("cm") {
+        match hgt.parse::<i32>() {
             Ok(v) => {
-                if v < 150 || v > 193 {
+                if !(150..=193).contains(&v) {
                     return false;
                 }
             }
             _ => return false,
         }
-    } else if hgt.ends_with("in") {
-        match hgt[..hgt.len() - 2].parse::<i32>() {
+    } else if let Some(hgt) = hgt.strip_suffix("in") {
+        match hgt.parse::<i32>() {
             Ok(v) => {
-                if v < 59 || v > 76 {
+                if !(59..=76).contains(&v) {
                     return false;
                 }
             }
@@ -66,7 +63,7 @@ fn check_items(items: HashMap<&str, &str>) -> bool {
     }
 
     let hcl = items.get("hcl").unwrap();
-    if hcl.len() != 7 || !hcl.starts_with("#") || !hcl[1..].chars().all(|c| c.is_digit(16)) {
+    if hcl.len() != 7 || !hcl.starts_with("#") || !hcl[1..].chars().all(|c| c.is_ascii_hexdigit()) {
         return false;
     }
 
@@ -76,7 +73,7 @@ fn check_items(items: HashMap<&str, &str>) -> bool {
     }
 
     let pid = items.get("pid").unwrap();
-    if pid.len() != 9 || !pid.chars().all(|c| c.is_digit(10)) {
+    if pid.len() != 9 || !pid.chars().all(|c| c.is_ascii_digit()) {
         return false;
     }
 
@@ -100,9 +97,8 @@ fn parse_input(input: &str) -> IResult<&str, Vec<HashMap<&str, &str>>> {
     separated_list1(tag("\n\n"), parse_passport)(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day04/input.txt").unwrap();
-    let (_, input_data) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let input_data = utils::parse_all(parse_input, input)?;
     let mut counter = 0;
     'outer: for items in input_data {
         for key in ["byr", "iyr", "eyr", "hgt", "hcl", "pid", "ecl"] {
@@ -112,19 +108,26 @@ pub fn solve1() -> i32 {
         }
         counter += 1;
     }
-    counter
+    Ok(counter)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/day04/input.txt").unwrap();
-    let (_, input_data) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let input_data = utils::parse_all(parse_input, input)?;
     let mut counter = 0;
     for items in input_data {
         if check_items(items) {
             counter += 1;
         }
     }
-    counter
+    Ok(counter)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 4).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 4).unwrap()).unwrap()
 }
 
 #[cfg(test)]
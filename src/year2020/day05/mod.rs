@@ -1,51 +1,58 @@
-use crate::utils;
+use crate::{utils, Error, Result};
 
-pub fn get_codes() -> Vec<i32> {
-    let input = utils::read_input("src/year2020/day05/input.txt").unwrap();
+pub fn get_codes(input: &str) -> Result<Vec<i32>> {
     let mut codes = Vec::new();
 
     for line in input.lines() {
         let mut min = 0;
         let mut max = 128;
-        for c in line.chars().take(7) {
+        for (k, c) in line.char_indices().take(7) {
             let mid = (min + max) / 2;
             match c {
                 'F' => max = mid,
                 'B' => min = mid,
-                _ => panic!("Invalid character"),
+                _ => return Err(Error::parse_in(input, &line[k..], "expected F or B")),
             }
         }
         let row = min;
         min = 0;
         max = 8;
-        for c in line.chars().skip(7).take(3) {
+        for (k, c) in line.char_indices().skip(7).take(3) {
             let mid = (min + max) / 2;
             match c {
                 'L' => max = mid,
                 'R' => min = mid,
-                _ => panic!("Invalid character"),
+                _ => return Err(Error::parse_in(input, &line[k..], "expected L or R")),
             }
         }
         let col = min;
         codes.push(row * 8 + col);
     }
     codes.sort();
-    return codes;
+    Ok(codes)
 }
 
-pub fn solve1() -> i32 {
-    let codes = get_codes();
-    *codes.last().unwrap()
+pub fn part1(input: &str) -> Result<i32> {
+    let codes = get_codes(input)?;
+    codes.last().copied().ok_or_else(|| Error::no_solution("no boarding pass"))
 }
 
-pub fn solve2() -> i32 {
-    let codes = get_codes();
+pub fn part2(input: &str) -> Result<i32> {
+    let codes = get_codes(input)?;
     for i in 1..codes.len() {
         if codes[i] - codes[i - 1] == 2 {
-            return codes[i] - 1;
+            return Ok(codes[i] - 1);
         }
     }
-    0
+    Err(Error::no_solution("no free seat between two taken seats"))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 5).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 5).unwrap()).unwrap()
 }
 
 #[cfg(test)]
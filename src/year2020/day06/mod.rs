@@ -1,18 +1,17 @@
 use std::collections::HashSet;
 
-use nom::{
-    bytes::complete::tag, character::complete::alpha1, multi::separated_list1, IResult,
-};
+use nom::{character::complete::alpha1, IResult};
 
 use crate::utils;
+use crate::utils::parse;
+use crate::Result;
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
-    separated_list1(tag("\n\n"), separated_list1(tag("\n"), alpha1))(input)
+    parse::blocks(parse::lines(alpha1))(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day06/input.txt").unwrap();
-    let (_, input_data) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let input_data = utils::parse_all(parse_input, input)?;
 
     let mut total = 0;
     for group in input_data {
@@ -23,12 +22,11 @@ pub fn solve1() -> i32 {
         total += letters.len();
     }
 
-    total as i32
+    Ok(total as i32)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/day06/input.txt").unwrap();
-    let (_, input_data) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let input_data = utils::parse_all(parse_input, input)?;
 
     let mut total = 0;
     for group in input_data {
@@ -39,7 +37,15 @@ pub fn solve2() -> i32 {
         }
         total += letters.len();
     }
-    total as i32
+    Ok(total as i32)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 6).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 6).unwrap()).unwrap()
 }
 
 #[cfg(test)]
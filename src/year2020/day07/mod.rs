@@ -3,14 +3,18 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{alpha1, digit1},
+    character::complete::alpha1,
     combinator::{map, opt},
     multi::separated_list1,
     sequence::{pair, separated_pair, terminated},
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
+
+/// Bags directly contained in a bag, with their counts
+type Contents = Vec<(i32, String)>;
 
 fn parse_bag_name(input: &str) -> IResult<&str, String> {
     terminated(
@@ -21,7 +25,7 @@ fn parse_bag_name(input: &str) -> IResult<&str, String> {
     )(input)
 }
 
-fn parse_line(input: &str) -> IResult<&str, (String, Vec<(i32, String)>)> {
+fn parse_line(input: &str) -> IResult<&str, (String, Contents)> {
     separated_pair(
         parse_bag_name,
         tag(" contain "),
@@ -30,7 +34,7 @@ fn parse_line(input: &str) -> IResult<&str, (String, Vec<(i32, String)>)> {
             separated_list1(
                 tag(", "),
                 separated_pair(
-                    map(digit1, |s: &str| s.parse::<i32>().unwrap()),
+                    utils::parse_unsigned_int,
                     tag(" "),
                     parse_bag_name,
                 ),
@@ -39,17 +43,16 @@ fn parse_line(input: &str) -> IResult<&str, (String, Vec<(i32, String)>)> {
     )(input)
 }
 
-fn get_rules(filename: &str) -> HashMap<String, Vec<(i32, String)>> {
-    let input = utils::read_input(filename).unwrap();
+fn get_rules(input: &str) -> Result<HashMap<String, Contents>> {
+    let lines = utils::parse_all(parse::lines(terminated(parse_line, tag("."))), input)?;
     let mut rules = HashMap::new();
-    for line in input.lines() {
-        let (_, (bag, contents)) = parse_line(line).unwrap();
+    for (bag, contents) in lines {
         rules.insert(bag, contents);
     }
-    rules
+    Ok(rules)
 }
 
-fn nb_bags(rules: &HashMap<String, Vec<(i32, String)>>, color: &str) -> i32 {
+fn nb_bags(rules: &HashMap<String, Contents>, color: &str) -> i32 {
     let mut result = 0;
     for (count, color) in &rules[color] {
         result += count * (1 + nb_bags(rules, color));
@@ -57,8 +60,8 @@ fn nb_bags(rules: &HashMap<String, Vec<(i32, String)>>, color: &str) -> i32 {
     result
 }
 
-pub fn solve1() -> i32 {
-    let rules = get_rules("src/year2020/day07/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let rules = get_rules(input)?;
     let mut colors = HashSet::new();
     let mut queue = VecDeque::new();
     queue.push_back("shiny gold".to_string());
@@ -75,18 +78,28 @@ pub fn solve1() -> i32 {
         }
     }
 
-    colors.len() as i32
+    Ok(colors.len() as i32)
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    let rules = get_rules(input)?;
+    Ok(nb_bags(&rules, "shiny gold"))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 7).unwrap()).unwrap()
 }
 
 pub fn solve2() -> i32 {
-    let rules = get_rules("src/year2020/day07/input.txt");
-    nb_bags(&rules, "shiny gold")
+    part2(&utils::read_day_input(2020, 7).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2020, 7);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
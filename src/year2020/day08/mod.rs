@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use nom::{branch::alt, bytes::complete::tag, combinator::map, sequence::preceded, IResult, multi::separated_list1};
 
-use crate::utils;
+use crate::{utils, Error, Result};
 
 #[derive(Debug, PartialEq)]
 enum Instruction {
@@ -33,7 +33,7 @@ fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
     separated_list1(tag("\n"), parse_instruction)(input)
 }
 
-fn execute(instructions: &Vec<Instruction>) -> (i32, bool) {
+fn execute(instructions: &[Instruction]) -> (i32, bool) {
     let mut pc = 0;
     let mut acc = 0;
     let mut visited = HashSet::new();
@@ -63,7 +63,7 @@ fn execute(instructions: &Vec<Instruction>) -> (i32, bool) {
     }
 }
 
-fn swap(instructions: &mut Vec<Instruction>, i: usize) {
+fn swap(instructions: &mut [Instruction], i: usize) {
     match instructions[i] {
         Instruction::Acc(_) => (),
         Instruction::Jmp(value) => instructions[i] = Instruction::Nop(value),
@@ -71,25 +71,31 @@ fn swap(instructions: &mut Vec<Instruction>, i: usize) {
     }
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day08/input.txt").unwrap();
-    let (_, instructions) = parse_instructions(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let instructions = utils::parse_all(parse_instructions, input)?;
     let (acc, _) = execute(&instructions);
-    acc
+    Ok(acc)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/day08/input.txt").unwrap();
-    let (_, mut instructions) = parse_instructions(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let mut instructions = utils::parse_all(parse_instructions, input)?;
     for i in 0..instructions.len() {
         swap(&mut instructions, i);
         let (acc, terminated) = execute(&instructions);
         if terminated {
-            return acc;
+            return Ok(acc);
         }
         swap(&mut instructions, i);
     }
-    0
+    Err(Error::no_solution("no single swap makes the program terminate"))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 8).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 8).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,6 +1,10 @@
 use std::collections::VecDeque;
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
+
+/// Number of previous numbers that the next number must be a sum of
+const PREAMBLE_LENGTH: usize = 25;
 
 fn is_sum_of_two(num: i64, previous: &VecDeque<i64>) -> bool {
     for i in 0..previous.len() {
@@ -13,39 +17,37 @@ fn is_sum_of_two(num: i64, previous: &VecDeque<i64>) -> bool {
     false
 }
 
-fn make_list() -> Vec<i64> {
-    let input = utils::read_input("src/year2020/day09/input.txt").unwrap();
+fn make_list(input: &str) -> Result<Vec<i64>> {
     let mut numbers = Vec::new();
     for line in input.lines() {
-        numbers.push(line.parse::<i64>().unwrap());
+        numbers.push(parse::value(input, line)?);
     }
-    numbers
+    Ok(numbers)
 }
 
-fn find_target(numbers: &Vec<i64>) -> i64 {
-    let mut previous = VecDeque::new();
-    for i in 0..25 {
-        previous.push_back(numbers[i]);
+fn find_target(numbers: &[i64]) -> Result<i64> {
+    if numbers.len() <= PREAMBLE_LENGTH {
+        return Err(Error::no_solution(format!("expected more than {} numbers", PREAMBLE_LENGTH)));
     }
-    let mut i = 25;
-    loop {
-        if !is_sum_of_two(numbers[i], &previous) {
-            return numbers[i];
+    let mut previous: VecDeque<i64> = numbers[..PREAMBLE_LENGTH].iter().copied().collect();
+    for &number in &numbers[PREAMBLE_LENGTH..] {
+        if !is_sum_of_two(number, &previous) {
+            return Ok(number);
         }
         previous.pop_front();
-        previous.push_back(numbers[i]);
-        i += 1;
+        previous.push_back(number);
     }
+    Err(Error::no_solution("every number is the sum of two of the previous ones"))
 }
 
-pub fn solve1() -> i64 {
-    let numbers = make_list();
+pub fn part1(input: &str) -> Result<i64> {
+    let numbers = make_list(input)?;
     find_target(&numbers)
 }
 
-pub fn solve2() -> i64 {
-    let numbers = make_list();
-    let target = find_target(&numbers);
+pub fn part2(input: &str) -> Result<i64> {
+    let numbers = make_list(input)?;
+    let target = find_target(&numbers)?;
     let mut i = 0;
     let mut j = 0;
     let mut total = 0;
@@ -54,13 +56,16 @@ pub fn solve2() -> i64 {
         if total == target {
             let mut min = numbers[i];
             let mut max = numbers[i];
-            for k in i..j {
-                min = min.min(numbers[k]);
-                max = max.max(numbers[k]);
+            for &number in &numbers[i..j] {
+                min = min.min(number);
+                max = max.max(number);
             }
-            return min + max;
+            return Ok(min + max);
         } else if total < target {
-            total += &numbers[j];
+            let Some(number) = numbers.get(j) else {
+                return Err(Error::no_solution("no contiguous set of numbers sums to the target"));
+            };
+            total += number;
             j += 1;
         } else {
             total -= &numbers[i];
@@ -69,6 +74,14 @@ pub fn solve2() -> i64 {
     }
 }
 
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2020, 9).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 9).unwrap()).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,6 +97,6 @@ mod tests {
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
-        // assert_eq!(solution, 0);
+        assert_eq!(solution, 169521051);
     }
 }
@@ -1,20 +1,20 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day10/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
     let mut values = input
         .lines()
-        .map(|line| line.parse::<i32>().unwrap())
-        .collect::<Vec<i32>>();
+        .map(|line| parse::value(input, line))
+        .collect::<Result<Vec<i32>>>()?;
     values.sort();
     values.insert(0, 0);
     values.push(values[values.len() - 1] + 3);
 
-    let mut counter = vec![0, 0, 0, 0];
+    let mut counter = [0, 0, 0, 0];
     for i in 0..values.len() - 1 {
         counter[(values[i + 1] - values[i]) as usize] += 1;
     }
-    counter[1] * counter[3]
+    Ok(counter[1] * counter[3])
 }
 
 fn count_paths(values: &Vec<i32>, index: usize, memo: &mut Vec<Option<i64>>) -> i64 {
@@ -37,18 +37,25 @@ fn count_paths(values: &Vec<i32>, index: usize, memo: &mut Vec<Option<i64>>) ->
     count
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2020/day10/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut values = input
         .lines()
-        .map(|line| line.parse::<i32>().unwrap())
-        .collect::<Vec<i32>>();
+        .map(|line| parse::value(input, line))
+        .collect::<Result<Vec<i32>>>()?;
     values.sort();
     values.insert(0, 0);
     values.push(values[values.len() - 1] + 3);
 
     let mut memo = vec![None; values.len()];
-    count_paths(&values, 0, &mut memo)
+    Ok(count_paths(&values, 0, &mut memo))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 10).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 10).unwrap()).unwrap()
 }
 
 #[cfg(test)]
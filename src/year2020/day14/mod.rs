@@ -8,7 +8,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
 enum Instruction {
     Mask(String),
@@ -117,13 +118,15 @@ fn parse_line(input: &str) -> IResult<&str, Instruction> {
     ))(input)
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2020/day14/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
     let mut memory = HashMap::new();
     let mut bitmask_0 = 0;
     let mut bitmask_1 = 0;
-    for line in input.lines() {
-        let (_, instruction) = parse_line(line).unwrap();
+    for instruction in parse_input(input)? {
         match instruction {
             Instruction::Mask(mask) => {
                 bitmask_0 = 0; // positions of 0s in the mask
@@ -142,16 +145,14 @@ pub fn solve1() -> i64 {
             }
         }
     }
-    memory.values().sum()
+    Ok(memory.values().sum())
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2020/day14/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut memory: HashMap<Range, i64> = HashMap::new();
     let mut bitmask_1 = 0;
     let mut bitmask_x = 0;
-    for line in input.lines() {
-        let (_, instruction) = parse_line(line).unwrap();
+    for instruction in parse_input(input)? {
         match instruction {
             Instruction::Mask(mask_string) => {
                 // update mask
@@ -186,7 +187,15 @@ pub fn solve2() -> i64 {
     for (range, value) in memory.iter() {
         total += range.get_nb_addresses() * value;
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2020, 14).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 14).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -201,6 +210,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
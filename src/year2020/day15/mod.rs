@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 
-fn get_nth_number(starting_numbers: &Vec<usize>, n: usize) -> usize {
+use crate::utils::{self, parse};
+use crate::Result;
+
+fn get_nth_number(starting_numbers: &[usize], n: usize) -> usize {
     let mut known_numbers = HashMap::new();
-    for i in 0..starting_numbers.len() - 1 {
-        known_numbers.insert(starting_numbers[i], i);
+    for (i, &number) in starting_numbers[..starting_numbers.len() - 1].iter().enumerate() {
+        known_numbers.insert(number, i);
     }
     let mut last_number = *starting_numbers.last().unwrap();
     for i in starting_numbers.len() - 1..n-1 {
@@ -17,12 +20,24 @@ fn get_nth_number(starting_numbers: &Vec<usize>, n: usize) -> usize {
     last_number
 }
 
+fn parse_input(input: &str) -> Result<Vec<usize>> {
+    input.trim().split(',').map(|x| parse::value(input, x)).collect()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(get_nth_number(&parse_input(input)?, 2020))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(get_nth_number(&parse_input(input)?, 30000000))
+}
+
 pub fn solve1() -> usize {
-    get_nth_number(&vec![0,13,1,8,6,15], 2020)
+    part1(&utils::read_day_input(2020, 15).unwrap()).unwrap()
 }
 
 pub fn solve2() -> usize {
-    get_nth_number(&vec![0,13,1,8,6,15], 30000000)
+    part2(&utils::read_day_input(2020, 15).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -31,12 +46,12 @@ mod tests {
 
     #[test]
     fn test_examples() {
-        assert_eq!(get_nth_number(&vec![1,3,2], 2020), 1);
-        assert_eq!(get_nth_number(&vec![2,1,3], 2020), 10);
-        assert_eq!(get_nth_number(&vec![1,2,3], 2020), 27);
-        assert_eq!(get_nth_number(&vec![2,3,1], 2020), 78);
-        assert_eq!(get_nth_number(&vec![3,2,1], 2020), 438);
-        assert_eq!(get_nth_number(&vec![3,1,2], 2020), 1836);
+        assert_eq!(get_nth_number(&[1,3,2], 2020), 1);
+        assert_eq!(get_nth_number(&[2,1,3], 2020), 10);
+        assert_eq!(get_nth_number(&[1,2,3], 2020), 27);
+        assert_eq!(get_nth_number(&[2,3,1], 2020), 78);
+        assert_eq!(get_nth_number(&[3,2,1], 2020), 438);
+        assert_eq!(get_nth_number(&[3,1,2], 2020), 1836);
     }
 
     #[test]
@@ -47,7 +62,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (14s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (12s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
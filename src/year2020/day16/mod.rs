@@ -1,14 +1,15 @@
 use std::collections::{HashSet, HashMap};
 
 use nom::{
-    bytes::complete::{is_not, tag},
+    bytes::complete::tag,
     combinator::map,
-    multi::separated_list1,
-    sequence::{separated_pair, terminated, tuple},
+    sequence::{delimited, preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
 use crate::utils;
+use crate::utils::parse;
+use crate::Result;
 
 struct Field {
     name: String,
@@ -26,30 +27,20 @@ impl Field {
     }
 }
 
+/// Fields rules, your ticket and nearby tickets
+type Notes = (Vec<Field>, Vec<i32>, Vec<Vec<i32>>);
+
+fn parse_range(input: &str) -> IResult<&str, (i32, i32)> {
+    separated_pair(utils::parse_int, tag("-"), utils::parse_int)(input)
+}
+
 fn parse_field_line(input: &str) -> IResult<&str, Field> {
     map(
-        tuple((
-            terminated(is_not(":"), tag(": ")),
-            terminated(
-                separated_pair(utils::parse_int, tag("-"), utils::parse_int),
-                tag(" or "),
-            ),
-            separated_pair(utils::parse_int, tag("-"), utils::parse_int),
-        )),
-        |(name, range1, range2)| Field::new(name, range1, range2),
+        parse::key_value(separated_pair(parse_range, tag(" or "), parse_range)),
+        |(name, (range1, range2))| Field::new(name, range1, range2),
     )(input)
 }
 
-fn make_fields() -> Vec<Field> {
-    let input = utils::read_input("src/year2020/day16/fields.txt").unwrap();
-    let mut fields = Vec::new();
-    for line in input.lines() {
-        let (_, field) = parse_field_line(line).unwrap();
-        fields.push(field);
-    }
-    fields
-}
-
 fn is_valid_field(num: i32, fields: &Vec<Field>) -> bool {
     for field in fields {
         if (num >= field.range1.0 && num <= field.range1.1)
@@ -70,18 +61,18 @@ fn is_valid_ticket(ticket: &Vec<i32>, fields: &Vec<Field>) -> bool {
     true
 }
 
-fn parse_ticket(input: &str) -> IResult<&str, Vec<i32>> {
-    separated_list1(tag(","), utils::parse_int)(input)
+/// Reads the notes as the fields rules, your ticket and the nearby tickets
+fn parse_notes(input: &str) -> Result<Notes> {
+    let notes = tuple((
+        terminated(parse::lines(parse_field_line), tag("\n\n")),
+        delimited(tag("your ticket:\n"), parse::comma_separated_ints, tag("\n\n")),
+        preceded(tag("nearby tickets:\n"), parse::lines(parse::comma_separated_ints)),
+    ));
+    utils::parse_all(notes, input)
 }
 
-pub fn solve1() -> i32 {
-    let fields = make_fields();
-    let tickets_input = utils::read_input("src/year2020/day16/tickets.txt").unwrap();
-    let mut tickets = Vec::new();
-    for line in tickets_input.lines() {
-        let (_, ticket) = parse_ticket(line).unwrap();
-        tickets.push(ticket);
-    }
+pub fn part1(input: &str) -> Result<i32> {
+    let (fields, _, tickets) = parse_notes(input)?;
 
     let mut total = 0;
     for ticket in tickets {
@@ -91,19 +82,12 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let fields = make_fields();
-    let tickets_input = utils::read_input("src/year2020/day16/tickets.txt").unwrap();
-    let mut tickets = Vec::new();
-    for line in tickets_input.lines() {
-        let (_, ticket) = parse_ticket(line).unwrap();
-        if is_valid_ticket(&ticket, &fields) {
-            tickets.push(ticket);
-        }
-    }
+pub fn part2(input: &str) -> Result<i64> {
+    let (fields, ticket, mut tickets) = parse_notes(input)?;
+    tickets.retain(|ticket| is_valid_ticket(ticket, &fields));
 
     let mut possibilities = HashMap::new();
     let mut solution = HashMap::new();
@@ -125,7 +109,7 @@ pub fn solve2() -> i64 {
         possibilities.insert(i, possible_fields);
     }
 
-    while possibilities.len() > 0 {
+    while !possibilities.is_empty() {
         let mut solved_indexes = Vec::new();
         let mut solved_fields = Vec::new();
         for (index, possible_fields) in &possibilities {
@@ -140,21 +124,27 @@ pub fn solve2() -> i64 {
             possibilities.remove(&index);
         }
         for field in solved_fields {
-            for (_, possible_fields) in &mut possibilities {
+            for possible_fields in possibilities.values_mut() {
                 possible_fields.remove(&field);
             }
         }
     }
 
-    let ticket_input = utils::read_input("src/year2020/day16/ticket.txt").unwrap();
-    let (_, ticket) = parse_ticket(&ticket_input).unwrap();
     let mut total = 1;
     for (index, field) in &solution {
         if field.starts_with("departure ") {
             total *= ticket[*index] as i64;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 16).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 16).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,93 +1,17 @@
 use std::collections::HashSet;
 
 use crate::utils;
+use crate::utils::geometry::{Point, Point3, Point4};
+use crate::{Error, Result};
 
-trait Point
-where
-    Self: Sized,
-    Self: std::hash::Hash,
-    Self: std::cmp::Eq,
-    Self: std::clone::Clone,
-{
-    fn get_neighbors(&self) -> Vec<Self>;
+struct Configuration<const N: usize> {
+    cells: HashSet<Point<N>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point3D {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl Point3D {
-    fn new(x: i32, y: i32, z: i32) -> Point3D {
-        Point3D { x, y, z }
-    }
-}
-
-impl Point for Point3D {
-    fn get_neighbors(&self) -> Vec<Point3D> {
-        let mut neighbors = Vec::new();
-        for dx in -1..=1 {
-            for dy in -1..=1 {
-                for dz in -1..=1 {
-                    if dx == 0 && dy == 0 && dz == 0 {
-                        continue;
-                    }
-                    neighbors.push(Point3D::new(self.x + dx, self.y + dy, self.z + dz));
-                }
-            }
-        }
-        neighbors
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point4D {
-    x: i32,
-    y: i32,
-    z: i32,
-    w: i32,
-}
-
-impl Point4D {
-    fn new(x: i32, y: i32, z: i32, w: i32) -> Point4D {
-        Point4D { x, y, z, w }
-    }
-}
-
-impl Point for Point4D {
-    fn get_neighbors(&self) -> Vec<Point4D> {
-        let mut neighbors = Vec::new();
-        for dx in -1..=1 {
-            for dy in -1..=1 {
-                for dz in -1..=1 {
-                    for dw in -1..=1 {
-                        if dx == 0 && dy == 0 && dz == 0 && dw == 0 {
-                            continue;
-                        }
-                        neighbors.push(Point4D::new(
-                            self.x + dx,
-                            self.y + dy,
-                            self.z + dz,
-                            self.w + dw,
-                        ));
-                    }
-                }
-            }
-        }
-        neighbors
-    }
-}
-
-struct Configuration<T: Point> {
-    cells: HashSet<T>,
-}
-
-impl<T: Point> Configuration<T> {
-    fn count_active_neighbors(&self, p: &T) -> i32 {
+impl<const N: usize> Configuration<N> {
+    fn count_active_neighbors(&self, p: &Point<N>) -> i32 {
         let mut count = 0;
-        for n in p.get_neighbors() {
+        for n in p.all_neighbors() {
             if self.cells.contains(&n) {
                 count += 1;
             }
@@ -95,19 +19,19 @@ impl<T: Point> Configuration<T> {
         count
     }
 
-    fn next(&self) -> Configuration<T> {
+    fn next(&self) -> Configuration<N> {
         let mut next_config = HashSet::new();
         for p in &self.cells {
             let count = self.count_active_neighbors(p);
             if count == 2 || count == 3 {
-                next_config.insert(p.clone());
+                next_config.insert(*p);
             }
 
-            for n in p.get_neighbors() {
+            for n in p.all_neighbors() {
                 if !self.cells.contains(&n) {
                     let count = self.count_active_neighbors(&n);
                     if count == 3 {
-                        next_config.insert(n.clone());
+                        next_config.insert(n);
                     }
                 }
             }
@@ -116,42 +40,55 @@ impl<T: Point> Configuration<T> {
     }
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2020/day17/input.txt").unwrap();
-    let mut active_cells = HashSet::new();
+/// Coordinates of the active cells of the initial slice
+fn parse_active_cells(input: &str) -> Result<Vec<(usize, usize)>> {
+    let mut cells = Vec::new();
     for (i, line) in input.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            if c == '#' {
-                active_cells.insert(Point3D::new(i as i32, j as i32, 0));
+        for (j, (k, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => cells.push((i, j)),
+                '.' => {}
+                _ => return Err(Error::parse_in(input, &line[k..], "expected '#' or '.'")),
             }
         }
     }
+    Ok(cells)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let active_cells = parse_active_cells(input)?
+        .into_iter()
+        .map(|(i, j)| Point3::new(i as i32, j as i32, 0))
+        .collect();
     let mut c = Configuration {
         cells: active_cells,
     };
     for _ in 0..6 {
         c = c.next();
     }
-    c.cells.len() as i32
+    Ok(c.cells.len() as i32)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/day17/input.txt").unwrap();
-    let mut active_cells = HashSet::new();
-    for (i, line) in input.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            if c == '#' {
-                active_cells.insert(Point4D::new(i as i32, j as i32, 0, 0));
-            }
-        }
-    }
+pub fn part2(input: &str) -> Result<i32> {
+    let active_cells = parse_active_cells(input)?
+        .into_iter()
+        .map(|(i, j)| Point4::new(i as i32, j as i32, 0, 0))
+        .collect();
     let mut c = Configuration {
         cells: active_cells,
     };
     for _ in 0..6 {
         c = c.next();
     }
-    c.cells.len() as i32
+    Ok(c.cells.len() as i32)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 17).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 17).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -166,10 +103,10 @@ mod tests {
     }
 
     #[test]
-    // #[ignore="long test (6s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (6s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
-        // assert_eq!(solution, 0);
+        assert_eq!(solution, 2440);
     }
 }
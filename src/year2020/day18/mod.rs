@@ -1,4 +1,5 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 use nom::{branch::alt, bytes::complete::tag, combinator::map, multi::many1, IResult};
 
 #[derive(Debug, PartialEq)]
@@ -16,7 +17,7 @@ fn parse_line(input: &str) -> IResult<&str, Vec<Token>> {
         map(tag(" * "), |_| Token::Mul),
         map(tag("("), |_| Token::OpenParen),
         map(tag(")"), |_| Token::CloseParen),
-        map(utils::parse_int, |n| Token::Num(n)),
+        map(utils::parse_int, Token::Num),
     )))(input)
 }
 
@@ -35,9 +36,10 @@ fn eval_operand(tokens: &[Token]) -> (i64, usize) {
                 }
                 j += 1;
             }
-            return (eval_expression(&tokens[1..j - 1]), j);
+            (eval_expression(&tokens[1..j - 1]), j)
         }
-        _ => panic!("Invalid operand"),
+        // expressions are checked by `is_well_formed`
+        _ => unreachable!("invalid operand"),
     }
 }
 
@@ -48,7 +50,7 @@ fn eval_expression(tokens: &[Token]) -> i64 {
         match tokens[i] {
             Token::Add => acc += x,
             Token::Mul => acc *= x,
-            _ => panic!("Invalid operator"),
+            _ => unreachable!("invalid operator"),
         }
         i += j + 1;
     }
@@ -78,7 +80,7 @@ fn add_priorities(tokens: &mut Vec<Token>) {
                     }
                     tokens.insert(j, Token::CloseParen);
                 }
-                _ => panic!("Invalid operand"),
+                _ => unreachable!("invalid operand"),
             }
             // add parenthesis before first operand
             match tokens[i - 1] {
@@ -99,7 +101,7 @@ fn add_priorities(tokens: &mut Vec<Token>) {
                     }
                     tokens.insert(j, Token::OpenParen);
                 }
-                _ => panic!("Invalid operand"),
+                _ => unreachable!("invalid operand"),
             }
             i += 2;
         } else {
@@ -108,31 +110,71 @@ fn add_priorities(tokens: &mut Vec<Token>) {
     }
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2020/day18/input.txt").unwrap();
+/// Whether operands and operators alternate, starting and ending with an operand, and
+/// parentheses are balanced
+fn is_well_formed(tokens: &[Token]) -> bool {
+    let mut expect_operand = true;
+    let mut depth = 0;
+    for token in tokens {
+        match (expect_operand, token) {
+            (true, Token::Num(_)) => expect_operand = false,
+            (true, Token::OpenParen) => depth += 1,
+            (false, Token::Add | Token::Mul) => expect_operand = true,
+            (false, Token::CloseParen) if depth > 0 => depth -= 1,
+            _ => return false,
+        }
+    }
+    !expect_operand && depth == 0
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Token>>> {
+    let expressions = utils::parse_all(parse::lines(parse_line), input)?;
+    for (tokens, line) in expressions.iter().zip(input.lines()) {
+        if !is_well_formed(tokens) {
+            return Err(Error::parse_in(input, line, "malformed expression"));
+        }
+    }
+    Ok(expressions)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
     let mut total = 0;
-    for line in input.lines() {
-        let (_, tokens) = parse_line(line).unwrap();
+    for tokens in parse_input(input)? {
         total += eval_expression(&tokens);
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2020/day18/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut total = 0;
-    for line in input.lines() {
-        let (_, mut tokens) = parse_line(line).unwrap();
+    for mut tokens in parse_input(input)? {
         add_priorities(&mut tokens);
         total += eval_expression(&tokens);
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2020, 18).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2020, 18).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_malformed_expressions() {
+        assert_eq!(part1("2 * 3 + (4 * 5)").unwrap(), 26);
+        assert_eq!(part2("2 * 3 + (4 * 5)").unwrap(), 46);
+        for input in ["1 + 2\n(1 + 2", "1 + ()", "(1 + 2))", ")1 + 2("] {
+            assert!(matches!(part2(input), Err(Error::Parse { .. })), "{}", input);
+        }
+    }
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
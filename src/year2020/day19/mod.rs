@@ -11,7 +11,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 
 #[derive(Debug)]
 enum Rule {
@@ -29,64 +30,122 @@ fn parse_rule(input: &str) -> IResult<&str, (i32, Rule)> {
             }),
             map(
                 separated_list1(tag(" | "), separated_list1(tag(" "), utils::parse_int)),
-                |v| Rule::Or(v),
+                Rule::Or,
             ),
         )),
     )(input)
 }
 
-fn make_rules() -> HashMap<i32, Rule> {
-    let input = utils::read_input("src/year2020/day19/rules.txt").unwrap();
+/// Sub-rules referenced by a rule
+fn references(rule: &Rule) -> impl Iterator<Item = &i32> {
+    let sequences = match rule {
+        Rule::Char(_) => &[][..],
+        Rule::Or(v) => &v[..],
+    };
+    sequences.iter().flatten()
+}
+
+/// Parses the rules, checking that all referenced rules are defined and that no rule depends on
+/// itself (rules are expanded into a regular expression, which would be infinite)
+fn make_rules(input: &str) -> Result<HashMap<i32, Rule>> {
+    let parsed = utils::parse_all(parse::lines(parse_rule), input)?;
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut line_of_rule = HashMap::new();
     let mut rules = HashMap::new();
-    for line in input.lines() {
-        let (_, (i, r)) = parse_rule(line).unwrap();
+    for ((i, r), line) in parsed.into_iter().zip(lines) {
+        line_of_rule.insert(i, line);
         rules.insert(i, r);
     }
-    rules
+
+    for (i, rule) in &rules {
+        if let Some(n) = references(rule).find(|n| !rules.contains_key(n)) {
+            return Err(Error::parse_in(input, line_of_rule[i], format!("undefined rule {}", n)));
+        }
+    }
+
+    // depth-first search of the rules, a rule is on the stack while its sub-rules are visited
+    let mut on_stack = HashMap::new();
+    let mut ids = rules.keys().copied().collect::<Vec<_>>();
+    ids.sort_unstable();
+    for start in ids {
+        if on_stack.contains_key(&start) {
+            continue;
+        }
+        let mut stack = vec![(start, references(&rules[&start]))];
+        on_stack.insert(start, true);
+        while let Some((i, children)) = stack.last_mut() {
+            let i = *i;
+            match children.next() {
+                Some(&n) => match on_stack.get(&n) {
+                    Some(true) => {
+                        return Err(Error::parse_in(input, line_of_rule[&i], format!("rule {} depends on itself", n)));
+                    }
+                    Some(false) => {}
+                    None => {
+                        on_stack.insert(n, true);
+                        stack.push((n, references(&rules[&n])));
+                    }
+                },
+                None => {
+                    on_stack.insert(i, false);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    Ok(rules)
 }
 
-fn get_string_re(rules: &HashMap<i32, Rule>, i: i32) -> String {
-    match rules.get(&i).unwrap() {
+/// Regular expression matching the messages of a rule (rules must be checked by `make_rules`)
+fn get_string_re(rules: &HashMap<i32, Rule>, i: i32) -> Result<String> {
+    let rule = rules.get(&i).ok_or_else(|| Error::no_solution(format!("no rule {}", i)))?;
+    Ok(match rule {
         Rule::Char(c) => c.to_string(),
         Rule::Or(v) => {
             let mut strings = Vec::new();
             for seq in v {
                 let mut s = String::new();
                 for n in seq {
-                    s.push_str(&get_string_re(rules, *n));
+                    s.push_str(&get_string_re(rules, *n)?);
                 }
                 strings.push(s);
             }
-            return format!("(?:{})", strings.join("|"));
+            format!("(?:{})", strings.join("|"))
         }
-    }
+    })
 }
 
-pub fn solve1() -> i32 {
-    let rules = make_rules();
-    let s = get_string_re(&rules, 0);
+/// Splits the input into the rules and the received messages
+fn parse_input(input: &str) -> Result<(HashMap<i32, Rule>, &str)> {
+    let (rules, words) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the rules"))?;
+    Ok((make_rules(rules)?, words))
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let (rules, words) = parse_input(input)?;
+    let s = get_string_re(&rules, 0)?;
     let re = Regex::new(&format!("^({})$", s)).unwrap();
 
-    let words = utils::read_input("src/year2020/day19/words.txt").unwrap();
     let mut total = 0;
     for word in words.lines() {
         if re.is_match(word) {
             total += 1;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let rules = make_rules();
+pub fn part2(input: &str) -> Result<i32> {
+    let (rules, words) = parse_input(input)?;
     
-    let s_42 = get_string_re(&rules, 42);
-    let s_31 = get_string_re(&rules, 31);
+    let s_42 = get_string_re(&rules, 42)?;
+    let s_31 = get_string_re(&rules, 31)?;
     let re_42 = Regex::new(&s_42).unwrap();
     let re_31 = Regex::new(&s_31).unwrap();
     let re = Regex::new(&format!("^((?:{})+)((?:{})+)$", s_42, s_31)).unwrap();
 
-    let words = utils::read_input("src/year2020/day19/words.txt").unwrap();
     let mut total = 0;
     for word in words.lines() {
         if re.is_match(word) {
@@ -98,13 +157,31 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2020, 19).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2020, 19).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_invalid_rules() {
+        let error = part1("0: 1\n\na").unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 1, .. }));
+        let error = part1("0: 1 2\n1: \"a\"\n2: 1 | 0\n\na").unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 3, .. }));
+        assert!(matches!(part1("1: \"a\"\n\na"), Err(Error::NoSolution(_))));
+        assert_eq!(part1("0: 1 2 | 2 1\n1: \"a\"\n2: \"b\"\n\nab\nba\naa").unwrap(), 2);
+    }
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
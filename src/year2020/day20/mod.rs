@@ -7,6 +7,7 @@ use nom::{
 };
 
 use crate::utils;
+use crate::{Error, Result};
 
 fn as_int(bits: &Vec<bool>) -> u32 {
     let mut result: u32 = 0;
@@ -62,15 +63,14 @@ fn parse_tile_header(input: &str) -> IResult<&str, i64> {
     delimited(tag("Tile "), utils::parse_int, tag(":"))(input)
 }
 
-fn parse_input() -> Vec<Tile> {
+fn parse_input(input: &str) -> Result<Vec<Tile>> {
     let mut tiles = Vec::new();
     let mut current_index = 0;
     let mut current_data = Vec::new();
 
-    let input = utils::read_input("src/year2020/day20/input.txt").unwrap();
     for line in input.lines() {
         if line.starts_with("Tile") {
-            current_index = parse_tile_header(line).unwrap().1;
+            current_index = parse_tile_header(line).map_err(|e| Error::from_nom(input, e))?.1;
         } else if line.is_empty() {
             tiles.push(Tile {
                 id: current_index,
@@ -85,11 +85,11 @@ fn parse_input() -> Vec<Tile> {
         id: current_index,
         data: current_data,
     });
-    tiles
+    Ok(tiles)
 }
 
-pub fn solve1() -> i64 {
-    let tiles = parse_input();
+pub fn part1(input: &str) -> Result<i64> {
+    let tiles = parse_input(input)?;
     let mut counter = HashMap::new();
     for tile in &tiles {
         for side in tile.get_sides() {
@@ -110,12 +110,19 @@ pub fn solve1() -> i64 {
             total *= tile.id;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn part2(_input: &str) -> Result<i32> {
+    Ok(0)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2020, 20).unwrap()).unwrap()
 }
 
 pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2020/dayXX/input.txt").unwrap();
-    0
+    part2(&utils::read_day_input(2020, 20).unwrap()).unwrap()
 }
 
 #[cfg(test)]
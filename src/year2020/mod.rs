@@ -1,3 +1,5 @@
+use crate::solution::Registry;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -15,3 +17,25 @@ pub mod day17;
 pub mod day18;
 pub mod day19;
 // pub mod day20;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2020, 1, day01::part1, day01::part2);
+    registry.add(2020, 2, day02::part1, day02::part2);
+    registry.add(2020, 3, day03::part1, day03::part2);
+    registry.add(2020, 4, day04::part1, day04::part2);
+    registry.add(2020, 5, day05::part1, day05::part2);
+    registry.add(2020, 6, day06::part1, day06::part2);
+    registry.add(2020, 7, day07::part1, day07::part2);
+    registry.add(2020, 8, day08::part1, day08::part2);
+    registry.add(2020, 9, day09::part1, day09::part2);
+    registry.add(2020, 10, day10::part1, day10::part2);
+    registry.add(2020, 14, day14::part1, day14::part2);
+    registry.mark_slow(2020, 14, 2);
+    registry.add(2020, 15, day15::part1, day15::part2);
+    registry.mark_slow(2020, 15, 2);
+    registry.add(2020, 16, day16::part1, day16::part2);
+    registry.add(2020, 17, day17::part1, day17::part2);
+    registry.mark_slow(2020, 17, 2);
+    registry.add(2020, 18, day18::part1, day18::part2);
+    registry.add(2020, 19, day19::part1, day19::part2);
+}
@@ -1,38 +1,53 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day01/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
     let mut best_total = 0;
     let mut current_total = 0;
     for line in input.lines() {
-        if line == "" {
+        if line.is_empty() {
             if current_total > best_total {
                 best_total = current_total;
             }
             current_total = 0;
         } else {
-            current_total += line.parse::<i32>().unwrap();
+            current_total += parse::value::<i32>(input, line)?;
         }
     }
-    best_total
+    // last elf (there is no blank line after it)
+    Ok(best_total.max(current_total))
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day01/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut totals = Vec::new();
     let mut current_total = 0;
     for line in input.lines() {
-        if line == "" {
+        if line.is_empty() {
             totals.push(current_total);
             current_total = 0;
         } else {
-            current_total += line.parse::<i32>().unwrap();
+            current_total += parse::value::<i32>(input, line)?;
         }
     }
+    // last elf (there is no blank line after it)
+    if input.lines().last().is_some_and(|line| !line.is_empty()) {
+        totals.push(current_total);
+    }
+    if totals.len() < 3 {
+        return Err(Error::no_solution("there are fewer than 3 elves"));
+    }
     totals.sort();
     totals.reverse();
 
-    totals[0] + totals[1] + totals[2]
+    Ok(totals[0] + totals[1] + totals[2])
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 1).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 1).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 use nom::character::complete::char;
 use nom::character::complete::one_of;
 use nom::combinator::map;
@@ -12,12 +13,14 @@ fn parser(input: &str) -> IResult<&str, (i32, i32)> {
     })(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day02/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<(i32, i32)>> {
+    utils::parse_all(parse::lines(parser), input)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut score = 0;
     
-    for line in input.lines() {
-        let (_, (first, second)) = parser(line).unwrap();
+    for (first, second) in parse_input(input)? {
         score += second + 1;
         match (3 + second - first) % 3 {
             0 => score += 3,
@@ -26,15 +29,13 @@ pub fn solve1() -> i32 {
         }
     }
 
-    score
+    Ok(score)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day02/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut score = 0;
     
-    for line in input.lines() {
-        let (_, (first, second)) = parser(line).unwrap();
+    for (first, second) in parse_input(input)? {
         match second {
             0 => { // should lose
                 score += (first + 2) % 3 + 1;
@@ -50,9 +51,17 @@ pub fn solve2() -> i32 {
         }
     }
 
-    score
+    Ok(score)
+}
+
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 2).unwrap()).unwrap()
 }
 
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 2).unwrap()).unwrap()
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,4 @@
-use crate::utils;
+use crate::{utils, Error, Result};
 
 fn to_priorities(items: &str) -> Vec<i32> {
     let mut priorities = Vec::new();
@@ -12,40 +12,42 @@ fn to_priorities(items: &str) -> Vec<i32> {
     priorities
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day03/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
     let mut total = 0;
     for line in input.lines() {
         let first = &line[..line.len() / 2];
         let second = &line[line.len() / 2..];
         let first_priority = to_priorities(first);
         let second_priority = to_priorities(second);
-        'outer: for i in 0..first_priority.len() {
-            for j in 0..second_priority.len() {
-                if first_priority[i] == second_priority[j] {
-                    total += first_priority[i];
+        'outer: for &first in &first_priority {
+            for &second in &second_priority {
+                if first == second {
+                    total += first;
                     break 'outer;
                 }
             }
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day03/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut lines = input.lines();
     let mut total = 0;
 
     loop {
         let mut group = Vec::new();
         let first = lines.next();
-        if first == None {
+        if first.is_none() {
             break;
         }
         group.push(to_priorities(first.unwrap()));
-        group.push(to_priorities(lines.next().unwrap()));
-        group.push(to_priorities(lines.next().unwrap()));
+        for _ in 0..2 {
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::parse_at(input, input.len(), "expected groups of three lines"))?;
+            group.push(to_priorities(line));
+        }
         'outer: for i in &group[0] {
             for j in &group[1] {
                 if i == j {
@@ -60,7 +62,15 @@ pub fn solve2() -> i32 {
         }
     }
 
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 3).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 3).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,65 +1,56 @@
-use nom::character::complete::{char, digit1};
+use nom::character::complete::char;
+use nom::combinator::map;
 use nom::sequence::separated_pair;
 use nom::IResult;
 
-use crate::utils;
+use crate::utils::interval::Interval;
+use crate::utils::{self, parse};
+use crate::Result;
 
-struct Interval {
-    start: usize,
-    end: usize,
+fn parse_interval(input: &str) -> IResult<&str, Interval<usize>> {
+    map(
+        separated_pair(utils::parse_unsigned_int, char('-'), utils::parse_unsigned_int),
+        |(first, last)| Interval::closed(first, last),
+    )(input)
 }
 
-impl Interval {
-    fn contains(&self, other: &Interval) -> bool {
-        self.start <= other.start && self.end >= other.end
-    }
-
-    fn overlaps(&self, other: &Interval) -> bool {
-        self.start <= other.end && other.start <= self.end
-    }
+fn parse_line(input: &str) -> IResult<&str, (Interval<usize>, Interval<usize>)> {
+    separated_pair(parse_interval, char(','), parse_interval)(input)
 }
 
-fn parse_interval(input: &str) -> IResult<&str, Interval> {
-    let (remaining, (start, end)) = separated_pair(digit1, char('-'), digit1)(input)?;
-    Ok((
-        remaining,
-        Interval {
-            start: start.parse::<usize>().unwrap(),
-            end: end.parse::<usize>().unwrap(),
-        },
-    ))
+fn parse_input(input: &str) -> Result<Vec<(Interval<usize>, Interval<usize>)>> {
+    utils::parse_all(parse::lines(parse_line), input)
 }
 
-fn parse_line(input: &str) -> (Interval, Interval) {
-    let (_, (i1, i2)) = separated_pair(parse_interval, char(','), parse_interval)(input).unwrap();
-    (i1, i2)
-}
-
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day04/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
     let mut total = 0;
-    for line in input.lines() {
-        let (i1, i2) = parse_line(line);
-        if i1.contains(&i2) || i2.contains(&i1) {
+    for (i1, i2) in parse_input(input)? {
+        if i1.contains_interval(&i2) || i2.contains_interval(&i1) {
             total += 1;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day04/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut total = 0;
-    for line in input.lines() {
-        let (i1, i2) = parse_line(line);
+    for (i1, i2) in parse_input(input)? {
         if i1.overlaps(&i2) {
             total += 1;
         }
     }
-    total
+    Ok(total)
 }
 
 
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 4).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 4).unwrap()).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
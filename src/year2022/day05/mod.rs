@@ -1,55 +1,53 @@
 use nom::bytes::complete::tag;
-use nom::character::complete::digit1;
 use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 
-fn parse_move(input: &str) -> IResult<&str, (usize, usize, usize)> {
-    let (rem, (x, y, z)) = tuple((
-        preceded(tag("move "), digit1),
-        preceded(tag(" from "), digit1),
-        preceded(tag(" to "), digit1),
-    ))(input)?;
+/// Number of crates to move, with the stacks they are moved from and to (numbered from 1)
+type Move = (usize, usize, usize);
 
-    Ok((
-        rem,
-        (
-            x.parse::<usize>().unwrap(),
-            y.parse::<usize>().unwrap(),
-            z.parse::<usize>().unwrap(),
-        ),
-    ))
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    tuple((
+        preceded(tag("move "), utils::parse_unsigned_int),
+        preceded(tag(" from "), utils::parse_unsigned_int),
+        preceded(tag(" to "), utils::parse_unsigned_int),
+    ))(input)
 }
 
-fn make_stacks() -> Vec<Vec<char>> {
-    let mut stacks = Vec::new();
-    for _ in 0..9 {
-        stacks.push(Vec::new());
-    }
+fn make_stacks(input: &str) -> Result<Vec<Vec<char>>> {
+    // the last line of the drawing contains the labels of the stacks
+    let mut lines = input.lines().rev();
+    let labels = lines.next().ok_or_else(|| Error::parse_at(input, 0, "expected a drawing of the stacks"))?;
+    let nb_stacks = labels.split_whitespace().count();
+    let mut stacks = vec![Vec::new(); nb_stacks];
 
-    let input = utils::read_input("src/year2022/day05/start.txt").unwrap();
-    for line in input.lines() {
-        for i in 0..9 {
-            let c = line.chars().nth(4*i + 1).unwrap();
-            if c != ' ' {
-                stacks[i].push(c);
+    for line in lines {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            if let Some(c) = line.chars().nth(4 * i + 1) {
+                if c != ' ' {
+                    stack.push(c);
+                }
             }
         }
     }
-    for i in 0..9 {
-        stacks[i].reverse();
-    }
-    stacks
+    Ok(stacks)
 }
 
-pub fn solve1() -> String {
-    let mut stacks = make_stacks();
-    
-    let input = utils::read_input("src/year2022/day05/moves.txt").unwrap();
-    for line in input.lines() {
-        let (n, start, end) = parse_move(line).unwrap().1;
+/// Splits the input into the starting stacks and the list of moves
+fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>)> {
+    let (drawing, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the drawing"))?;
+    Ok((make_stacks(drawing)?, parse::parse_all_in(parse::lines(parse_move), input, moves)?))
+}
+
+pub fn part1(input: &str) -> Result<String> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for (n, start, end) in moves {
         for _ in 0..n {
             let c = stacks[start - 1].pop().unwrap();
             stacks[end - 1].push(c);
@@ -60,15 +58,13 @@ pub fn solve1() -> String {
     for mut stack in stacks {
         result.push(stack.pop().unwrap());
     }
-    result
+    Ok(result)
 }
 
-pub fn solve2() -> String {
-    let mut stacks = make_stacks();
-    
-    let input = utils::read_input("src/year2022/day05/moves.txt").unwrap();
-    for line in input.lines() {
-        let (n, start, end) = parse_move(line).unwrap().1;
+pub fn part2(input: &str) -> Result<String> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for (n, start, end) in moves {
         let l = stacks[start - 1].len();
         let moved = stacks[start - 1].drain(l - n..).collect::<Vec<char>>();
         stacks[end - 1].extend(moved);
@@ -78,7 +74,15 @@ pub fn solve2() -> String {
     for mut stack in stacks {
         result.push(stack.pop().unwrap());
     }
-    result
+    Ok(result)
+}
+
+pub fn solve1() -> String {
+    part1(&utils::read_day_input(2022, 5).unwrap()).unwrap()
+}
+
+pub fn solve2() -> String {
+    part2(&utils::read_day_input(2022, 5).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,28 +1,31 @@
-use crate::utils;
+use crate::{utils, Error, Result};
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day06/input.txt").unwrap();
+fn no_marker() -> Error {
+    Error::no_solution("no marker found in the datastream")
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut buffer = Vec::new();
     
     let mut chars = input.chars();
     let mut counter = 0;
     for _ in 0..4 {
-        buffer.push(chars.next().unwrap());
+        buffer.push(chars.next().ok_or_else(no_marker)?);
         counter += 1;
     }
 
     loop {
         if buffer[0] != buffer[1] && buffer[0] != buffer[2] && buffer[0] != buffer[3]
         && buffer[1] != buffer[2] && buffer[1] != buffer[3] && buffer[2] != buffer[3] {
-            return counter;
+            return Ok(counter);
         }
         buffer.remove(0);
-        buffer.push(chars.next().unwrap());
+        buffer.push(chars.next().ok_or_else(no_marker)?);
         counter += 1;
     }
 }
 
-fn are_all_different(buffer: &Vec<char>) -> bool {
+fn are_all_different(buffer: &[char]) -> bool {
     for i in 0..buffer.len() {
         for j in i+1..buffer.len() {
             if buffer[i] == buffer[j] {
@@ -33,27 +36,34 @@ fn are_all_different(buffer: &Vec<char>) -> bool {
     true
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day06/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut buffer = Vec::new();
     
     let mut chars = input.chars();
     let mut counter = 0;
     for _ in 0..14 {
-        buffer.push(chars.next().unwrap());
+        buffer.push(chars.next().ok_or_else(no_marker)?);
         counter += 1;
     }
 
     loop {
         if are_all_different(&buffer) {
-            return counter;
+            return Ok(counter);
         }
         buffer.remove(0);
-        buffer.push(chars.next().unwrap());
+        buffer.push(chars.next().ok_or_else(no_marker)?);
         counter += 1;
     }
 }
 
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 6).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 6).unwrap()).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,8 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while1};
-use nom::character::complete::{char, alpha1, digit1};
+use nom::character::complete::{char, alpha1};
 use nom::combinator::map;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
@@ -63,20 +64,17 @@ fn parse_line(input: &str) -> IResult<&str, Command> {
         map(preceded(tag("dir "), alpha1), |_s: &str| {
             Command::Dir
         }),
-        map(separated_pair(digit1, char(' '), parse_filename), |(size, _name): (&str, &str)| {
-            Command::File(size.parse::<usize>().unwrap())
+        map(separated_pair(utils::parse_unsigned_int, char(' '), parse_filename), |(size, _name)| {
+            Command::File(size)
         }),
     ))(input)?;
     Ok((remaining, command))
 }
 
-fn build_root() -> Rc<RefCell<Directory>> {
+fn build_root(input: &str) -> Result<Rc<RefCell<Directory>>> {
     let root = Rc::new(RefCell::new(Directory::new(String::from("/"), None)));
     let mut current_dir = Rc::clone(&root);
-
-    let input = utils::read_input("src/year2022/day07/input.txt").unwrap();
-    for line in input.lines() {
-        let (_, command) = parse_line(line).unwrap();
+    for command in utils::parse_all(parse::lines(parse_line), input)? {
         match command {
             Command::CdParent => {
                 let parent = Rc::clone(current_dir.borrow().parent.as_ref().unwrap());
@@ -96,7 +94,7 @@ fn build_root() -> Rc<RefCell<Directory>> {
             _ => {}
         }
     }
-    root
+    Ok(root)
 }
 
 fn solve1_aux(dir: &mut Directory)-> usize {
@@ -111,9 +109,9 @@ fn solve1_aux(dir: &mut Directory)-> usize {
     sum_total
 }
 
-pub fn solve1() -> usize {
-    let root = build_root();
-    return solve1_aux(&mut root.borrow_mut());
+pub fn part1(input: &str) -> Result<usize> {
+    let root = build_root(input)?;
+    return Ok(solve1_aux(&mut root.borrow_mut()));
 }
 
 fn solve2_aux(dir: &mut Directory, required_space: usize)-> usize {
@@ -128,11 +126,19 @@ fn solve2_aux(dir: &mut Directory, required_space: usize)-> usize {
     best_size
 }
 
-pub fn solve2() -> usize {
-    let root = build_root();
+pub fn part2(input: &str) -> Result<usize> {
+    let root = build_root(input)?;
     let used_space = root.borrow_mut().get_total_size();
     let required_space = used_space - 40000000;
-    return solve2_aux(&mut root.borrow_mut(), required_space);
+    return Ok(solve2_aux(&mut root.borrow_mut(), required_space));
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2022, 7).unwrap()).unwrap()
+}
+
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2022, 7).unwrap()).unwrap()
 }
 
 #[cfg(test)]
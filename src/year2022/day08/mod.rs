@@ -1,6 +1,7 @@
 use std::cmp;
 
-use crate::utils;
+use crate::utils::{self, Grid};
+use crate::Result;
 
 struct Trees {
     nb_rows: usize,
@@ -9,22 +10,13 @@ struct Trees {
 }
 
 impl Trees {
-    fn from_file(filename: &str) -> Trees {
-        let input = utils::read_input(filename).unwrap();
-        let mut grid = Vec::new();
-        for line in input.lines() {
-            let mut row = Vec::new();
-            for c in line.chars() {
-                row.push(c.to_digit(10).unwrap() as i32);
-            }
-            grid.push(row);
-        }
-
-        Trees {
-            nb_rows: grid.len(),
-            nb_cols: grid[0].len(),
-            grid,
-        }
+    fn from_file(input: &str) -> Result<Trees> {
+        let grid = Grid::try_parse(input, |c| Some(c.to_digit(10)? as i32))?;
+        Ok(Trees {
+            nb_rows: grid.height(),
+            nb_cols: grid.width(),
+            grid: grid.rows().map(<[i32]>::to_vec).collect(),
+        })
     }
 
     fn is_visible(&self, i: usize, j: usize) -> bool {
@@ -124,8 +116,8 @@ impl Trees {
     }
 }
 
-pub fn solve1() -> i32 {
-    let trees = Trees::from_file("src/year2022/day08/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let trees = Trees::from_file(input)?;
     let mut counter = 0;
     for i in 0..trees.nb_rows {
         for j in 0..trees.nb_cols {
@@ -134,18 +126,26 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    counter
+    Ok(counter)
 }
 
-pub fn solve2() -> i32 {
-    let trees = Trees::from_file("src/year2022/day08/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let trees = Trees::from_file(input)?;
     let mut best = 0;
     for i in 0..trees.nb_rows {
         for j in 0..trees.nb_cols {
             best = cmp::max(best, trees.scenic_score(i, j));
         }
     }
-    best
+    Ok(best)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 8).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 8).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,98 +1,78 @@
 use std::collections::HashSet;
 
-use crate::utils;
-use nom::character::complete::{char, digit1, one_of};
-use nom::combinator::map;
+use crate::utils::{self, parse, Direction};
+use crate::utils::geometry::Point2;
+use crate::Result;
+use nom::character::complete::{char, one_of};
+use nom::combinator::map_opt;
 use nom::sequence::separated_pair;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn new(x: i32, y: i32) -> Point {
-        Point { x, y }
-    }
-}
 struct Rope {
-    knots: Vec<Point>,
+    knots: Vec<Point2>,
 }
 
 impl Rope {
     fn new(nb_knots: usize) -> Rope {
         Rope {
-            knots: vec![Point::new(0, 0); nb_knots],
+            knots: vec![Point2::ZERO; nb_knots],
         }
     }
 
-    fn move_head(&mut self, direction: &Point) {
-        self.knots[0].x += direction.x;
-        self.knots[0].y += direction.y;
+    fn move_head(&mut self, direction: &Point2) {
+        self.knots[0] += *direction;
 
         for i in 1..self.knots.len() {
-            let delta_x = self.knots[i-1].x - self.knots[i].x;
-            let dx = match delta_x {
-                x if x > 0 => 1,
-                x if x < 0 => -1,
-                _ => 0,
-            };
-            let delta_y = self.knots[i-1].y - self.knots[i].y;
-            let dy = match delta_y {
-                y if y > 0 => 1,
-                y if y < 0 => -1,
-                _ => 0,
-            };
-            if delta_x.abs() >= 2 || delta_y.abs() >= 2 {
-                self.knots[i].x += dx;
-                self.knots[i].y += dy;
+            let delta = self.knots[i-1] - self.knots[i];
+            if delta.norm_inf() >= 2 {
+                self.knots[i] += delta.signum();
             }
         }
     }
 }
 
-fn parse_line(input: &str) -> IResult<&str, (Point, usize)> {
+fn parse_line(input: &str) -> IResult<&str, (Point2, usize)> {
     separated_pair(
-        map(one_of("UDLR"), |x| match x {
-            'U' => Point { x: 0, y: 1 },
-            'D' => Point { x: 0, y: -1 },
-            'L' => Point { x: -1, y: 0 },
-            'R' => Point { x: 1, y: 0 },
-            _ => panic!("Invalid direction"),
-        }),
+        map_opt(one_of("UDLR"), |c| Direction::from_char(c).map(Point2::from)),
         char(' '),
-        map(digit1, |x: &str| x.parse().unwrap()),
+        utils::parse_unsigned_int,
     )(input)
 }
 
-pub fn solve1() -> usize {
-    let input = utils::read_input("src/year2022/day09/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<(Point2, usize)>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
     let mut rope = Rope::new(2);
     let mut tail_orbit = HashSet::new();
-    for line in input.lines() {
-        let (_, (direction, distance)) = parse_line(line).unwrap();
+    for (direction, distance) in parse_input(input)? {
         for _ in 0..distance {
             rope.move_head(&direction);
-            tail_orbit.insert(rope.knots[1].clone());
+            tail_orbit.insert(rope.knots[1]);
         }
     }
-    tail_orbit.len()
+    Ok(tail_orbit.len())
 }
 
-pub fn solve2() -> usize {
-    let input = utils::read_input("src/year2022/day09/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<usize> {
     let mut rope = Rope::new(10);
     let mut tail_orbit = HashSet::new();
-    for line in input.lines() {
-        let (_, (direction, distance)) = parse_line(line).unwrap();
+    for (direction, distance) in parse_input(input)? {
         for _ in 0..distance {
             rope.move_head(&direction);
-            tail_orbit.insert(rope.knots[9].clone());
+            tail_orbit.insert(rope.knots[9]);
         }
     }
-    tail_orbit.len()
+    Ok(tail_orbit.len())
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2022, 9).unwrap()).unwrap()
+}
+
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2022, 9).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,50 +1,40 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{char, digit1};
-use nom::combinator::{map, opt};
-use nom::sequence::{pair, preceded};
+use nom::combinator::map;
+use nom::sequence::preceded;
 use nom::IResult;
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
 enum Line {
     Noop,
     Add(i32),
 }
 
-fn parse_signed(input: &str) -> IResult<&str, i32> {
-    map(
-        pair(opt(char('-')), digit1),
-        |(sign, number): (Option<char>, &str)| {
-            let mut value = number.parse::<i32>().unwrap();
-            if sign == Some('-') {
-                value *= -1;
-            }
-            value
-        },
-    )(input)
-}
-
 fn parse_line(input: &str) -> IResult<&str, Line> {
     alt((
         map(tag("noop"), |_| Line::Noop),
-        map(preceded(tag("addx "), parse_signed), |value| {
+        map(preceded(tag("addx "), utils::parse_int), |value| {
             Line::Add(value)
         }),
     ))(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day10/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<Line>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut state = Vec::new();
     let mut x = 1;
     state.push(x);
-    for line in input.lines() {
-        match parse_line(line).unwrap() {
-            (_, Line::Noop) => {
+    for line in parse_input(input)? {
+        match line {
+            Line::Noop => {
                 state.push(x);
             }
-            (_, Line::Add(value)) => {
+            Line::Add(value) => {
                 state.push(x);
                 x += value;
                 state.push(x);
@@ -55,21 +45,20 @@ pub fn solve1() -> i32 {
     for i in [20, 60, 100, 140, 180, 220] {
         total += i as i32 * state[i - 1];
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> Vec<String> {
-    let input = utils::read_input("src/year2022/day10/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<Vec<String>> {
     let mut crt = Vec::new();
     let mut state = Vec::new();
     let mut x = 1;
     state.push(x);
-    for line in input.lines() {
-        match parse_line(line).unwrap() {
-            (_, Line::Noop) => {
+    for line in parse_input(input)? {
+        match line {
+            Line::Noop => {
                 state.push(x);
             }
-            (_, Line::Add(value)) => {
+            Line::Add(value) => {
                 state.push(x);
                 x += value;
                 state.push(x);
@@ -80,20 +69,30 @@ pub fn solve2() -> Vec<String> {
         let mut crt_line = String::new();
         for i in 0..40 {
             if state[40 * j + i] - 1 <= i as i32 && i as i32 <= state[40 * j + i] + 1 {
-                crt_line.push_str("#");
+                crt_line.push('#');
             } else {
-                crt_line.push_str(" ");
+                crt_line.push(' ');
             }
         }
         crt.push(crt_line);
     }
-    crt
+    Ok(crt)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 10).unwrap()).unwrap()
+}
+
+pub fn solve2() -> Vec<String> {
+    part2(&utils::read_day_input(2022, 10).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 10);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -104,10 +103,7 @@ mod tests {
     #[test]
     fn test_solve2() {
         let solution = solve2();
-        println!("Part Two:");
-        for line in &solution {
-            println!("{}", line);
-        }
+        println!("Part Two:\n{}", solution.join("\n"));
         assert_eq!(
             solution,
             vec![
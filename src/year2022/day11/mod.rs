@@ -1,15 +1,16 @@
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1, one_of},
+    character::complete::{char, one_of},
     combinator::map,
     multi::separated_list1,
     sequence::{delimited, preceded, terminated, tuple},
     IResult,
 };
-use num::integer;
 
 use crate::utils;
+use crate::utils::number;
+use crate::Result;
 
 #[derive(Debug)]
 struct Monkey {
@@ -77,27 +78,21 @@ impl Operation {
 }
 
 fn parse_header_line(input: &str) -> IResult<&str, usize> {
-    map(delimited(tag("Monkey "), digit1, tag(":\n")), |s: &str| {
-        s.parse().unwrap()
-    })(input)
+    delimited(tag("Monkey "), utils::parse_unsigned_int, tag(":\n"))(input)
 }
 
 fn parse_items_line(input: &str) -> IResult<&str, Vec<i64>> {
-    let (remaining, items) = delimited(
+    delimited(
         tag("  Starting items: "),
-        separated_list1(tag(", "), digit1),
+        separated_list1(tag(", "), utils::parse_unsigned_int),
         tag("\n"),
-    )(input)?;
-    Ok((
-        remaining,
-        items.iter().map(|x| x.parse().unwrap()).collect(),
-    ))
+    )(input)
 }
 
 fn parse_operand(input: &str) -> IResult<&str, Operand> {
     alt((
         map(tag("old"), |_| Operand::Old),
-        map(digit1, |s: &str| Operand::Value(s.parse().unwrap())),
+        map(utils::parse_unsigned_int, Operand::Value),
     ))(input)
 }
 
@@ -115,7 +110,7 @@ fn parse_operation_line(input: &str) -> IResult<&str, Operation> {
     let op = match op {
         '+' => Operator::Add,
         '*' => Operator::Multiply,
-        _ => panic!("Invalid operator"),
+        _ => unreachable!("invalid operator"),
     };
 
     Ok((remaining, Operation { x, op, y }))
@@ -123,18 +118,9 @@ fn parse_operation_line(input: &str) -> IResult<&str, Operation> {
 
 fn parse_divisibility(input: &str) -> IResult<&str, (i64, usize, usize)> {
     tuple((
-        map(
-            delimited(tag("  Test: divisible by "), digit1, tag("\n")),
-            |s: &str| s.parse().unwrap(),
-        ),
-        map(
-            delimited(tag("    If true: throw to monkey "), digit1, tag("\n")),
-            |s: &str| s.parse().unwrap(),
-        ),
-        map(
-            delimited(tag("    If false: throw to monkey "), digit1, tag("\n")),
-            |s: &str| s.parse().unwrap(),
-        ),
+        delimited(tag("  Test: divisible by "), utils::parse_unsigned_int, tag("\n")),
+        delimited(tag("    If true: throw to monkey "), utils::parse_unsigned_int, tag("\n")),
+        delimited(tag("    If false: throw to monkey "), utils::parse_unsigned_int, tag("\n")),
     ))(input)
 }
 
@@ -154,9 +140,8 @@ fn parse_monkeys(input: &str) -> IResult<&str, Vec<Monkey>> {
     separated_list1(char('\n'), parse_monkey)(input)
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2022/day11/input.txt").unwrap();
-    let (_, mut monkeys) = parse_monkeys(&input).unwrap();
+pub fn part1(input: &str) -> Result<i64> {
+    let mut monkeys = utils::parse_all(parse_monkeys, input)?;
     let nb_monkeys = monkeys.len();
     // play 20 rounds
     for _ in 0..20 {
@@ -182,16 +167,15 @@ pub fn solve1() -> i64 {
     nb_inspected.sort();
     nb_inspected.reverse();
 
-    nb_inspected[0] * nb_inspected[1]
+    Ok(nb_inspected[0] * nb_inspected[1])
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2022/day11/input.txt").unwrap();
-    let (_, mut monkeys) = parse_monkeys(&input).unwrap();
+pub fn part2(input: &str) -> Result<i64> {
+    let mut monkeys = utils::parse_all(parse_monkeys, input)?;
     let nb_monkeys = monkeys.len();
     let mut lcm: i64 = 1;
     for monkey in &monkeys {
-        lcm = integer::lcm(lcm, monkey.divisibility as i64);
+        lcm = number::lcm(lcm, monkey.divisibility);
     }
 
     // play 10000 rounds
@@ -203,7 +187,7 @@ pub fn solve2() -> i64 {
                 let mut item = m.items.pop().unwrap();
                 m.nb_inspected += 1;
                 item = m.operation.exec(item);
-                item = item % lcm;
+                item %= lcm;
                 let target_monkey = if (item % m.divisibility) == 0 {
                     m.on_true
                 } else {
@@ -218,13 +202,23 @@ pub fn solve2() -> i64 {
     nb_inspected.sort();
     nb_inspected.reverse();
 
-    nb_inspected[0] * nb_inspected[1]
+    Ok(nb_inspected[0] * nb_inspected[1])
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2022, 11).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2022, 11).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 11);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
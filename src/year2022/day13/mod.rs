@@ -3,14 +3,16 @@ use std::cmp::Ordering;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1},
+    character::complete::char,
     combinator::map,
-    multi::{separated_list0, separated_list1},
-    sequence::{delimited, pair, terminated},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
     IResult,
 };
 
 use crate::utils;
+use crate::utils::parse;
+use crate::Result;
 
 #[derive(Debug, Eq, Clone)]
 enum Message {
@@ -54,10 +56,6 @@ impl PartialOrd for Message {
     }
 }
 
-fn parse_int(input: &str) -> IResult<&str, i32> {
-    map(digit1, |s: &str| s.parse::<i32>().unwrap())(input)
-}
-
 fn parse_list(input: &str) -> IResult<&str, Vec<Message>> {
     delimited(
         char('['),
@@ -68,37 +66,28 @@ fn parse_list(input: &str) -> IResult<&str, Vec<Message>> {
 
 fn parse_message(input: &str) -> IResult<&str, Message> {
     alt((
-        map(parse_int, Message::Value),
+        map(utils::parse_unsigned_int, Message::Value),
         map(parse_list, Message::List),
     ))(input)
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<(Message, Message)>> {
-    separated_list1(
-        tag("\n"),
-        pair(
-            terminated(parse_message, tag("\n")),
-            terminated(parse_message, tag("\n")),
-        ),
-    )(input)
+    parse::blocks(separated_pair(parse_message, char('\n'), parse_message))(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2022/day13/input.txt").unwrap();
-    let (_, message_pairs) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let message_pairs = utils::parse_all(parse_input, input)?;
     let mut total = 0;
-    for i in 0..message_pairs.len() {
-        let (m1, m2) = &message_pairs[i];
-        if m1.partial_cmp(&m2).unwrap() == Ordering::Less {
+    for (i, (m1, m2)) in message_pairs.iter().enumerate() {
+        if m1.partial_cmp(m2).unwrap() == Ordering::Less {
             total += (i + 1) as i32;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day13/input.txt").unwrap();
-    let (_, message_pairs) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let message_pairs = utils::parse_all(parse_input, input)?;
     let mut messages = Vec::new();
     for (m1, m2) in message_pairs {
         messages.push(m1);
@@ -114,21 +103,31 @@ pub fn solve2() -> i32 {
 
     let mut index1 = 0;
     let mut index2 = 0;
-    for i in 0..messages.len() {
-        if messages[i] == marker1 {
+    for (i, message) in messages.iter().enumerate() {
+        if *message == marker1 {
             index1 = i + 1;
-        } else if messages[i] == marker2 {
+        } else if *message == marker2 {
             index2 = i + 1;
         }
     }
 
-    (index1 * index2) as i32
+    Ok((index1 * index2) as i32)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 13).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 13).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 13);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
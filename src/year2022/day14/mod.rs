@@ -1,8 +1,9 @@
 use crate::utils;
+use crate::utils::parse;
+use crate::Result;
 use nom::{
     bytes::complete::tag,
-    character::complete::{char, digit1},
-    combinator::map,
+    character::complete::char,
     multi::separated_list1,
     sequence::separated_pair,
     IResult,
@@ -15,9 +16,8 @@ struct Cave {
 }
 
 impl Cave {
-    fn new(filename: &str) -> Self {
-        let input = utils::read_input(filename).unwrap();
-        let (_, paths) = parse_input(&input).unwrap();
+    fn new(input: &str) -> Result<Self> {
+        let paths = utils::parse_all(parse_input, input)?;
         let mut max_depth = 0;
 
         for path in &paths {
@@ -32,9 +32,7 @@ impl Cave {
         let width = max_x - offset;
         let mut grid = vec![vec![b'.'; width]; max_depth + 3];
 
-        for i in 0..width {
-            grid[max_depth + 2][i] = b'#';
-        }
+        grid[max_depth + 2].fill(b'#');
 
         for path in &paths {
             for i in 0..path.len() - 1 {
@@ -45,14 +43,14 @@ impl Cave {
                         grid[d1][x - offset] = b'#';
                     }
                 } else if x1 == x2 {
-                    for d in d1.min(d2)..=d1.max(d2) {
-                        grid[d][x1 - offset] = b'#';
+                    for row in &mut grid[d1.min(d2)..=d1.max(d2)] {
+                        row[x1 - offset] = b'#';
                     }
                 }
             }
         }
 
-        Self { grid, offset }
+        Ok(Self { grid, offset })
     }
 
     fn drop_sand(&mut self) -> (usize, usize) {
@@ -89,12 +87,8 @@ impl fmt::Display for Cave {
     }
 }
 
-fn parse_int(input: &str) -> IResult<&str, usize> {
-    map(digit1, |s: &str| s.parse().unwrap())(input)
-}
-
 fn parse_point(input: &str) -> IResult<&str, (usize, usize)> {
-    separated_pair(parse_int, char(','), parse_int)(input)
+    separated_pair(utils::parse_unsigned_int, char(','), utils::parse_unsigned_int)(input)
 }
 
 fn parse_path(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
@@ -102,11 +96,11 @@ fn parse_path(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<(usize, usize)>>> {
-    separated_list1(char('\n'), parse_path)(input)
+    parse::lines(parse_path)(input)
 }
 
-pub fn solve1() -> i32 {
-    let mut cave = Cave::new("src/year2022/day14/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let mut cave = Cave::new(input)?;
     let mut counter = 0;
     loop {
         let (d, _) = cave.drop_sand();
@@ -115,11 +109,11 @@ pub fn solve1() -> i32 {
         }
         counter += 1;
     }
-    counter
+    Ok(counter)
 }
 
-pub fn solve2() -> i32 {
-    let mut cave = Cave::new("src/year2022/day14/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let mut cave = Cave::new(input)?;
     let mut counter = 0;
     loop {
         let (d, x) = cave.drop_sand();
@@ -128,7 +122,15 @@ pub fn solve2() -> i32 {
             break;
         }
     }
-    counter
+    Ok(counter)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 14).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 14).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,10 +1,12 @@
-use std::{cmp::Ordering, collections::HashSet};
+use std::collections::HashSet;
 
 use crate::utils;
+use crate::utils::interval::{Interval, IntervalSet};
+use crate::utils::parse;
+use crate::{Error, Result};
 use nom::{
     bytes::complete::tag,
     combinator::map,
-    multi::separated_list1,
     sequence::{preceded, tuple},
     IResult,
 };
@@ -25,88 +27,31 @@ impl Sensor {
     fn get_diamond(&self) -> Diamond {
         let r = self.radius();
         Diamond {
-            diag_interval: Interval {
-                start: self.x - self.y - r,
-                end: self.x - self.y + r + 1,
-            },
-            anti_interval: Interval {
-                start: self.x + self.y - r,
-                end: self.x + self.y + r + 1,
-            },
+            diag_interval: Interval::closed(self.x - self.y - r, self.x - self.y + r),
+            anti_interval: Interval::closed(self.x + self.y - r, self.x + self.y + r),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-struct Interval {
-    start: i32,
-    end: i32,
-}
-
-impl Interval {
-    fn sub(&self, other: &Interval) -> Vec<Interval> {
-        let mut intervals = Vec::new();
-        if self.start < other.start {
-            intervals.push(Interval {
-                start: self.start,
-                end: self.end.min(other.start),
-            });
-        }
-        if self.end > other.end {
-            intervals.push(Interval {
-                start: self.start.max(other.end),
-                end: self.end,
-            });
-        }
-        intervals
-    }
-
-    fn intersect(&self, other: &Interval) -> Option<Interval> {
-        let start = self.start.max(other.start);
-        let end = self.end.min(other.end);
-        if start < end {
-            Some(Interval { start, end })
-        } else {
-            None
-        }
-    }
-
-    fn size(&self) -> i32 {
-        self.end - self.start
-    }
-}
-
-impl PartialOrd for Interval {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Interval {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.start.cmp(&other.start)
-    }
-}
-
 #[derive(Debug, Copy, Clone)]
 struct Diamond {
     // diagonal: x - y = cst
-    diag_interval: Interval,
+    diag_interval: Interval<i32>,
     // anti-diagonal: x + y = cst
-    anti_interval: Interval,
+    anti_interval: Interval<i32>,
 }
 
 impl Diamond {
     fn sub(&self, other: &Diamond) -> Vec<Diamond> {
         let mut result = Vec::new();
-        for i in self.diag_interval.sub(&other.diag_interval) {
+        for i in self.diag_interval.difference(&other.diag_interval) {
             result.push(Diamond {
                 diag_interval: i,
                 anti_interval: self.anti_interval,
             });
         }
-        for i in self.anti_interval.sub(&other.anti_interval) {
-            if let Some(j) = self.diag_interval.intersect(&other.diag_interval) {
+        for i in self.anti_interval.difference(&other.anti_interval) {
+            if let Some(j) = self.diag_interval.intersection(&other.diag_interval) {
                 result.push(Diamond {
                     diag_interval: j,
                     anti_interval: i,
@@ -135,14 +80,13 @@ fn parse_line(input: &str) -> IResult<&str, Sensor> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Sensor>> {
-    separated_list1(tag("\n"), parse_line)(input)
+    parse::lines(parse_line)(input)
 }
 
-pub fn solve1() -> i32 {
+pub fn part1(input: &str) -> Result<i32> {
     // read input and make list of sensors data
-    let input = utils::read_input("src/year2022/day15/input.txt").unwrap();
-    let (_, sensors) = parse_input(input.as_str()).unwrap();
-    let mut intervals = Vec::new();
+    let sensors = utils::parse_all(parse_input, input)?;
+    let mut covered = IntervalSet::new();
 
     // line on which we count the number of positions that cannot contain a beacon
     let line_number = 2000000;
@@ -152,33 +96,12 @@ pub fn solve1() -> i32 {
         let r = s.radius();
         if (s.y - line_number).abs() <= r {
             let d = r - (s.y - line_number).abs();
-            intervals.push(Interval {
-                start: s.x - d,
-                end: s.x + d + 1,
-            });
+            covered.insert(Interval::closed(s.x - d, s.x + d));
         }
     }
 
-    // merge the overlapping intervals
-    intervals.sort();
-    let mut merged = Vec::new();
-    for i in intervals {
-        if merged.is_empty() {
-            merged.push(i);
-        } else {
-            let last = merged.last_mut().unwrap();
-            if last.end >= i.start {
-                last.end = last.end.max(i.end);
-            } else {
-                merged.push(i);
-            }
-        }
-    }
-    // count total length of intervals
-    let mut total = 0;
-    for i in merged {
-        total += i.size();
-    }
+    // count the positions covered by the (merged) intervals
+    let total = covered.len();
 
     // count the number of beacons that are on the line
     let mut beacons_on_line = HashSet::new();
@@ -188,29 +111,21 @@ pub fn solve1() -> i32 {
         }
     }
 
-    total - beacons_on_line.len() as i32
+    Ok(total - beacons_on_line.len() as i32)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2022/day15/input.txt").unwrap();
-    let (_, sensors) = parse_input(input.as_str()).unwrap();
+pub fn part2(input: &str) -> Result<i64> {
+    let sensors = utils::parse_all(parse_input, input)?;
 
     // make initial search space containing the [0, 4M] x [0, 4M] area
     let min_diag = -4_000_000;
     let max_diag = 4_000_000;
     let min_anti = 0;
     let max_anti = 8_000_000;
-    let mut search_space = Vec::new();
-    search_space.push(Diamond {
-        diag_interval: Interval {
-            start: min_diag,
-            end: max_diag + 1,
-        },
-        anti_interval: Interval {
-            start: min_anti,
-            end: max_anti + 1,
-        },
-    });
+    let mut search_space = vec![Diamond {
+        diag_interval: Interval::closed(min_diag, max_diag),
+        anti_interval: Interval::closed(min_anti, max_anti),
+    }];
 
     // remove the diamond covered by each sensor from the search space
     for sensor in &sensors {
@@ -234,16 +149,26 @@ pub fn solve2() -> i64 {
             let anti_diagonal = diamond.anti_interval.start;
             let x = (diagonal + anti_diagonal) as i64 / 2;
             let y = (anti_diagonal - diagonal) as i64 / 2;
-            return 4_000_000 * x + y;
+            return Ok(4_000_000 * x + y);
         }
     }
-    0
+    Err(Error::no_solution("no uncovered position in the search area"))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 15).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2022, 15).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 15);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -1,4 +1,7 @@
-use crate::utils;
+use crate::utils::linalg::Matrix;
+use crate::utils::memo::Memo;
+use crate::utils::{self, graph, parse, Grid};
+use crate::Result;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -7,57 +10,7 @@ use nom::{
     sequence::{preceded, tuple, pair},
     IResult,
 };
-use std::collections::{HashMap, HashSet};
-use std::fmt::Display;
-
-#[derive(Debug, Clone)]
-pub struct Matrix {
-    pub values: Vec<Vec<i32>>,
-    pub nb_rows: usize,
-    pub nb_cols: usize,
-}
-
-impl Matrix {
-    pub fn new(nb_rows: usize, nb_cols: usize) -> Matrix {
-        Matrix {
-            values: vec![vec![0; nb_cols]; nb_rows],
-            nb_rows,
-            nb_cols,
-        }
-    }
-
-    pub fn multiply(&self, other: &Matrix) -> Matrix {
-        let mut result = Matrix::new(self.nb_rows, other.nb_cols);
-        for i in 0..self.nb_rows {
-            for j in 0..other.nb_cols {
-                for k in 0..self.nb_cols {
-                    result.values[i][j] += self.values[i][k] * other.values[k][j];
-                }
-            }
-        }
-        result
-    }
-
-    pub fn fill(&mut self, value: i32) {
-        for i in 0..self.nb_rows {
-            for j in 0..self.nb_cols {
-                self.values[i][j] = value;
-            }
-        }
-    }
-}
-
-impl Display for Matrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.values {
-            for value in row {
-                write!(f, "{:2} ", value)?;
-            }
-            write!(f, "\n")?;
-        }
-        Ok(())
-    }
-}
+use std::collections::HashMap;
 
 fn parse_line(input: &str) -> IResult<&str, (&str, i32, Vec<&str>)> {
     tuple((
@@ -73,19 +26,17 @@ fn parse_line(input: &str) -> IResult<&str, (&str, i32, Vec<&str>)> {
     ))(input)
 }
 
-fn parse_input(filename: &str) -> (Matrix, Vec<i32>) {
+fn parse_input(input: &str) -> Result<(Matrix<i32>, Vec<i32>)> {
     // parse input and make node indexes map, adjacency lists and list of flow rates
     let mut node_indexes = HashMap::new();
     let mut adjacency = Vec::new();
     let mut flow_rates = Vec::new();
-
-    let input = utils::read_input(filename).unwrap();
     // sort input lines in ascending order (so that AA is the first node)
     let mut lines = input.lines().collect::<Vec<_>>();
     lines.sort();
 
     for line in lines {
-        let (_, (label, flow_rate, neighbor_labels)) = parse_line(line).unwrap();
+        let (label, flow_rate, neighbor_labels) = parse::parse_all_in(parse_line, input, line)?;
         node_indexes.insert(label, adjacency.len());
         flow_rates.push(flow_rate);
         let mut node_neighbors = Vec::new();
@@ -104,119 +55,119 @@ fn parse_input(filename: &str) -> (Matrix, Vec<i32>) {
     // Fill distance matrix
     for (node_index, node_neighbors) in adjacency.iter().enumerate() {
         for neighbor_index in node_neighbors {
-            matrix.values[node_index][*neighbor_index] = 1;
-            matrix.values[*neighbor_index][node_index] = 1;
+            matrix[(node_index, *neighbor_index)] = 1;
+            matrix[(*neighbor_index, node_index)] = 1;
         }
     }
 
     // return adjacency matrix and list of flow rates
-    (matrix, flow_rates)
+    Ok((matrix, flow_rates))
 }
 
-fn floyd_warshall(adj_matrix: Matrix) -> Matrix {
-    let n = adj_matrix.nb_rows;
-    let mut distances = Matrix::new(n, n);
-    distances.fill(std::i32::MAX);
-
-    for i in 0..n {
-        distances.values[i][i] = 0;
-        for j in 0..n {
-            if adj_matrix.values[i][j] == 1 {
-                distances.values[i][j] = 1;
-            }
-        }
-    }
-
-    for k in 0..n {
-        for i in 0..n {
-            for j in 0..n {
-                if distances.values[i][k] != std::i32::MAX
-                    && distances.values[k][j] != std::i32::MAX
-                {
-                    distances.values[i][j] =
-                        distances.values[i][j].min(distances.values[i][k] + distances.values[k][j]);
-                }
+/// Lengths of the shortest paths between all pairs of valves
+fn make_distances(adj_matrix: &Matrix<i32>) -> Grid<i32> {
+    let mut edges = Vec::new();
+    for i in 0..adj_matrix.nb_rows() {
+        for j in 0..adj_matrix.nb_cols() {
+            if adj_matrix[(i, j)] == 1 {
+                edges.push((i, j, 1));
             }
         }
     }
-
-    distances
+    graph::all_pairs(adj_matrix.nb_rows(), edges).map(|d| d.unwrap_or(i32::MAX))
 }
 
+/// Memo of the best pressure released, indexed by the current valve, the remaining time and the
+/// bitmask of the valves that can still be opened
+type ScoreMemo = Memo<(usize, i32, u64), i32>;
+
+/// Most pressure that can be released by opening some of the valves in `remaining_valves` (a
+/// bitmask of valve indexes) in the remaining time
 fn solve1_aux(
-    distances: &Matrix,
-    flow_rates: &Vec<i32>,
+    distances: &Grid<i32>,
+    flow_rates: &[i32],
     current_node: usize,
     remaining_time: i32,
-    remaining_valves: HashSet<usize>,
+    remaining_valves: u64,
+    memo: &mut ScoreMemo,
 ) -> i32 {
-    let mut best_score = 0;
-    for j in &remaining_valves {
-        if distances.values[current_node][*j] >= remaining_time - 1 {
-            // there isn't enough time to open the valve
-            continue;
+    memo.get_or_compute((current_node, remaining_time, remaining_valves), |memo, _| {
+        let mut best_score = 0;
+        for j in (0..flow_rates.len()).filter(|j| remaining_valves & (1 << j) != 0) {
+            if distances[(current_node, j)] >= remaining_time - 1 {
+                // there isn't enough time to open the valve
+                continue;
+            }
+            let time_after_opening = remaining_time - distances[(current_node, j)] - 1;
+            let score = solve1_aux(
+                distances,
+                flow_rates,
+                j,
+                time_after_opening,
+                remaining_valves & !(1 << j),
+                memo,
+            ) + flow_rates[j] * time_after_opening;
+            best_score = best_score.max(score);
         }
-        let mut new_remaining_valves = remaining_valves.clone();
-        new_remaining_valves.remove(&j);
-        let score = solve1_aux(
-            distances,
-            flow_rates,
-            *j,
-            remaining_time - distances.values[current_node][*j] - 1,
-            new_remaining_valves,
-        ) + flow_rates[*j] * (remaining_time - distances.values[current_node][*j] - 1);
-        best_score = best_score.max(score);
-    }
-    best_score
+        best_score
+    })
 }
 
-pub fn solve1() -> i32 {
-    let (adjacency_matrix, flow_rates) = parse_input("src/year2022/day16/input.txt");
-    let distances = floyd_warshall(adjacency_matrix);
+/// Indexes of the valves with a positive flow rate
+fn active_valves(flow_rates: &[i32]) -> Vec<usize> {
+    assert!(flow_rates.len() <= 64, "too many valves for a bitmask");
+    (0..flow_rates.len()).filter(|&i| flow_rates[i] > 0).collect()
+}
 
-    let mut remaining_valves = HashSet::new();
-    for i in 0..flow_rates.len() {
-        if flow_rates[i] > 0 {
-            remaining_valves.insert(i);
-        }
-    }
-    solve1_aux(&distances, &flow_rates, 0, 30, remaining_valves)
+pub fn part1(input: &str) -> Result<i32> {
+    let (adjacency_matrix, flow_rates) = parse_input(input)?;
+    let distances = make_distances(&adjacency_matrix);
+
+    let remaining_valves = active_valves(&flow_rates).iter().fold(0, |mask, i| mask | (1 << i));
+    Ok(solve1_aux(&distances, &flow_rates, 0, 30, remaining_valves, &mut Memo::new()))
 }
 
-pub fn solve2() -> i32 {
-    let (adjacency_matrix, flow_rates) = parse_input("src/year2022/day16/input.txt");
-    let distances = floyd_warshall(adjacency_matrix);
+pub fn part2(input: &str) -> Result<i32> {
+    let (adjacency_matrix, flow_rates) = parse_input(input)?;
+    let distances = make_distances(&adjacency_matrix);
 
     let mut best_score = 0;
-    let mut active_valves = Vec::new();
-    for i in 0..flow_rates.len() {
-        if flow_rates[i] > 0 {
-            active_valves.push(i);
-        }
-    }
+    let active_valves = active_valves(&flow_rates);
+    // both explorations share the same memo, as they only differ by the valves they can open
+    let mut memo = Memo::new();
 
     for i in 0..(1 << active_valves.len()) {
-        let mut valves1 = HashSet::new();
-        let mut valves2 = HashSet::new();
-        for j in 0..active_valves.len() {
+        let mut valves1 = 0;
+        let mut valves2 = 0;
+        for (j, valve) in active_valves.iter().enumerate() {
             if i & (1 << j) != 0 {
-                valves1.insert(active_valves[j]);
+                valves1 |= 1 << valve;
             } else {
-                valves2.insert(active_valves[j]);
+                valves2 |= 1 << valve;
             }
         }
 
-        let score1 = solve1_aux(&distances, &flow_rates, 0, 26, valves1);
-        let score2 = solve1_aux(&distances, &flow_rates, 0, 26, valves2);
+        let score1 = solve1_aux(&distances, &flow_rates, 0, 26, valves1, &mut memo);
+        let score2 = solve1_aux(&distances, &flow_rates, 0, 26, valves2, &mut memo);
         best_score = best_score.max(score1 + score2);
     }
-    best_score
+    Ok(best_score)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 16).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 16).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 16);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -225,7 +176,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (125s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (12s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,29 +1,27 @@
 use rand::{self, Rng};
 use std::collections::VecDeque;
 use std::fmt::Display;
+use std::iter;
 
 use crate::utils;
-
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
+use crate::utils::cycle;
+use crate::utils::geometry::Point2;
+use crate::{Error, Result};
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct RockShape {
-    points: Vec<Point>,
+    points: Vec<Point2>,
     max_x: i32,
     max_y: i32,
 }
 
 impl RockShape {
-    pub fn new(points: Vec<Point>) -> RockShape {
+    pub fn new(points: Vec<Point2>) -> RockShape {
         let mut max_x = 0;
         let mut max_y = 0;
         for point in points.iter() {
-            max_x = max_x.max(point.x);
-            max_y = max_y.max(point.y);
+            max_x = max_x.max(point.x());
+            max_y = max_y.max(point.y());
         }
         RockShape {
             points,
@@ -37,40 +35,40 @@ pub fn make_rock_shapes() -> [RockShape; 5] {
     [
         // - shape
         RockShape::new(vec![
-            Point { x: 0, y: 0 },
-            Point { x: 1, y: 0 },
-            Point { x: 2, y: 0 },
-            Point { x: 3, y: 0 },
+            Point2::new(0, 0),
+            Point2::new(1, 0),
+            Point2::new(2, 0),
+            Point2::new(3, 0),
         ]),
         // + shape
         RockShape::new(vec![
-            Point { x: 1, y: 0 },
-            Point { x: 0, y: 1 },
-            Point { x: 1, y: 1 },
-            Point { x: 2, y: 1 },
-            Point { x: 1, y: 2 },
+            Point2::new(1, 0),
+            Point2::new(0, 1),
+            Point2::new(1, 1),
+            Point2::new(2, 1),
+            Point2::new(1, 2),
         ]),
         // J shape
         RockShape::new(vec![
-            Point { x: 0, y: 0 },
-            Point { x: 1, y: 0 },
-            Point { x: 2, y: 0 },
-            Point { x: 2, y: 1 },
-            Point { x: 2, y: 2 },
+            Point2::new(0, 0),
+            Point2::new(1, 0),
+            Point2::new(2, 0),
+            Point2::new(2, 1),
+            Point2::new(2, 2),
         ]),
         // I shape
         RockShape::new(vec![
-            Point { x: 0, y: 0 },
-            Point { x: 0, y: 1 },
-            Point { x: 0, y: 2 },
-            Point { x: 0, y: 3 },
+            Point2::new(0, 0),
+            Point2::new(0, 1),
+            Point2::new(0, 2),
+            Point2::new(0, 3),
         ]),
         // square shape
         RockShape::new(vec![
-            Point { x: 0, y: 0 },
-            Point { x: 1, y: 0 },
-            Point { x: 0, y: 1 },
-            Point { x: 1, y: 1 },
+            Point2::new(0, 0),
+            Point2::new(1, 0),
+            Point2::new(0, 1),
+            Point2::new(1, 1),
         ]),
     ]
 }
@@ -79,7 +77,7 @@ pub fn make_rock_shapes() -> [RockShape; 5] {
 pub struct RockBlock {
     pub shape: RockShape,
     // absolute position of the block in the chamber
-    pub position: Point,
+    pub position: Point2,
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
@@ -161,10 +159,7 @@ impl Chamber {
 
         self.current_block = Some(RockBlock {
             shape: shape.clone(),
-            position: Point {
-                x: 2,
-                y: self.top_height + 3,
-            },
+            position: Point2::new(2, self.top_height + 3),
         });
     }
 
@@ -173,33 +168,33 @@ impl Chamber {
         self.jet_index = (self.jet_index + 1) % self.jet_patterns.len();
 
         if let Some(ref mut block) = self.current_block {
-            if block.position.x + direction < 0
-                || block.position.x + direction + block.shape.max_x >= 7
+            if block.position.x() + direction < 0
+                || block.position.x() + direction + block.shape.max_x >= 7
             {
                 // block would hit a wall
                 return;
             }
             for point in block.shape.points.iter() {
-                if self.grid[(block.position.y + point.y - self.height_shift) as usize]
-                    [(block.position.x + point.x + direction) as usize]
+                if self.grid[(block.position.y() + point.y() - self.height_shift) as usize]
+                    [(block.position.x() + point.x() + direction) as usize]
                 {
                     // block would hit another block
                     return;
                 }
             }
-            block.position.x += direction;
+            block.position += Point2::new(direction, 0);
         }
     }
 
     fn drop_block(&mut self) {
         if let Some(ref mut block) = self.current_block {
-            if block.position.y <= self.height_shift {
+            if block.position.y() <= self.height_shift {
                 panic!("block fell below the grid window");
             }
             let mut can_drop = true;
             for point in block.shape.points.iter() {
-                if self.grid[(block.position.y + point.y - self.height_shift - 1) as usize]
-                    [(block.position.x + point.x) as usize]
+                if self.grid[(block.position.y() + point.y() - self.height_shift - 1) as usize]
+                    [(block.position.x() + point.x()) as usize]
                 {
                     // block would hit another block
                     can_drop = false;
@@ -207,16 +202,16 @@ impl Chamber {
                 }
             }
             if can_drop {
-                block.position.y -= 1;
+                block.position -= Point2::new(0, 1);
             } else {
                 // freeze the block
                 for point in block.shape.points.iter() {
-                    self.grid[(block.position.y + point.y - self.height_shift) as usize]
-                        [(point.x + block.position.x) as usize] = true;
+                    self.grid[(block.position.y() + point.y() - self.height_shift) as usize]
+                        [(point.x() + block.position.x()) as usize] = true;
                 }
                 self.top_height = self
                     .top_height
-                    .max(block.position.y + block.shape.max_y + 1);
+                    .max(block.position.y() + block.shape.max_y + 1);
                 self.current_block = None;
             }
         }
@@ -237,8 +232,8 @@ impl Display for Chamber {
         let mut grid_copy = self.grid.clone();
         if let Some(block) = &self.current_block {
             for point in block.shape.points.iter() {
-                grid_copy[(block.position.y + point.y - self.height_shift) as usize]
-                    [(point.x + block.position.x) as usize] = true;
+                grid_copy[(block.position.y() + point.y() - self.height_shift) as usize]
+                    [(point.x() + block.position.x()) as usize] = true;
             }
         }
         for row in grid_copy.iter().rev() {
@@ -249,71 +244,70 @@ impl Display for Chamber {
                     write!(f, ".")?;
                 }
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
 }
 
-pub fn parse_input(filename: &str) -> Vec<i32> {
-    let input = utils::read_input(filename).unwrap();
+pub fn parse_input(input: &str) -> Result<Vec<i32>> {
     let mut jet_patterns = Vec::new();
-    for c in input.chars() {
+    for (k, c) in input.char_indices() {
         match c {
             '<' => jet_patterns.push(-1),
             '>' => jet_patterns.push(1),
-            _ => (),
+            c if c.is_whitespace() => (),
+            _ => return Err(Error::parse_at(input, k, "expected '<' or '>'")),
         }
     }
-    jet_patterns
+    if jet_patterns.is_empty() {
+        return Err(Error::parse_at(input, 0, "no jet pattern"));
+    }
+    Ok(jet_patterns)
 }
 
-pub fn solve1() -> i32 {
-    let jet_patterns = parse_input("src/year2022/day17/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let jet_patterns = parse_input(input)?;
     let mut chamber = Chamber::new(200, jet_patterns);
 
     for _ in 0..2022 {
         chamber.drop_new_block();
     }
     // println!("{}", chamber);
-    chamber.top_height
+    Ok(chamber.top_height)
 }
 
-pub fn solve2() -> i64 {
-    let jet_patterns = parse_input("src/year2022/day17/input.txt");
+pub fn part2(input: &str) -> Result<i64> {
+    let jet_patterns = parse_input(input)?;
     // let jet_patterns = make_random_jet_patterns(10091);
-    let mut chamber1 = Chamber::new(200, jet_patterns.clone());
-    let mut chamber2 = Chamber::new(200, jet_patterns.clone());
+    let mut chamber = Chamber::new(200, jet_patterns);
 
-    let mut steps_difference = 0;
-    loop {
-        chamber1.drop_new_block_set();
-        chamber2.drop_new_block_set();
-        chamber2.drop_new_block_set();
-        steps_difference += 1;
-        if chamber1.jet_index == chamber2.jet_index && chamber1.grid == chamber2.grid {
-            break;
-        }
-    }
-    println!("steps_difference: {}", steps_difference);
+    // states of the chamber after each set of 5 blocks (the next block is always the first shape)
+    let states = iter::repeat_with(|| {
+        let state = (chamber.grid.clone(), chamber.jet_index, chamber.top_height as i64);
+        chamber.drop_new_block_set();
+        state
+    });
+    let (cycle, states) = cycle::find_cycle_by_key(states, |(grid, jet_index, _)| (grid.clone(), *jet_index))
+        .ok_or_else(|| Error::no_solution("the chamber never repeats"))?;
+    let heights: Vec<i64> = states.into_iter().map(|(_, _, height)| height).collect();
+    Ok(cycle.extrapolate(&heights, 1000000000000 / 5))
+}
 
-    let height1 = chamber1.top_height as i64;
-    let height2 = chamber2.top_height as i64;
-    let nb_steps: i64 = 1000000000000 / 5;
-    let nb_cycles = nb_steps / steps_difference as i64 - 1;
-    let extra_steps = nb_steps % steps_difference as i64;
-    for _ in 0..extra_steps {
-        chamber2.drop_new_block_set();
-    }
-    let height3 = chamber2.top_height as i64;
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 17).unwrap()).unwrap()
+}
 
-    height1 + (height2 - height1) * nb_cycles + (height3 - height2)
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2022, 17).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 17);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
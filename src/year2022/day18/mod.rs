@@ -6,7 +6,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils::{self, parse_int};
+use crate::utils::{self, parse, parse_int};
+use crate::Result;
 
 fn parse_line(input: &str) -> IResult<&str, (i32, i32, i32)> {
     tuple((
@@ -16,11 +17,13 @@ fn parse_line(input: &str) -> IResult<&str, (i32, i32, i32)> {
     ))(input)
 }
 
-pub fn solve1() -> i32 {
+fn parse_input(input: &str) -> Result<Vec<(i32, i32, i32)>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut grid = vec![vec![vec![false; 22]; 22]; 22];
-    let input = utils::read_input("src/year2022/day18/input.txt").unwrap();
-    for line in input.lines() {
-        let (x, y, z) = parse_line(line).unwrap().1;
+    for (x, y, z) in parse_input(input)? {
         grid[x as usize][y as usize][z as usize] = true;
     }
     let neighbors: Vec<(i32, i32, i32)> = vec![
@@ -40,12 +43,9 @@ pub fn solve1() -> i32 {
                         let x = x as i32 + dx;
                         let y = y as i32 + dy;
                         let z = z as i32 + dz;
-                        if x < 0
-                            || x > 21
-                            || y < 0
-                            || y > 21
-                            || z < 0
-                            || z > 21
+                        if !(0..=21).contains(&x)
+                            || !(0..=21).contains(&y)
+                            || !(0..=21).contains(&z)
                             || !grid[x as usize][y as usize][z as usize]
                         {
                             counter += 1;
@@ -55,18 +55,16 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    counter
+    Ok(counter)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2022/day18/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut points = Vec::new();
     let mut max_coordinate = 0;
-    for line in input.lines() {
-        let (x, y, z) = parse_line(line).unwrap().1;
+    for (x, y, z) in parse_input(input)? {
         let (x, y, z) = (x as usize, y as usize, z as usize);
         max_coordinate = max_coordinate.max(x).max(y).max(z);
-        points.push((x as usize, y as usize, z as usize));
+        points.push((x, y, z));
     }
     let grid_size = max_coordinate + 3;
     let mut grid = vec![vec![vec![false; grid_size]; grid_size]; grid_size];
@@ -90,8 +88,7 @@ pub fn solve2() -> i32 {
     stack.push((0, 0, 0));
     visited.insert((0, 0, 0));
 
-    while !stack.is_empty() {
-        let (x, y, z) = stack.pop().unwrap();
+    while let Some((x, y, z)) = stack.pop() {
         for (dx, dy, dz) in &neighbors {
             let nx = x + dx;
             let ny = y + dy;
@@ -114,7 +111,15 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    counter
+    Ok(counter)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 18).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 18).unwrap()).unwrap()
 }
 
 #[cfg(test)]
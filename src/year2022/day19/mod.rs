@@ -1,10 +1,16 @@
-use crate::utils::{self, parse_int};
+use crate::utils::memo::Memo;
+use crate::utils::{self, parse, parse_int};
+use crate::Result;
 use nom::{
     bytes::complete::tag,
     sequence::{delimited, pair, terminated, tuple},
     IResult,
 };
 
+/// Memo of the number of geodes that can be opened, indexed by the remaining time and the numbers
+/// of resources and robots of each type
+type GeodesMemo = Memo<[i32; 8], i32>;
+
 #[derive(Debug)]
 struct Blueprint {
     index: i32,
@@ -35,6 +41,7 @@ impl Blueprint {
             max_ore_cost,
         }
     }
+    #[allow(clippy::too_many_arguments)]
     fn get_nb_geodes_aux(
         &self,
         time: i32,
@@ -44,106 +51,127 @@ impl Blueprint {
         nb_clay_robots: i32,
         nb_obsidian: i32,
         nb_obsidian_robots: i32,
-        nb_geode: i32,
         nb_geode_robots: i32,
+        memo: &mut GeodesMemo,
     ) -> i32 {
-        if time < 0 {
-            return 0;
-        }
+        // resources that cannot be spent in the remaining time do not change the result
+        let nb_ore = nb_ore.min(time * self.max_ore_cost.max(self.ore_robot_cost));
+        let nb_clay = nb_clay.min(time * self.obsidian_robot_cost.1);
+        let nb_obsidian = nb_obsidian.min(time * self.geode_robot_cost.1);
+        let state = [
+            time,
+            nb_ore,
+            nb_ore_robots,
+            nb_clay,
+            nb_clay_robots,
+            nb_obsidian,
+            nb_obsidian_robots,
+            nb_geode_robots,
+        ];
+        memo.get_or_compute(state, |memo, _| {
+            if time < 0 {
+                return 0;
+            }
 
-        let mut result = nb_geode + time * nb_geode_robots;
-
-        // make an ore robot
-        if nb_ore_robots < self.max_ore_cost {
-            let time_for_robot =
-                utils::div_up(self.ore_robot_cost - nb_ore, nb_ore_robots).max(0) + 1;
-            if time_for_robot <= time {
-                result = result.max(self.get_nb_geodes_aux(
-                    time - time_for_robot,
-                    nb_ore + time_for_robot * nb_ore_robots - self.ore_robot_cost,
-                    nb_ore_robots + 1,
-                    nb_clay + time_for_robot * nb_clay_robots,
-                    nb_clay_robots,
-                    nb_obsidian + time_for_robot * nb_obsidian_robots,
-                    nb_obsidian_robots,
-                    nb_geode + time_for_robot * nb_geode_robots,
-                    nb_geode_robots,
-                ));
+            let mut result = time * nb_geode_robots;
+
+            // make an ore robot
+            if nb_ore_robots < self.max_ore_cost {
+                let time_for_robot =
+                    utils::div_up(self.ore_robot_cost - nb_ore, nb_ore_robots).max(0) + 1;
+                if time_for_robot <= time {
+                    let nb_geodes = self.get_nb_geodes_aux(
+                        time - time_for_robot,
+                        nb_ore + time_for_robot * nb_ore_robots - self.ore_robot_cost,
+                        nb_ore_robots + 1,
+                        nb_clay + time_for_robot * nb_clay_robots,
+                        nb_clay_robots,
+                        nb_obsidian + time_for_robot * nb_obsidian_robots,
+                        nb_obsidian_robots,
+                        nb_geode_robots,
+                        memo,
+                    );
+                    // geodes opened while waiting for the robot, and after it is built
+                    result = result.max(time_for_robot * nb_geode_robots + nb_geodes);
+                }
             }
-        }
 
-        // make a clay robot
-        if nb_clay_robots < self.obsidian_robot_cost.1 {
-            let time_for_robot =
-                utils::div_up(self.clay_robot_cost - nb_ore, nb_ore_robots).max(0) + 1;
-            if time_for_robot <= time {
-                result = result.max(self.get_nb_geodes_aux(
-                    time - time_for_robot,
-                    nb_ore + time_for_robot * nb_ore_robots - self.clay_robot_cost,
-                    nb_ore_robots,
-                    nb_clay + time_for_robot * nb_clay_robots,
-                    nb_clay_robots + 1,
-                    nb_obsidian + time_for_robot * nb_obsidian_robots,
-                    nb_obsidian_robots,
-                    nb_geode + time_for_robot * nb_geode_robots,
-                    nb_geode_robots,
-                ));
+            // make a clay robot
+            if nb_clay_robots < self.obsidian_robot_cost.1 {
+                let time_for_robot =
+                    utils::div_up(self.clay_robot_cost - nb_ore, nb_ore_robots).max(0) + 1;
+                if time_for_robot <= time {
+                    let nb_geodes = self.get_nb_geodes_aux(
+                        time - time_for_robot,
+                        nb_ore + time_for_robot * nb_ore_robots - self.clay_robot_cost,
+                        nb_ore_robots,
+                        nb_clay + time_for_robot * nb_clay_robots,
+                        nb_clay_robots + 1,
+                        nb_obsidian + time_for_robot * nb_obsidian_robots,
+                        nb_obsidian_robots,
+                        nb_geode_robots,
+                        memo,
+                    );
+                    result = result.max(time_for_robot * nb_geode_robots + nb_geodes);
+                }
             }
-        }
 
-        // make an obsidian robot
-        if nb_clay_robots > 0 && nb_obsidian_robots < self.geode_robot_cost.1 {
-            let time_for_robot = utils::div_up(self.obsidian_robot_cost.0 - nb_ore, nb_ore_robots)
-                .max(utils::div_up(
-                    self.obsidian_robot_cost.1 - nb_clay,
-                    nb_clay_robots,
-                ))
-                .max(0)
-                + 1;
-            if time_for_robot <= time {
-                result = result.max(self.get_nb_geodes_aux(
-                    time - time_for_robot,
-                    nb_ore + time_for_robot * nb_ore_robots - self.obsidian_robot_cost.0,
-                    nb_ore_robots,
-                    nb_clay + time_for_robot * nb_clay_robots - self.obsidian_robot_cost.1,
-                    nb_clay_robots,
-                    nb_obsidian + time_for_robot * nb_obsidian_robots,
-                    nb_obsidian_robots + 1,
-                    nb_geode + time_for_robot * nb_geode_robots,
-                    nb_geode_robots,
-                ));
+            // make an obsidian robot
+            if nb_clay_robots > 0 && nb_obsidian_robots < self.geode_robot_cost.1 {
+                let time_for_robot = utils::div_up(self.obsidian_robot_cost.0 - nb_ore, nb_ore_robots)
+                    .max(utils::div_up(
+                        self.obsidian_robot_cost.1 - nb_clay,
+                        nb_clay_robots,
+                    ))
+                    .max(0)
+                    + 1;
+                if time_for_robot <= time {
+                    let nb_geodes = self.get_nb_geodes_aux(
+                        time - time_for_robot,
+                        nb_ore + time_for_robot * nb_ore_robots - self.obsidian_robot_cost.0,
+                        nb_ore_robots,
+                        nb_clay + time_for_robot * nb_clay_robots - self.obsidian_robot_cost.1,
+                        nb_clay_robots,
+                        nb_obsidian + time_for_robot * nb_obsidian_robots,
+                        nb_obsidian_robots + 1,
+                        nb_geode_robots,
+                        memo,
+                    );
+                    result = result.max(time_for_robot * nb_geode_robots + nb_geodes);
+                }
             }
-        }
 
-        // make a geode robot
-        if nb_obsidian_robots > 0 {
-            let time_for_robot = utils::div_up(self.geode_robot_cost.0 - nb_ore, nb_ore_robots)
-                .max(utils::div_up(
-                    self.geode_robot_cost.1 - nb_obsidian,
-                    nb_obsidian_robots,
-                ))
-                .max(0)
-                + 1;
-            if time_for_robot <= time {
-                result = result.max(self.get_nb_geodes_aux(
-                    time - time_for_robot,
-                    nb_ore + time_for_robot * nb_ore_robots - self.geode_robot_cost.0,
-                    nb_ore_robots,
-                    nb_clay + time_for_robot * nb_clay_robots,
-                    nb_clay_robots,
-                    nb_obsidian + time_for_robot * nb_obsidian_robots - self.geode_robot_cost.1,
-                    nb_obsidian_robots,
-                    nb_geode + time_for_robot * nb_geode_robots,
-                    nb_geode_robots + 1,
-                ));
+            // make a geode robot
+            if nb_obsidian_robots > 0 {
+                let time_for_robot = utils::div_up(self.geode_robot_cost.0 - nb_ore, nb_ore_robots)
+                    .max(utils::div_up(
+                        self.geode_robot_cost.1 - nb_obsidian,
+                        nb_obsidian_robots,
+                    ))
+                    .max(0)
+                    + 1;
+                if time_for_robot <= time {
+                    let nb_geodes = self.get_nb_geodes_aux(
+                        time - time_for_robot,
+                        nb_ore + time_for_robot * nb_ore_robots - self.geode_robot_cost.0,
+                        nb_ore_robots,
+                        nb_clay + time_for_robot * nb_clay_robots,
+                        nb_clay_robots,
+                        nb_obsidian + time_for_robot * nb_obsidian_robots - self.geode_robot_cost.1,
+                        nb_obsidian_robots,
+                        nb_geode_robots + 1,
+                        memo,
+                    );
+                    result = result.max(time_for_robot * nb_geode_robots + nb_geodes);
+                }
             }
-        }
 
-        result
+            result
+        })
     }
 
     fn get_nb_geodes(&self, time: i32) -> i32 {
-        self.get_nb_geodes_aux(time, 0, 1, 0, 0, 0, 0, 0, 0)
+        self.get_nb_geodes_aux(time, 0, 1, 0, 0, 0, 0, 0, &mut Memo::new())
     }
 }
 
@@ -189,48 +217,47 @@ fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
     Ok((remaining, Blueprint::new(index, c1, c2, (c3, c4), (c5, c6))))
 }
 
-fn make_blueprints(filename: &str) -> Vec<Blueprint> {
-    let input = utils::read_input(filename).unwrap();
-    let mut blueprints = Vec::new();
-    for line in input.lines() {
-        let (_, blueprint) = parse_blueprint(line).unwrap();
-        blueprints.push(blueprint);
-    }
-    blueprints
+fn make_blueprints(input: &str) -> Result<Vec<Blueprint>> {
+    utils::parse_all(parse::lines(parse_blueprint), input)
 }
 
-pub fn solve1() -> i32 {
-    let blueprints = make_blueprints("src/year2022/day19/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let blueprints = make_blueprints(input)?;
     let mut total = 0;
     for blueprint in blueprints.iter() {
-        println!("blueprint: {:?}", blueprint);
-        let nb_geodes = blueprint.get_nb_geodes(24);
-        println!("nb geodes: {}", nb_geodes);
-        total += blueprint.index * nb_geodes;
+        total += blueprint.index * blueprint.get_nb_geodes(24);
     }
 
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let blueprints = make_blueprints("src/year2022/day19/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let blueprints = make_blueprints(input)?;
     let mut total = 1;
 
-    for blueprint in &blueprints[0..3] {
-        println!("blueprint: {:?}", blueprint);
-        let nb_geodes = blueprint.get_nb_geodes(32);
-        println!("nb geodes: {}", nb_geodes);
-        total *= nb_geodes;
+    for blueprint in blueprints.iter().take(3) {
+        total *= blueprint.get_nb_geodes(32);
     }
 
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 19).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 19).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 19);
+
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1s)")]
     fn test_solve1() {
         let solution = solve1();
         println!("Part One: {}", solution);
@@ -238,7 +265,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (12s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (2s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
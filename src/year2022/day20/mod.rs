@@ -1,7 +1,12 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 
 fn mix(mut values: Vec<i64>, nb_times: usize) -> Vec<i64> {
     let n = values.len();
+    if n <= 1 {
+        // moving the only value does not change the list
+        return values;
+    }
     // indexes[i] is the current index in values of the value that was initially at index i
     let mut indexes = (0..n).collect::<Vec<usize>>();
 
@@ -14,15 +19,15 @@ fn mix(mut values: Vec<i64>, nb_times: usize) -> Vec<i64> {
             values.insert(end_index, value);
 
             if start_index < end_index {
-                for j in 0..n {
-                    if start_index < indexes[j] && indexes[j] <= end_index {
-                        indexes[j] -= 1;
+                for index in indexes.iter_mut() {
+                    if start_index < *index && *index <= end_index {
+                        *index -= 1;
                     }
                 }
             } else if end_index < start_index {
-                for j in 0..n {
-                    if end_index <= indexes[j] && indexes[j] < start_index {
-                        indexes[j] += 1;
+                for index in indexes.iter_mut() {
+                    if end_index <= *index && *index < start_index {
+                        *index += 1;
                     }
                 }
             }
@@ -34,44 +39,58 @@ fn mix(mut values: Vec<i64>, nb_times: usize) -> Vec<i64> {
     values
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2022/day20/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i64> {
     let mut values = Vec::new();
     for line in input.lines() {
-        values.push(line.parse::<i64>().unwrap());
+        values.push(parse::value::<i64>(input, line)?);
     }
 
     let values = mix(values, 1);
     let n = values.len();
 
-    let zero_index = values.iter().position(|&x| x == 0).unwrap();
+    let zero_index = values.iter().position(|&x| x == 0).ok_or_else(|| Error::no_solution("no value 0 in the list"))?;
 
-    values[(zero_index + 1000) % n]
+    Ok(values[(zero_index + 1000) % n]
         + values[(zero_index + 2000) % n]
-        + values[(zero_index + 3000) % n]
+        + values[(zero_index + 3000) % n])
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2022/day20/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut values = Vec::new();
     for line in input.lines() {
-        values.push(line.parse::<i64>().unwrap() * 811589153);
+        values.push(parse::value::<i64>(input, line)? * 811589153);
     }
     
     let values = mix(values, 10);
     let n = values.len();
 
-    let zero_index = values.iter().position(|&x| x == 0).unwrap();
+    let zero_index = values.iter().position(|&x| x == 0).ok_or_else(|| Error::no_solution("no value 0 in the list"))?;
 
-    values[(zero_index + 1000) % n]
+    Ok(values[(zero_index + 1000) % n]
         + values[(zero_index + 2000) % n]
-        + values[(zero_index + 3000) % n]
+        + values[(zero_index + 3000) % n])
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2022, 20).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2022, 20).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 20);
+
+    #[test]
+    fn test_single_value() {
+        assert_eq!(mix(vec![0], 1), vec![0]);
+        assert_eq!(part1("0").unwrap(), 0);
+    }
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -80,7 +99,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (7s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (3s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,4 +1,5 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -18,7 +19,7 @@ enum Job {
 
 fn parse_job(input: &str) -> IResult<&str, Job> {
     alt((
-        map(utils::parse_int, |x| Job::Number(x)),
+        map(utils::parse_int, Job::Number),
         map(
             tuple((
                 alpha1,
@@ -34,117 +35,133 @@ fn parse_line(input: &str) -> IResult<&str, (&str, Job)> {
     separated_pair(alpha1, tag(": "), parse_job)(input)
 }
 
-fn make_jobs(filename: &str) -> HashMap<String, Job> {
-    let input = utils::read_input(filename).unwrap();
-    let mut jobs = HashMap::new();
-
-    for line in input.lines() {
-        let (_, (label, job)) = parse_line(line).unwrap();
-        jobs.insert(label.to_string(), job);
-    }
+fn make_jobs(input: &str) -> Result<HashMap<String, Job>> {
+    let lines = utils::parse_all(parse::lines(parse_line), input)?;
+    Ok(lines.into_iter().map(|(label, job)| (label.to_string(), job)).collect())
+}
 
-    jobs
+fn get_job(label: &str, jobs: &HashMap<String, Job>) -> Result<Job> {
+    jobs.get(label)
+        .cloned()
+        .ok_or_else(|| Error::no_solution(format!("no job for monkey {}", label)))
 }
 
-fn eval(label: &str, jobs: &mut HashMap<String, Job>) -> Option<i64> {
-    let job = jobs.get(label).unwrap().clone();
+/// Applies an operator, failing on overflows and divisions by zero
+fn apply(op: char, val1: i64, val2: i64) -> Result<i64> {
+    let value = match op {
+        '+' => val1.checked_add(val2),
+        '*' => val1.checked_mul(val2),
+        '-' => val1.checked_sub(val2),
+        '/' => val1.checked_div(val2),
+        _ => None,
+    };
+    value.ok_or_else(|| Error::no_solution(format!("cannot compute {} {} {}", val1, op, val2)))
+}
 
-    match job {
-        Job::Number(x) => return Some(x),
+/// Value of a job, or `None` if it depends on an unknown job
+fn eval(label: &str, jobs: &mut HashMap<String, Job>) -> Result<Option<i64>> {
+    match get_job(label, jobs)? {
+        Job::Number(x) => Ok(Some(x)),
         Job::Operation(label1, op, label2) => {
-            let val1 = eval(&label1, jobs);
-            let val2 = eval(&label2, jobs);
+            let val1 = eval(&label1, jobs)?;
+            let val2 = eval(&label2, jobs)?;
             match (val1, val2) {
                 (Some(val1), Some(val2)) => {
-                    let value = match op {
-                        '+' => val1 + val2,
-                        '*' => val1 * val2,
-                        '-' => val1 - val2,
-                        '/' => val1 / val2,
-                        _ => panic!("Unknown operator"),
-                    };
+                    let value = apply(op, val1, val2)?;
                     jobs.insert(label.to_string(), Job::Number(value));
-                    return Some(value);
+                    Ok(Some(value))
                 }
-                _ => return None,
+                _ => Ok(None),
             }
         }
-        Job::Unknown => return None,
+        Job::Unknown => Ok(None),
     }
 }
 
-fn set_value(label: &str, value: i64, jobs: &mut HashMap<String, Job>) {
-    let job = jobs.get(label).unwrap().clone();
-    match job {
+/// Sets the unknown job that a job depends on so that the job has the given value
+fn set_value(label: &str, value: i64, jobs: &mut HashMap<String, Job>) -> Result<()> {
+    match get_job(label, jobs)? {
         Job::Unknown => {
             jobs.insert(label.to_string(), Job::Number(value));
+            Ok(())
         }
         Job::Operation(label1, op, label2) => {
-            let val1 = eval(&label1, jobs);
-            let val2 = eval(&label2, jobs);
+            let val1 = eval(&label1, jobs)?;
+            let val2 = eval(&label2, jobs)?;
             match (val1, val2) {
-                (Some(val1), None) => match op {
-                    '+' => set_value(&label2, value - val1, jobs),
-                    '*' => set_value(&label2, value / val1, jobs),
-                    '-' => set_value(&label2, val1 - value, jobs),
-                    '/' => set_value(&label2, val1 / value, jobs),
-                    _ => panic!("Unknown operator"),
-                },
-                (None, Some(val2)) => match op {
-                    '+' => set_value(&label1, value - val2, jobs),
-                    '*' => set_value(&label1, value / val2, jobs),
-                    '-' => set_value(&label1, value + val2, jobs),
-                    '/' => set_value(&label1, value * val2, jobs),
-                    _ => panic!("Unknown operator"),
-                },
-                _ => {
-                    panic!("Cannot set value");
+                (Some(val1), None) => {
+                    let value = match op {
+                        '+' => apply('-', value, val1)?,
+                        '*' => apply('/', value, val1)?,
+                        '-' => apply('-', val1, value)?,
+                        _ => apply('/', val1, value)?,
+                    };
+                    set_value(&label2, value, jobs)
+                }
+                (None, Some(val2)) => {
+                    let value = match op {
+                        '+' => apply('-', value, val2)?,
+                        '*' => apply('/', value, val2)?,
+                        '-' => apply('+', value, val2)?,
+                        _ => apply('*', value, val2)?,
+                    };
+                    set_value(&label1, value, jobs)
                 }
+                _ => Err(Error::no_solution(format!("cannot set the value of {}", label))),
             }
         }
-        _ => panic!("Cannot set value"),
+        Job::Number(_) => Err(Error::no_solution(format!("cannot set the value of {}", label))),
     }
 }
 
-pub fn solve1() -> i64 {
-    let mut jobs = make_jobs("src/year2022/day21/input.txt");
-    eval("root", &mut jobs).unwrap()
+pub fn part1(input: &str) -> Result<i64> {
+    let mut jobs = make_jobs(input)?;
+    eval("root", &mut jobs)?.ok_or_else(|| Error::no_solution("root value depends on an unknown job"))
 }
 
-pub fn solve2() -> i64 {
-    let mut jobs = make_jobs("src/year2022/day21/input.txt");
+pub fn part2(input: &str) -> Result<i64> {
+    let mut jobs = make_jobs(input)?;
     let label1;
     let label2;
     // get two labels that should be equal from root
-    let root_job = jobs.get("root").unwrap();
-    match root_job {
-        Job::Operation(a, _, b) => {
+    match jobs.get("root") {
+        Some(Job::Operation(a, _, b)) => {
             label1 = a.clone();
             label2 = b.clone();
         }
-        _ => panic!("Root job is not an operation"),
+        _ => return Err(Error::no_solution("root job is not an operation")),
     }
     jobs.remove("root");
     // set job "humn" as Unknown
     jobs.insert(String::from("humn"), Job::Unknown);
 
-    match (eval(&label1, &mut jobs), eval(&label2, &mut jobs)) {
+    match (eval(&label1, &mut jobs)?, eval(&label2, &mut jobs)?) {
         (Some(val1), None) => {
-            set_value(&label2, val1, &mut jobs);
+            set_value(&label2, val1, &mut jobs)?;
         }
         (None, Some(val2)) => {
-            set_value(&label1, val2, &mut jobs);
+            set_value(&label1, val2, &mut jobs)?;
         }
-        _ => panic!("No value to set"),
+        _ => return Err(Error::no_solution("exactly one side of root should depend on humn")),
     }
 
-    eval("humn", &mut jobs).unwrap()
+    eval("humn", &mut jobs)?.ok_or_else(|| Error::no_solution("humn value could not be determined"))
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2022, 21).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2022, 21).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 21);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
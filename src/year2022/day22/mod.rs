@@ -1,14 +1,23 @@
 use nom::{
     branch::alt,
-    character::complete::{digit1, one_of},
+    character::complete::one_of,
     combinator::map,
     multi::many1,
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse, Direction};
+use crate::{Error, Result};
 
-static DIRECTIONS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+/// Value of a direction in the final password
+fn facing(direction: Direction) -> i32 {
+    match direction {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    }
+}
 
 #[derive(Debug)]
 enum Instruction {
@@ -36,16 +45,12 @@ struct CubePosition {
     face_index: usize,
     i: i32,
     j: i32,
-    dir: i32,
+    dir: Direction,
 }
 
 impl Cube {
-    fn new(filename: &str, size: i32) -> Cube {
-        let map_input = utils::read_input(filename).unwrap();
-        let map = map_input
-            .lines()
-            .map(|l| l.chars().map(|c| c as u8).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+    fn new(map_input: &str, size: i32) -> Result<Cube> {
+        let map = parse_map(map_input)?;
         let faces = vec![
             CubeFace {
                 offset_i: 0,
@@ -97,17 +102,32 @@ impl Cube {
             },
         ];
 
-        Cube {
+        // every cell of every face must be a tile of the map
+        let lines = map_input.lines().collect::<Vec<_>>();
+        for face in &faces {
+            for i in face.offset_i..face.offset_i + size {
+                let line = lines
+                    .get(i as usize)
+                    .ok_or_else(|| Error::parse_at(map_input, map_input.len(), "map is too small for the cube layout"))?;
+                for j in face.offset_j..face.offset_j + size {
+                    if !matches!(map[i as usize].get(j as usize), Some(b'.' | b'#')) {
+                        return Err(Error::parse_in(map_input, line, "row does not match the cube layout"));
+                    }
+                }
+            }
+        }
+
+        Ok(Cube {
             size,
             map,
             faces,
-        }
+        })
     }
 
     fn step(&self, p: &CubePosition) -> CubePosition {
-        let (di, dj) = DIRECTIONS[p.dir as usize];
-        let mut i = p.i + di;
-        let mut j = p.j + dj;
+        let (di, dj) = p.dir.offset();
+        let mut i = p.i + di as i32;
+        let mut j = p.j + dj as i32;
         let mut dir = p.dir;
         let mut face_index = p.face_index;
         let new_face;
@@ -141,32 +161,31 @@ impl Cube {
         }
 
         face_index = new_face.0;
-        dir = (dir + new_face.1).rem_euclid(4);
+        dir = dir.turn(new_face.1);
         match dir {
-            0 => {
+            Direction::East => {
                 // entering from left side
                 i = k;
                 j = 0;
             }
-            1 => {
+            Direction::South => {
                 // entering from top side
                 i = 0;
                 j = self.size - k - 1;
             }
-            2 => {
+            Direction::West => {
                 // entering from right side
                 i = self.size - k - 1;
                 j = self.size - 1;
             }
-            3 => {
+            Direction::North => {
                 // entering from bottom side
                 i = self.size - 1;
                 j = k;
             }
-            _ => panic!("Invalid direction"),
         }
         CubePosition {
-            face_index: face_index,
+            face_index,
             i,
             j,
             dir,
@@ -184,11 +203,9 @@ fn parse_move(input: &str) -> IResult<&str, Instruction> {
         map(one_of("RL"), |c| match c {
             'R' => Instruction::Rotate(1),
             'L' => Instruction::Rotate(-1),
-            _ => panic!("Invalid rotation"),
-        }),
-        map(digit1, |s: &str| {
-            Instruction::Move(s.parse::<i32>().unwrap())
+            _ => unreachable!("invalid rotation"),
         }),
+        map(utils::parse_unsigned_int, Instruction::Move),
     ))(input)
 }
 
@@ -196,52 +213,63 @@ fn parse_moves(input: &str) -> IResult<&str, Vec<Instruction>> {
     many1(parse_move)(input)
 }
 
-fn parse_map(filename: &str) -> Vec<Vec<u8>> {
-    let map_input = utils::read_input(filename).unwrap();
+/// Splits the input into the map and the parsed instructions
+fn parse_input(input: &str) -> Result<(&str, Vec<Instruction>)> {
+    let (map_input, instructions_input) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the map"))?;
+    Ok((map_input, parse::parse_all_in(parse_moves, input, instructions_input)?))
+}
+
+/// Parses the map, padding rows with spaces to the width of the widest row
+fn parse_map(map_input: &str) -> Result<Vec<Vec<u8>>> {
     let mut map = map_input
         .lines()
         .map(|l| l.chars().map(|c| c as u8).collect::<Vec<_>>())
         .collect::<Vec<_>>();
 
-    let max_width = map.iter().map(|l| l.len()).max().unwrap();
+    let max_width = map.iter().map(|l| l.len()).max().unwrap_or(0);
+    if max_width == 0 {
+        return Err(Error::parse_at(map_input, 0, "empty map"));
+    }
     for line in map.iter_mut() {
         while line.len() < max_width {
-            line.push(' ' as u8);
+            line.push(b' ');
         }
     }
-    map
+    Ok(map)
 }
 
-pub fn solve1() -> i32 {
-    // parse instructions
-    let (_, instructions) =
-        parse_moves(&utils::read_input("src/year2022/day22/instructions.txt").unwrap()).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let (map_input, instructions) = parse_input(input)?;
 
     // parse map
-    let map = parse_map("src/year2022/day22/map.txt");
+    let map = parse_map(map_input)?;
     let height = map.len() as i32;
     let width = map[0].len() as i32;
 
     // find starting position
-    let (mut i, mut j) = (0, 0);
-    while map[i as usize][j as usize] != b'.' {
-        j += 1;
-    }
+    let mut i = 0;
+    let mut j = map[0]
+        .iter()
+        .position(|&c| c == b'.')
+        .ok_or_else(|| Error::parse_at(map_input, 0, "no open tile on the first row"))? as i32;
 
-    let mut dir = 0;
+    let mut dir = Direction::East;
     for instruction in instructions {
         match instruction {
             Instruction::Rotate(r) => {
-                dir = (dir + r).rem_euclid(4);
+                dir = dir.turn(r);
             }
             Instruction::Move(m) => {
-                let (di, dj) = DIRECTIONS[dir as usize];
+                let (di, dj) = dir.offset();
+                let (di, dj) = (di as i32, dj as i32);
                 for _ in 0..m {
                     if di != 0 {
                         // move vertically
-                        let mut i2 = (i + di).rem_euclid(height as i32);
+                        let mut i2 = (i + di).rem_euclid(height);
                         while map[i2 as usize][j as usize] == b' ' {
-                            i2 = (i2 + di).rem_euclid(height as i32);
+                            i2 = (i2 + di).rem_euclid(height);
                         }
                         if map[i2 as usize][j as usize] == b'#' {
                             // hit a wall
@@ -251,9 +279,9 @@ pub fn solve1() -> i32 {
                         }
                     } else {
                         // move horizontally
-                        let mut j2 = (j + dj).rem_euclid(width as i32);
+                        let mut j2 = (j + dj).rem_euclid(width);
                         while map[i as usize][j2 as usize] == b' ' {
-                            j2 = (j2 + dj).rem_euclid(width as i32);
+                            j2 = (j2 + dj).rem_euclid(width);
                         }
                         if map[i as usize][j2 as usize] == b'#' {
                             // hit a wall
@@ -266,28 +294,26 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    1000 * (i + 1) + 4 * (j + 1) + dir
+    Ok(1000 * (i + 1) + 4 * (j + 1) + facing(dir))
 }
 
-pub fn solve2() -> i32 {
-    // parse instructions
-    let (_, instructions) =
-        parse_moves(&utils::read_input("src/year2022/day22/instructions.txt").unwrap()).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let (map_input, instructions) = parse_input(input)?;
 
     // make cube from map
-    let cube = Cube::new("src/year2022/day22/map.txt", 50);
+    let cube = Cube::new(map_input, 50)?;
 
     let mut p = CubePosition {
         face_index: 0,
         i: 0,
         j: 0,
-        dir: 0,
+        dir: Direction::East,
     };
 
     for instruction in instructions {
         match instruction {
             Instruction::Rotate(r) => {
-                p.dir = (p.dir + r).rem_euclid(4);
+                p.dir = p.dir.turn(r);
             }
             Instruction::Move(m) => {
                 for _ in 0..m {
@@ -304,13 +330,33 @@ pub fn solve2() -> i32 {
     }
 
     let f = &cube.faces[p.face_index];
-    1000 * (p.i + f.offset_i + 1) + 4 * (p.j + f.offset_j + 1) + p.dir
+    Ok(1000 * (p.i + f.offset_i + 1) + 4 * (p.j + f.offset_j + 1) + facing(p.dir))
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 22).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 22).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 22);
+
+    #[test]
+    fn test_short_rows() {
+        // rows shorter than the widest row are padded
+        assert_eq!(part1("  ..\n.#\n\n3").unwrap(), 1000 + 4 * 4);
+        assert_eq!(part1("  ..\n.#\n\nR1").unwrap(), 1000 + 4 * 3 + 1);
+        // the map does not match the cube layout of the puzzle
+        assert!(matches!(part2("  ..\n.#\n\n3"), Err(Error::Parse { .. })));
+        assert!(matches!(part2("\n\n3"), Err(Error::Parse { .. })));
+    }
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
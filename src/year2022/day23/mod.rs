@@ -1,53 +1,31 @@
 use std::collections::HashSet;
 
 use crate::utils;
-
-static DIRECTIONS: [Vec2; 4] = [
-    Vec2 { x: 0, y: 1 },  // North
-    Vec2 { x: 0, y: -1 }, // South
-    Vec2 { x: -1, y: 0 }, // West
-    Vec2 { x: 1, y: 0 },  // East
+use crate::utils::geometry::Point2;
+use crate::{Error, Result};
+
+static DIRECTIONS: [Point2; 4] = [
+    Point2::new(0, 1),  // North
+    Point2::new(0, -1), // South
+    Point2::new(-1, 0), // West
+    Point2::new(1, 0),  // East
 ];
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Vec2 {
-    x: i32,
-    y: i32,
-}
-
-impl Vec2 {
-    fn new(x: i32, y: i32) -> Vec2 {
-        Vec2 { x, y }
-    }
-
-    fn add(&self, other: &Vec2) -> Vec2 {
-        Vec2::new(self.x + other.x, self.y + other.y)
-    }
-
-    fn orth(&self) -> Vec2 {
-        Vec2::new(-self.y, self.x)
-    }
-
-    fn neg(&self) -> Vec2 {
-        Vec2::new(-self.x, -self.y)
-    }
-}
-
-fn get_proposition(config: &HashSet<Vec2>, p: &Vec2, dir: usize) -> Option<Vec2> {
+fn get_proposition(config: &HashSet<Point2>, p: &Point2, dir: usize) -> Option<Point2> {
     let mut neighbors = [false; 4];
     let mut has_neighbors = false;
     for i in 0..4 {
         let direction = DIRECTIONS[(i + dir) % 4];
-        let target = p.add(&direction);
+        let target = *p + direction;
         if config.contains(&target) {
             neighbors[i] = true;
             has_neighbors = true;
         }
-        if config.contains(&target.add(&direction.orth())) {
+        if config.contains(&(target + direction.rotate_left())) {
             neighbors[i] = true;
             has_neighbors = true;
         }
-        if config.contains(&target.add(&direction.orth().neg())) {
+        if config.contains(&(target + direction.rotate_right())) {
             neighbors[i] = true;
             has_neighbors = true;
         }
@@ -56,7 +34,7 @@ fn get_proposition(config: &HashSet<Vec2>, p: &Vec2, dir: usize) -> Option<Vec2>
     if has_neighbors {
         for i in 0..4 {
             if !neighbors[i] {
-                return Some(p.add(&DIRECTIONS[(i + dir) % 4]));
+                return Some(*p + DIRECTIONS[(i + dir) % 4]);
             }
         }
     }
@@ -64,7 +42,7 @@ fn get_proposition(config: &HashSet<Vec2>, p: &Vec2, dir: usize) -> Option<Vec2>
     None
 }
 
-fn next(config: &HashSet<Vec2>, dir: usize) -> (HashSet<Vec2>, bool) {
+fn next(config: &HashSet<Point2>, dir: usize) -> (HashSet<Point2>, bool) {
     let mut new_config = HashSet::new();
     let mut did_move = false;
     for p in config {
@@ -72,7 +50,7 @@ fn next(config: &HashSet<Vec2>, dir: usize) -> (HashSet<Vec2>, bool) {
             Some(prop) => {
                 let mut can_move = true;
                 for neighbor_direction in &DIRECTIONS {
-                    let neighbor = prop.add(neighbor_direction);
+                    let neighbor = prop + *neighbor_direction;
                     if neighbor != *p
                         && config.contains(&neighbor)
                         && get_proposition(config, &neighbor, dir) == Some(prop)
@@ -96,17 +74,28 @@ fn next(config: &HashSet<Vec2>, dir: usize) -> (HashSet<Vec2>, bool) {
     (new_config, did_move)
 }
 
-pub fn solve1() -> i32 {
+/// Positions of the elves (the y axis points north)
+fn parse_elves(input: &str) -> Result<HashSet<Point2>> {
     let mut config = HashSet::new();
-
-    let input = utils::read_input("src/year2022/day23/input.txt").unwrap();
     for (y, line) in input.lines().rev().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                config.insert(Vec2::new(x as i32, y as i32));
+        for (x, (k, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => {
+                    config.insert(Point2::new(x as i32, y as i32));
+                }
+                '.' => {}
+                _ => return Err(Error::parse_in(input, &line[k..], "expected '#' or '.'")),
             }
         }
     }
+    Ok(config)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let mut config = parse_elves(input)?;
+    if config.is_empty() {
+        return Err(Error::no_solution("there are no elves"));
+    }
 
     let mut dir = 0;
 
@@ -120,26 +109,17 @@ pub fn solve1() -> i32 {
     let mut min_y = i32::MAX;
     let mut max_y = i32::MIN;
     for p in &config {
-        min_x = min_x.min(p.x);
-        max_x = max_x.max(p.x);
-        min_y = min_y.min(p.y);
-        max_y = max_y.max(p.y);
+        min_x = min_x.min(p.x());
+        max_x = max_x.max(p.x());
+        min_y = min_y.min(p.y());
+        max_y = max_y.max(p.y());
     }
 
-    (max_x - min_x + 1) * (max_y - min_y + 1) - config.len() as i32
+    Ok((max_x - min_x + 1) * (max_y - min_y + 1) - config.len() as i32)
 }
 
-pub fn solve2() -> i32 {
-    let mut config = HashSet::new();
-
-    let input = utils::read_input("src/year2022/day23/input.txt").unwrap();
-    for (y, line) in input.lines().rev().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                config.insert(Vec2::new(x as i32, y as i32));
-            }
-        }
-    }
+pub fn part2(input: &str) -> Result<i32> {
+    let mut config = parse_elves(input)?;
 
     let mut dir = 0;
     let mut counter = 1;
@@ -152,13 +132,23 @@ pub fn solve2() -> i32 {
         dir = (dir + 1) % 4;
         counter += 1;
     }
-    counter
+    Ok(counter)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 23).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 23).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 23);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -167,7 +157,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (9s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (8s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,12 +1,13 @@
-use std::collections::HashSet;
-
-use crate::utils;
+use crate::utils::{self, graph, number};
+use crate::{Error, Result};
 
 static DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
 
 struct Board {
     width: i32,
     height: i32,
+    /// Number of minutes after which all blizzards are back to their initial positions
+    period: i32,
     left_moving_grid: Vec<Vec<bool>>,
     right_moving_grid: Vec<Vec<bool>>,
     up_moving_grid: Vec<Vec<bool>>,
@@ -14,35 +15,44 @@ struct Board {
 }
 
 impl Board {
-    fn new(filename: &str) -> Self {
-        let input = utils::read_input(filename).unwrap();
-        let height = input.lines().count() - 2;
-        let width = input.lines().next().unwrap().chars().count() - 2;
+    fn new(input: &str) -> Result<Self> {
+        let nb_lines = input.lines().count();
+        let nb_columns = input.lines().next().map_or(0, |line| line.chars().count());
+        if nb_lines < 3 || nb_columns < 3 {
+            return Err(Error::parse_at(input, 0, "expected a valley surrounded by walls"));
+        }
+        let height = nb_lines - 2;
+        let width = nb_columns - 2;
         let mut left_moving_grid = vec![vec![false; width]; height];
         let mut right_moving_grid = vec![vec![false; width]; height];
         let mut up_moving_grid = vec![vec![false; width]; height];
         let mut down_moving_grid = vec![vec![false; width]; height];
 
         for (i, line) in input.lines().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                match c {
-                    '<' => left_moving_grid[i - 1][j - 1] = true,
-                    '>' => right_moving_grid[i - 1][j - 1] = true,
-                    '^' => up_moving_grid[i - 1][j - 1] = true,
-                    'v' => down_moving_grid[i - 1][j - 1] = true,
-                    _ => (),
+            for (j, (k, c)) in line.char_indices().enumerate() {
+                let grid = match c {
+                    '<' => &mut left_moving_grid,
+                    '>' => &mut right_moving_grid,
+                    '^' => &mut up_moving_grid,
+                    'v' => &mut down_moving_grid,
+                    _ => continue,
+                };
+                if i == 0 || i > height || j == 0 || j > width {
+                    return Err(Error::parse_in(input, &line[k..], "blizzard outside of the valley"));
                 }
+                grid[i - 1][j - 1] = true;
             }
         }
 
-        Self {
+        Ok(Self {
             width: width as i32,
             height: height as i32,
+            period: number::lcm(width as i32, height as i32),
             left_moving_grid,
             right_moving_grid,
             up_moving_grid,
             down_moving_grid,
-        }
+        })
     }
 
     fn is_safe(&self, i: i32, j: i32, time: i32) -> bool {
@@ -66,73 +76,76 @@ impl Board {
         true
     }
 
-    fn get_next_positions(&self, positions: HashSet<(i32, i32)>, time: i32) -> HashSet<(i32, i32)> {
-        let mut result = HashSet::new();
-        for (i, j) in positions {
-            if self.is_safe(i, j, time) {
-                result.insert((i, j));
-            }
-            for (di, dj) in &DIRECTIONS {
-                if self.is_safe(i + di, j + dj, time) {
-                    result.insert((i + di, j + dj));
-                }
+    /// Positions that can be reached at time `time + 1` from a position at time `time` (times are
+    /// taken modulo the period of the blizzards, so that there are finitely many states)
+    fn next_states(&self, (i, j, time): (i32, i32, i32)) -> Vec<(i32, i32, i32)> {
+        let mut result = Vec::new();
+        let next_time = (time + 1) % self.period;
+        if self.is_safe(i, j, next_time) {
+            result.push((i, j, next_time));
+        }
+        for (di, dj) in &DIRECTIONS {
+            if self.is_safe(i + di, j + dj, next_time) {
+                result.push((i + di, j + dj, next_time));
             }
         }
         result
     }
-}
 
-pub fn solve1() -> i32 {
-    let board = Board::new("src/year2022/day24/input.txt");
-    let mut positions = HashSet::new();
-    let mut time = 0;
-    positions.insert((-1, 0)); // start position
-
-    let exit_position = (board.height as i32, board.width as i32 - 1);
-    while !positions.contains(&exit_position) {
-        time += 1;
-        positions = board.get_next_positions(positions, time);
+    /// Earliest time at which `target` can be reached when leaving `start` at time `time`
+    fn crossing_time(&self, start: (i32, i32), target: (i32, i32), time: i32) -> Result<i32> {
+        let (_, duration) = graph::bfs_to(
+            [(start.0, start.1, time % self.period)],
+            |&state| self.next_states(state),
+            |&(i, j, _)| (i, j) == target,
+        )
+        .ok_or_else(|| Error::no_solution("the target cannot be reached"))?;
+        Ok(time + duration as i32)
     }
+}
 
-    time
+pub fn part1(input: &str) -> Result<i32> {
+    let board = Board::new(input)?;
+    let start_position = (-1, 0);
+    let exit_position = (board.height, board.width - 1);
+    board.crossing_time(start_position, exit_position, 0)
 }
 
-pub fn solve2() -> i32 {
-    let board = Board::new("src/year2022/day24/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let board = Board::new(input)?;
     let start_position = (-1, 0);
-    let exit_position = (board.height as i32, board.width as i32 - 1);
-    let mut time = 0;
-    
-    let mut positions = HashSet::new();
-    positions.insert(start_position); // start position
-    while !positions.contains(&exit_position) {
-        time += 1;
-        positions = board.get_next_positions(positions, time);
-    }
-    
-    positions = HashSet::new();
-    positions.insert(exit_position);
-    while !positions.contains(&start_position) {
-        time += 1;
-        positions = board.get_next_positions(positions, time);
-    }
-    
-    positions = HashSet::new();
-    positions.insert(start_position);
-    while !positions.contains(&exit_position) {
-        time += 1;
-        positions = board.get_next_positions(positions, time);
-    }
-    println!("Time to reach exit: {}", time);
+    let exit_position = (board.height, board.width - 1);
+
+    let mut time = board.crossing_time(start_position, exit_position, 0)?;
+    time = board.crossing_time(exit_position, start_position, time)?;
+    time = board.crossing_time(start_position, exit_position, time)?;
+
+    Ok(time)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2022, 24).unwrap()).unwrap()
+}
 
-    time
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 24).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 24);
+
+    #[test]
+    fn test_example_part2() {
+        // going back to the start for the snacks and crossing again
+        let example = utils::read_example(2022, 24, 1).unwrap();
+        assert_eq!(part2(&example).unwrap(), 54);
+    }
+
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1s)")]
     fn test_solve1() {
         let solution = solve1();
         println!("Part One: {}", solution);
@@ -140,9 +153,10 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (2s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
-        // assert_eq!(solution, 0);
+        assert_eq!(solution, 853);
     }
 }
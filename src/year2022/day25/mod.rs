@@ -1,4 +1,5 @@
 use crate::utils;
+use crate::{Error, Result};
 
 fn snafu_to_int(snafu: &str) -> i64 {
     let mut total = 0;
@@ -6,10 +7,11 @@ fn snafu_to_int(snafu: &str) -> i64 {
         match c {
             '2' => total = 5 * total + 2,
             '1' => total = 5 * total + 1,
-            '0' => total = 5 * total,
+            '0' => total *= 5,
             '-' => total = 5 * total - 1,
             '=' => total = 5 * total - 2,
-            _ => panic!("Invalid character in snafu: {}", c),
+            // digits are checked when reading the input
+            _ => unreachable!("invalid character in snafu: {}", c),
         }
     }
     total
@@ -39,31 +41,42 @@ fn int_to_snafu(n: i64) -> String {
                 result.insert(0, '-');
                 n += 1;
             }
-            _ => panic!("Invalid integer: {}", n),
+            _ => unreachable!("remainder of a division by 5"),
         }
         n /= 5;
     }
     result
 }
 
-pub fn solve1() -> String {
-    let input = utils::read_input("src/year2022/day25/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<String> {
     let mut total = 0;
     for line in input.lines() {
+        if let Some(k) = line.find(|c| !"210-=".contains(c)) {
+            return Err(Error::parse_in(input, &line[k..], "expected a SNAFU digit"));
+        }
         total += snafu_to_int(line);
     }
-    int_to_snafu(total)
+    Ok(int_to_snafu(total))
 }
 
-pub fn solve2() -> i32 {
-    let _input = utils::read_input("src/year2022/day25/input.txt").unwrap();
+pub fn part2(_input: &str) -> i32 {
     0
 }
 
+pub fn solve1() -> String {
+    part1(&utils::read_day_input(2022, 25).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2022, 25).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2022, 25);
+
     #[test]
     fn test_snafu_to_int() {
         assert_eq!(1, snafu_to_int("1"));
@@ -1,3 +1,5 @@
+use crate::solution::Registry;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -23,4 +25,40 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
-// pub mod dayXX;
\ No newline at end of file
+// pub mod dayXX;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2022, 1, day01::part1, day01::part2);
+    registry.add(2022, 2, day02::part1, day02::part2);
+    registry.add(2022, 3, day03::part1, day03::part2);
+    registry.add(2022, 4, day04::part1, day04::part2);
+    registry.add(2022, 5, day05::part1, day05::part2);
+    registry.add(2022, 6, day06::part1, day06::part2);
+    registry.add(2022, 7, day07::part1, day07::part2);
+    registry.add(2022, 8, day08::part1, day08::part2);
+    registry.add(2022, 9, day09::part1, day09::part2);
+    registry.add(2022, 10, day10::part1, day10::part2);
+    registry.add(2022, 11, day11::part1, day11::part2);
+    registry.add(2022, 12, day12::part1, day12::part2);
+    registry.add(2022, 13, day13::part1, day13::part2);
+    registry.add(2022, 14, day14::part1, day14::part2);
+    registry.add(2022, 15, day15::part1, day15::part2);
+    registry.add(2022, 16, day16::part1, day16::part2);
+    registry.mark_slow(2022, 16, 2);
+    registry.add(2022, 17, day17::part1, day17::part2);
+    registry.add(2022, 18, day18::part1, day18::part2);
+    registry.add(2022, 19, day19::part1, day19::part2);
+    registry.mark_slow(2022, 19, 1);
+    registry.mark_slow(2022, 19, 2);
+    registry.add(2022, 20, day20::part1, day20::part2);
+    registry.mark_slow(2022, 20, 2);
+    registry.add(2022, 21, day21::part1, day21::part2);
+    registry.add(2022, 22, day22::part1, day22::part2);
+    registry.add(2022, 23, day23::part1, day23::part2);
+    registry.mark_slow(2022, 23, 2);
+    registry.add(2022, 24, day24::part1, day24::part2);
+    registry.mark_slow(2022, 24, 1);
+    registry.mark_slow(2022, 24, 2);
+    registry.add_part(2022, 25, 1, day25::part1);
+    registry.add_stub(2022, 25, 2, day25::part2);
+}
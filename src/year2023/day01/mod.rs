@@ -1,31 +1,27 @@
 use std::collections::HashMap;
 
 use crate::utils;
+use crate::Result;
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2023/day01/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
     let mut total = 0;
     for line in input.lines() {
-        let mut c1: Option<char> = None;
-        let mut c2: Option<char> = None;
-        for c in line.chars() {
-            if c.is_numeric() {
-                if c1.is_none() {
-                    c1 = Some(c);
-                }
-                c2 = Some(c);
+        let mut c1: Option<i32> = None;
+        let mut c2: Option<i32> = None;
+        for d in line.chars().filter_map(|c| c.to_digit(10)) {
+            if c1.is_none() {
+                c1 = Some(d as i32);
             }
+            c2 = Some(d as i32);
         }
         if let (Some(c1), Some(c2)) = (c1, c2) {
-            let s = c1.to_string() + &c2.to_string();
-            total += s.parse::<i32>().unwrap();
+            total += 10 * c1 + c2;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2023/day01/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut total = 0;
 
     let digit_names = [
@@ -40,7 +36,7 @@ pub fn solve2() -> i32 {
     for line in input.lines() {
         let mut c1: Option<i32> = None;
         let mut c2: Option<i32> = None;
-        for i in 0..line.len() {
+        for (i, _) in line.char_indices() {
             for key in digits.keys() {
                 if line[i..].starts_with(key) {
                     if c1.is_none() {
@@ -55,7 +51,15 @@ pub fn solve2() -> i32 {
             total += 10 * c1 + c2;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 1).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2023, 1).unwrap()).unwrap()
 }
 
 #[cfg(test)]
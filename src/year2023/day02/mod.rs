@@ -7,7 +7,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
 #[derive(Debug)]
 enum Cubes {
@@ -18,7 +19,7 @@ enum Cubes {
 
 fn parse_cubes(input: &str) -> IResult<&str, Cubes> {
     alt((
-        map(terminated(utils::parse_int, tag(" red")), |v| Cubes::Red(v)),
+        map(terminated(utils::parse_int, tag(" red")), Cubes::Red),
         map(terminated(utils::parse_int, tag(" green")), |v| {
             Cubes::Green(v)
         }),
@@ -40,11 +41,13 @@ fn parse_line(input: &str) -> IResult<&str, (i32, Vec<Vec<Cubes>>)> {
     )(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2023/day02/input.txt").unwrap();
+fn parse_input(input: &str) -> Result<Vec<(i32, Vec<Vec<Cubes>>)>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
     let mut total = 0;
-    for line in input.lines() {
-        let (_, (game_id, draws)) = parse_line(line).unwrap();
+    for (game_id, draws) in parse_input(input)? {
         let mut is_valid = true;
         for draw in draws {
             for cubes in draw {
@@ -59,14 +62,12 @@ pub fn solve1() -> i32 {
             total += game_id;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2023/day02/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
     let mut total = 0;
-    for line in input.lines() {
-        let (_, (_, draws)) = parse_line(line).unwrap();
+    for (_, draws) in parse_input(input)? {
         let mut nb_red = 0;
         let mut nb_green = 0;
         let mut nb_blue = 0;
@@ -81,7 +82,15 @@ pub fn solve2() -> i32 {
         }
         total += nb_red * nb_green * nb_blue;
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 2).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2023, 2).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::utils;
+use crate::{Error, Result};
 
 #[derive(Debug)]
 struct Number {
@@ -17,9 +18,11 @@ impl Number {
         }
     }
 
-    fn add_digit(&mut self, digit: i32) {
-        self.value = self.value * 10 + digit;
+    /// Appends a digit to the number (returns `None` if the value overflows)
+    fn add_digit(&mut self, digit: i32) -> Option<()> {
+        self.value = self.value.checked_mul(10)?.checked_add(digit)?;
         self.length += 1;
+        Some(())
     }
 
     fn is_adjacent_to(&self, row: i32, column: i32) -> bool {
@@ -37,45 +40,33 @@ struct Schematic {
     numbers: Vec<Number>,
 }
 impl Schematic {
-    fn from_file(filename: &str) -> Schematic {
-        let input = utils::read_input(filename).unwrap();
-        // make the grid
+    fn from_file(input: &str) -> Result<Schematic> {
         let mut grid: Vec<Vec<char>> = Vec::new();
-        for line in input.lines() {
-            let mut row: Vec<char> = Vec::new();
-            for c in line.chars() {
-                row.push(c);
-            }
-            grid.push(row);
-        }
-
-        // find the numbers
         let mut numbers = Vec::new();
-        let mut current_number = None;
-        for i in 0..grid.len() {
-            for j in 0..grid[i].len() {
-                if grid[i][j].is_digit(10) {
-                    let d = grid[i][j].to_digit(10).unwrap() as i32;
-                    if current_number.is_none() {
-                        current_number = Some(Number::new(d, i, j, 1));
+        for (i, line) in input.lines().enumerate() {
+            grid.push(line.chars().collect());
+
+            // find the numbers of the row
+            let mut current_number: Option<Number> = None;
+            for (j, (k, c)) in line.char_indices().enumerate() {
+                if let Some(d) = c.to_digit(10) {
+                    if let Some(number) = current_number.as_mut() {
+                        number
+                            .add_digit(d as i32)
+                            .ok_or_else(|| Error::parse_in(input, &line[k..], "number too large"))?;
                     } else {
-                        current_number.as_mut().unwrap().add_digit(d);
-                    }
-                } else {
-                    if current_number.is_some() {
-                        numbers.push(current_number.unwrap());
-                        current_number = None;
+                        current_number = Some(Number::new(d as i32, i, j, 1));
                     }
+                } else if let Some(number) = current_number.take() {
+                    numbers.push(number);
                 }
             }
-            if current_number.is_some() {
-                numbers.push(current_number.unwrap());
-                current_number = None;
+            if let Some(number) = current_number {
+                numbers.push(number);
             }
         }
 
-        // return object
-        Schematic { grid, numbers }
+        Ok(Schematic { grid, numbers })
     }
 
     fn get_symbol(&self, row: i32, column: i32) -> Option<char> {
@@ -87,7 +78,7 @@ impl Schematic {
             return None;
         }
         let column = column as usize;
-        if self.grid[row][column] == '.' || self.grid[row][column].is_digit(10) {
+        if self.grid[row][column] == '.' || self.grid[row][column].is_ascii_digit() {
             return None;
         }
         Some(self.grid[row][column])
@@ -121,24 +112,24 @@ impl Schematic {
     }
 }
 
-pub fn solve1() -> i32 {
-    let schematic = Schematic::from_file("src/year2023/day03/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let schematic = Schematic::from_file(input)?;
     let mut total = 0;
     for number in &schematic.numbers {
-        if schematic.is_engine_part(&number) {
+        if schematic.is_engine_part(number) {
             total += number.value;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let schematic = Schematic::from_file("src/year2023/day03/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let schematic = Schematic::from_file(input)?;
     let mut total = 0;
 
-    for i in 0..schematic.grid.len() {
-        for j in 0..schematic.grid[0].len() {
-            if schematic.grid[i][j] == '*' {
+    for (i, row) in schematic.grid.iter().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
+            if c == '*' {
                 let mut adjacent_numbers = Vec::new();
                 for number in &schematic.numbers {
                     if number.is_adjacent_to(i as i32, j as i32) {
@@ -151,7 +142,15 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 3).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2023, 3).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -8,7 +8,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
 fn parse_line(input: &str) -> IResult<&str, (i32, Vec<i32>, Vec<i32>)> {
     tuple((
@@ -25,11 +26,10 @@ fn parse_line(input: &str) -> IResult<&str, (i32, Vec<i32>, Vec<i32>)> {
     ))(input)
 }
 
-fn make_cards_scores() -> Vec<i32> {
-    let input = utils::read_input("src/year2023/day04/input.txt").unwrap();
+fn make_cards_scores(input: &str) -> Result<Vec<i32>> {
     let mut scores = Vec::new();
     for line in input.lines() {
-        let (_, (_, winning_numbers, card_numbers)) = parse_line(line).unwrap();
+        let (_, winning_numbers, card_numbers) = parse::parse_all_in(parse_line, input, line)?;
         let winning_numbers: HashSet<i32> = winning_numbers.into_iter().collect();
         let card_numbers: HashSet<i32> = card_numbers.into_iter().collect();
 
@@ -41,22 +41,22 @@ fn make_cards_scores() -> Vec<i32> {
         }
         scores.push(nb_winning_numbers);
     }
-    scores
+    Ok(scores)
 }
 
-pub fn solve1() -> i32 {
-    let scores = make_cards_scores();
+pub fn part1(input: &str) -> Result<i32> {
+    let scores = make_cards_scores(input)?;
     let mut total = 0;
     for score in scores {
         if score > 0 {
             total += 1 << (score - 1);
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let scores = make_cards_scores();
+pub fn part2(input: &str) -> Result<i32> {
+    let scores = make_cards_scores(input)?;
     let mut t = vec![0; scores.len()];
     for i in (0..scores.len()).rev() {
         let mut n = 1;
@@ -65,7 +65,15 @@ pub fn solve2() -> i32 {
         }
         t[i] = n;
     }
-    t.iter().sum()
+    Ok(t.iter().sum())
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 4).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2023, 4).unwrap()).unwrap()
 }
 
 #[cfg(test)]
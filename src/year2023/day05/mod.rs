@@ -1,4 +1,6 @@
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
+use crate::utils::interval::{Interval, IntervalMap, IntervalSet};
 use nom::{
     bytes::complete::tag,
     combinator::map,
@@ -7,171 +9,78 @@ use nom::{
     IResult,
 };
 
-#[derive(Debug)]
-struct Interval {
-    start: i64,
-    end: i64,
-}
-impl Interval {
-    fn new(start: i64, end: i64) -> Self {
-        Self { start, end }
-    }
-
-    fn contains(&self, value: i64) -> bool {
-        self.start <= value && value < self.end
-    }
-}
-
-#[derive(Debug)]
-struct IntervalMap {
-    source: Interval,
-    destination: Interval,
-}
-impl IntervalMap {
-    fn new(source: Interval, destination: Interval) -> Self {
-        Self {
-            source,
-            destination,
-        }
-    }
-
-    fn convert(&self, value: i64) -> i64 {
-        self.destination.start + value - self.source.start
-    }
-
-    fn convert_interval(&self, other: &Interval) -> (Vec<Interval>, Vec<Interval>) {
-        let mut converted_intervals = Vec::new();
-        let mut remaining_intervals = Vec::new();
-
-        if other.start < self.source.start {
-            // segment of interval before source is unchanged
-            remaining_intervals.push(Interval::new(other.start, other.end.min(self.source.start)));
-        }
-        if other.end > self.source.end {
-            // segment of interval after source is unchanged
-            remaining_intervals.push(Interval::new(other.start.max(self.source.end), other.end));
-        }
-        if self.source.start < other.end && other.start < self.source.end {
-            // the overlapping interval is converted
-            converted_intervals.push(Interval::new(
-                self.convert(other.start.max(self.source.start)),
-                self.convert(other.end.min(self.source.end)),
-            ));
-        }
-
-        (converted_intervals, remaining_intervals)
-    }
-}
-
-struct Map {
-    interval_maps: Vec<IntervalMap>,
-}
-impl Map {
-    fn new(interval_maps: Vec<IntervalMap>) -> Self {
-        Self { interval_maps }
-    }
-
-    fn convert(&self, value: i64) -> i64 {
-        for interval_map in &self.interval_maps {
-            if interval_map.source.contains(value) {
-                return interval_map.convert(value);
-            }
-        }
-        value
-    }
-
-    fn convert_intervals(&self, intervals: Vec<Interval>) -> Vec<Interval> {
-        let mut converted_intervals = Vec::new();
-        let mut remaining_intervals = intervals;
-        for interval_map in &self.interval_maps {
-            let mut new_remaining_intervals = Vec::new();
-            for i in remaining_intervals {
-                let (mut converted, mut remaining) = interval_map.convert_interval(&i);
-                converted_intervals.append(&mut converted);
-                new_remaining_intervals.append(&mut remaining);
-            }
-            remaining_intervals = new_remaining_intervals;
-        }
-
-        converted_intervals.append(&mut remaining_intervals);
-        converted_intervals
-    }
-}
-
 fn parse_seeds(input: &str) -> IResult<&str, Vec<i64>> {
     preceded(tag("seeds: "), separated_list1(tag(" "), utils::parse_int))(input)
 }
 
-fn parse_map_interval(input: &str) -> IResult<&str, IntervalMap> {
+/// Reads a line of a map, as a source interval and the offset of its values
+fn parse_map_interval(input: &str) -> IResult<&str, (Interval<i64>, i64)> {
     map(
         tuple((
             utils::parse_int,
             preceded(tag(" "), utils::parse_int),
-            preceded(tag(" "), utils::parse_int::<i64>),
+            preceded(tag(" "), utils::parse_int),
         )),
-        |(dest, source, len)| {
-            IntervalMap::new(
-                Interval::new(source, source + len),
-                Interval::new(dest, dest + len),
-            )
-        },
+        |(dest, source, len): (i64, i64, i64)| (Interval::new(source, source + len), dest - source),
     )(input)
 }
 
-fn make_seeds() -> Vec<i64> {
-    let input = utils::read_input("src/year2023/day05/seeds.txt").unwrap();
-    let (_, seeds) = parse_seeds(&input).unwrap();
-    seeds
-}
-
-fn make_maps() -> Vec<Map> {
-    let input = utils::read_input("src/year2023/day05/maps.txt").unwrap();
+/// Reads the maps from `part` (the section of `input` after the seeds)
+fn make_maps(input: &str, part: &str) -> Result<Vec<IntervalMap<i64>>> {
     let mut maps = Vec::new();
     let mut current_intervals = Vec::new();
-    for line in input.lines() {
+    for line in part.lines() {
         if line.is_empty() {
-            maps.push(Map::new(current_intervals));
+            maps.push(current_intervals.into_iter().collect());
             current_intervals = Vec::new();
             continue;
         }
-        if let Ok((_, interval)) = parse_map_interval(&line) {
-            current_intervals.push(interval);
+        if line.ends_with(" map:") {
+            // header of the map (maps are given in order)
+            continue;
         }
+        current_intervals.push(parse::parse_all_in(parse_map_interval, input, line)?);
     }
-    maps.push(Map::new(current_intervals));
-    maps
+    maps.push(current_intervals.into_iter().collect());
+    Ok(maps)
 }
 
-pub fn solve1() -> i64 {
-    let seeds = make_seeds();
-    let maps = make_maps();
-    let mut positions = Vec::new();
+/// Splits the almanac into the seeds and the conversion maps
+fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<IntervalMap<i64>>)> {
+    let (seeds, maps) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the seeds"))?;
+    Ok((parse::parse_all_in(parse_seeds, input, seeds)?, make_maps(input, maps)?))
+}
 
-    for seed in seeds {
-        let mut x = seed;
-        for map in &maps {
-            x = map.convert(x);
-        }
-        positions.push(x);
-    }
-    positions.sort();
-    positions[0]
+pub fn part1(input: &str) -> Result<i64> {
+    let (seeds, maps) = parse_input(input)?;
+    // single map from seeds to locations
+    let almanac = maps.iter().fold(IntervalMap::new(), |almanac, map| almanac.then(map));
+    seeds.into_iter().map(|seed| almanac.apply(seed)).min().ok_or_else(|| Error::no_solution("no seeds"))
 }
 
-pub fn solve2() -> i64 {
-    let seeds = make_seeds();
-    let mut intervals = Vec::new();
-    for i in 0..(seeds.len() / 2) {
-        intervals.push(Interval::new(seeds[2 * i], seeds[2 * i] + seeds[2 * i + 1]));
+pub fn part2(input: &str) -> Result<i64> {
+    let (seeds, maps) = parse_input(input)?;
+    // seeds are given as pairs of a start and a length
+    let pairs = seeds.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::parse_at(input, 0, "expected an even number of seed values"));
     }
+    let mut values: IntervalSet<i64> = pairs.map(|pair| Interval::new(pair[0], pair[0] + pair[1])).collect();
 
-    let maps = make_maps();
-    for map in maps {
-        intervals = map.convert_intervals(intervals);
+    for map in &maps {
+        values = map.image(&values);
     }
+    values.min().ok_or_else(|| Error::no_solution("no seeds"))
+}
 
-    intervals.sort_by_key(|i| i.start);
-    intervals[0].start
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2023, 5).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 5).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -2,33 +2,25 @@ use nom::{
     bytes::complete::tag,
     character::complete::space1,
     multi::separated_list1,
-    sequence::{pair, preceded},
+    sequence::{pair, preceded, separated_pair},
 };
 
 use crate::utils;
+use crate::{Error, Result};
 
-fn parse_input() -> Vec<(i32, i32)> {
-    let input = utils::read_input("src/year2023/day06/input.txt").unwrap();
-    let mut lines = input.lines();
-
-    // get times from first line
-    let line = lines.next().unwrap();
-    let (_, times) = preceded(
-        pair(tag("Time:"), space1),
-        separated_list1(space1, utils::parse_int),
-    )(line)
-    .unwrap();
-
-    // get distances from second line
-    let line = lines.next().unwrap();
-    let (_, distances) = preceded(
-        pair(tag("Distance:"), space1),
-        separated_list1(space1, utils::parse_int),
-    )(line)
-    .unwrap();
+fn parse_input(input: &str) -> Result<Vec<(u32, u32)>> {
+    // times on the first line and distances on the second line
+    let (times, distances) = utils::parse_all(
+        separated_pair(
+            preceded(pair(tag("Time:"), space1), separated_list1(space1, utils::parse_unsigned_int)),
+            tag("\n"),
+            preceded(pair(tag("Distance:"), space1), separated_list1(space1, utils::parse_unsigned_int)),
+        ),
+        input,
+    )?;
 
     // zip times and distances into a vector of pairs
-    times.into_iter().zip(distances.into_iter()).collect()
+    Ok(times.into_iter().zip(distances).collect())
 }
 
 fn get_nb_solutions(t: f64, d: f64) -> i64 {
@@ -50,18 +42,18 @@ fn get_nb_solutions(t: f64, d: f64) -> i64 {
     y2 - y1 + 1
 }
 
-pub fn solve1() -> i64 {
-    let stats = parse_input();
+pub fn part1(input: &str) -> Result<i64> {
+    let stats = parse_input(input)?;
     let mut total = 1;
 
     for (t, d) in stats {
         total *= get_nb_solutions(t as f64, d as f64);
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let stats = parse_input();
+pub fn part2(input: &str) -> Result<i64> {
+    let stats = parse_input(input)?;
     // concatenate times and distances
     let mut time_str = String::new();
     let mut distance_str = String::new();
@@ -70,7 +62,17 @@ pub fn solve2() -> i64 {
         distance_str.push_str(&d.to_string());
     }
 
-    get_nb_solutions(time_str.parse().unwrap(), distance_str.parse().unwrap())
+    let time = time_str.parse().map_err(|_| Error::parse_at(input, 0, "invalid concatenated time"))?;
+    let distance = distance_str.parse().map_err(|_| Error::parse_at(input, 0, "invalid concatenated distance"))?;
+    Ok(get_nb_solutions(time, distance))
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2023, 6).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 6).unwrap()).unwrap()
 }
 
 #[cfg(test)]
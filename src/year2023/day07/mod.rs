@@ -2,13 +2,15 @@ use std::collections::HashMap;
 
 use nom::{
     bytes::complete::{is_a, tag},
+    combinator::verify,
     sequence::separated_pair,
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::Result;
 
-#[derive(PartialEq, PartialOrd)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
     HighCard,
     OnePair,
@@ -21,7 +23,8 @@ enum HandType {
 
 fn parse_line(input: &str) -> IResult<&str, (&str, i32)> {
     let labels = "23456789TJQKA";
-    separated_pair(is_a(labels), tag(" "), utils::parse_int)(input)
+    // a hand has exactly 5 cards
+    separated_pair(verify(is_a(labels), |cards: &str| cards.len() == 5), tag(" "), utils::parse_int)(input)
 }
 
 fn get_hand_type(cards: &str, jokers: bool) -> HandType {
@@ -63,7 +66,8 @@ fn get_hand_type(cards: &str, jokers: bool) -> HandType {
     }
 }
 
-fn get_card_values(cards: &str, jokers: bool) -> Vec<i32> {
+/// Values of the cards (`None` for an invalid label, which the parser does not accept)
+fn get_card_values(cards: &str, jokers: bool) -> Vec<Option<i32>> {
     let labels: Vec<char> = if jokers {
         "J23456789TQKA".chars().collect()
     } else {
@@ -74,44 +78,46 @@ fn get_card_values(cards: &str, jokers: bool) -> Vec<i32> {
         values.insert(c, i as i32);
     }
     // associate a value to each card
-    cards.chars().map(|c| *values.get(&c).unwrap()).collect()
+    cards.chars().map(|c| values.get(&c).copied()).collect()
 }
 
-fn compare_hands(hand1: &str, hand2: &str, jokers: bool) -> Option<std::cmp::Ordering> {
+fn compare_hands(hand1: &str, hand2: &str, jokers: bool) -> std::cmp::Ordering {
     let type1 = get_hand_type(hand1, jokers);
     let type2 = get_hand_type(hand2, jokers);
     if type1 != type2 {
-        return type1.partial_cmp(&type2);
+        return type1.cmp(&type2);
     }
 
     let values1 = get_card_values(hand1, jokers);
     let values2 = get_card_values(hand2, jokers);
-    values1.partial_cmp(&values2)
+    values1.cmp(&values2)
 }
 
-fn get_winnings(jokers: bool) -> i32 {
-    let input = utils::read_input("src/year2023/day07/input.txt").unwrap();
-
-    let mut hands = Vec::new();
-    for line in input.lines() {
-        let (_, (hand, bid)) = parse_line(line).unwrap();
-        hands.push((hand, bid));
-    }
-    hands.sort_by(|a, b| compare_hands(&a.0, &b.0, jokers).unwrap());
+fn get_winnings(input: &str, jokers: bool) -> Result<i32> {
+    let mut hands = utils::parse_all(parse::lines(parse_line), input)?;
+    hands.sort_by(|a, b| compare_hands(a.0, b.0, jokers));
 
     let mut total = 0;
     for (i, (_, bid)) in hands.iter().enumerate() {
         total += bid * (i + 1) as i32;
     }
-    total
+    Ok(total)
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    get_winnings(input, false)
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    get_winnings(input, true)
 }
 
 pub fn solve1() -> i32 {
-    get_winnings(false)
+    part1(&utils::read_day_input(2023, 7).unwrap()).unwrap()
 }
 
 pub fn solve2() -> i32 {
-    get_winnings(true)
+    part2(&utils::read_day_input(2023, 7).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::iter;
 
 use nom::{
     bytes::complete::tag,
@@ -6,9 +7,13 @@ use nom::{
     sequence::{delimited, separated_pair},
     IResult,
 };
-use num::Integer;
 
 use crate::utils;
+use crate::utils::{cycle, number, parse};
+use crate::{Error, Result};
+
+/// Left and right nodes reached from each node
+type Network = HashMap<String, Vec<String>>;
 
 fn parse_line(input: &str) -> IResult<&str, (&str, (&str, &str))> {
     separated_pair(
@@ -22,81 +27,164 @@ fn parse_line(input: &str) -> IResult<&str, (&str, (&str, &str))> {
     )(input)
 }
 
-fn parse_input() -> (Vec<usize>, HashMap<String, Vec<String>>) {
-    let input = utils::read_input("src/year2023/day08/input.txt").unwrap();
-    let mut lines = input.lines();
-    let directions = lines
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| if c == 'L' { 0 } else { 1 })
-        .collect::<Vec<usize>>();
-    let _ = lines.next();
+fn parse_input(input: &str) -> Result<(Vec<usize>, Network)> {
+    let (directions, nodes) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the directions"))?;
+    let directions = directions
+        .char_indices()
+        .map(|(k, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(Error::parse_in(input, &directions[k..], "expected L or R")),
+        })
+        .collect::<Result<Vec<usize>>>()?;
+    if directions.is_empty() {
+        return Err(Error::parse_at(input, 0, "expected L or R"));
+    }
+    let lines = parse::parse_all_in(parse::lines(parse_line), input, nodes)?;
     let mut map = HashMap::new();
-    for line in lines {
-        let (_, (node, (left, right))) = parse_line(line).unwrap();
+    for &(node, (left, right)) in &lines {
         map.insert(node.to_string(), vec![left.to_string(), right.to_string()]);
     }
-    (directions, map)
+    for (_, (left, right)) in lines {
+        for next in [left, right] {
+            if !map.contains_key(next) {
+                return Err(Error::parse_in(input, next, format!("unknown node {}", next)));
+            }
+        }
+    }
+    Ok((directions, map))
 }
 
 fn next_node<'a>(
-    map: &'a HashMap<String, Vec<String>>,
+    map: &'a Network,
     node: &str,
-    directions: &Vec<usize>,
+    directions: &[usize],
     dir_index: usize,
-) -> &'a str {
+) -> Result<&'a str> {
     let dir = directions[dir_index % directions.len()];
-    &map.get(node).unwrap()[dir]
+    map.get(node)
+        .map(|next| next[dir].as_str())
+        .ok_or_else(|| Error::no_solution(format!("node {} is not in the network", node)))
 }
 
-pub fn solve1() -> usize {
-    let (directions, map) = parse_input();
+pub fn part1(input: &str) -> Result<usize> {
+    let (directions, map) = parse_input(input)?;
     let mut counter = 0;
     let mut node = "AAA";
 
     while node != "ZZZ" {
-        node = next_node(&map, &node, &directions, counter);
+        if counter > map.len() * directions.len() {
+            // some (node, direction index) pair was visited twice
+            return Err(Error::no_solution("ZZZ is never reached"));
+        }
+        node = next_node(&map, node, &directions, counter)?;
         counter += 1;
     }
-    counter
+    Ok(counter)
 }
 
-fn find_period(
-    map: &HashMap<String, Vec<String>>,
-    start_node: &str,
-    directions: &Vec<usize>,
-) -> (usize, usize) {
-    let mut counter = 0;
-    let mut node = start_node;
+/// Steps at which a walk is on an end node
+///
+/// The walk is periodic once a node is reached twice at the same position in the directions.
+struct EndSteps {
+    /// Steps before the walk enters its cycle (each of them happens only once)
+    before_cycle: Vec<usize>,
+    /// First step of the cycle
+    cycle_start: usize,
+    /// Steps of the first cycle (they repeat every `period` steps)
+    in_cycle: Vec<usize>,
+    period: usize,
+}
+
+impl EndSteps {
+    fn is_end(&self, step: usize) -> bool {
+        if step < self.cycle_start {
+            self.before_cycle.contains(&step)
+        } else {
+            let step = self.cycle_start + (step - self.cycle_start) % self.period;
+            self.in_cycle.contains(&step)
+        }
+    }
+}
+
+fn find_end_steps(map: &Network, start_node: &str, directions: &[usize]) -> Result<EndSteps> {
     let len = directions.len();
+    // the walk stops early (and `error` is set) if it leaves the network
+    let mut error = None;
+    let mut state = Some((start_node, 0));
+    let states = iter::from_fn(|| {
+        let (node, dir_index) = state?;
+        state = match next_node(map, node, directions, dir_index) {
+            Ok(next) => Some((next, (dir_index + 1) % len)),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        };
+        Some((node, dir_index))
+    });
+    let Some((cycle, states)) = cycle::find_cycle_by_key(states, |&state| state) else {
+        return Err(error.unwrap_or_else(|| Error::no_solution("the walk ended unexpectedly")));
+    };
+    // the last state is the first repetition
+    let end_steps = (0..states.len() - 1).filter(|&k| states[k].0.ends_with('Z'));
+    let (before_cycle, in_cycle) = end_steps.partition(|&k| k < cycle.start);
+    Ok(EndSteps { before_cycle, cycle_start: cycle.start, in_cycle, period: cycle.length })
+}
 
-    while !node.ends_with("Z") {
-        node = next_node(map, node, &directions, counter);
-        counter += 1;
+pub fn part2(input: &str) -> Result<usize> {
+    let (directions, map) = parse_input(input)?;
+    let walks: Vec<EndSteps> = map
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| find_end_steps(&map, node, &directions))
+        .collect::<Result<_>>()?;
+    if walks.is_empty() {
+        return Err(Error::no_solution("there is no start node"));
     }
 
-    let n = counter;
-    node = next_node(map, node, &directions, counter);
-    counter += 1;
-    while !(node.ends_with("Z") && (counter - n) % len == 0) {
-        node = next_node(map, node, &directions, counter);
-        counter += 1;
+    // steps before all walks are in their cycles are checked one by one
+    let min_steps = walks.iter().map(|walk| walk.cycle_start).max().unwrap_or(0);
+    if let Some(steps) = (0..min_steps).find(|&step| walks.iter().all(|walk| walk.is_end(step))) {
+        return Ok(steps);
     }
 
-    (n, counter - n)
+    // after that, all walks are on an end node when the number of steps is congruent to one of
+    // the steps of the cycle of each walk, so each choice of these steps gives a system of
+    // congruences
+    let mut systems: Vec<Vec<(i64, i64)>> = vec![Vec::new()];
+    for walk in &walks {
+        systems = systems
+            .iter()
+            .flat_map(|system| {
+                walk.in_cycle.iter().map(move |&step| {
+                    let mut system = system.clone();
+                    system.push((step as i64, walk.period as i64));
+                    system
+                })
+            })
+            .collect();
+    }
+    systems
+        .into_iter()
+        .filter_map(number::crt)
+        .map(|(steps, period)| {
+            let (steps, period) = (steps as usize, period as usize);
+            // first solution after all walks have entered their cycles
+            steps + min_steps.saturating_sub(steps).div_ceil(period) * period
+        })
+        .min()
+        .ok_or_else(|| Error::no_solution("walks are never all on end nodes"))
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2023, 8).unwrap()).unwrap()
 }
 
 pub fn solve2() -> usize {
-    let (directions, map) = parse_input();
-    let mut m = 1;
-    for (node, _) in &map {
-        if node.ends_with("A") {
-            let (_, p) = find_period(&map, node, &directions);
-            m = m.lcm(&p);
-        }
-    }
-    m
+    part2(&utils::read_day_input(2023, 8).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,15 +1,8 @@
-use nom::{bytes::complete::tag, multi::separated_list1};
+use crate::utils::{self, parse};
+use crate::Result;
 
-use crate::utils;
-
-fn get_input_sequences() -> Vec<Vec<i64>> {
-    let input = utils::read_input("src/year2023/day09/input.txt").unwrap();
-    let mut sequences: Vec<Vec<i64>> = Vec::new();
-    for line in input.lines() {
-        let (_, values) = separated_list1(tag(" "), utils::parse_int)(line).unwrap();
-        sequences.push(values);
-    }
-    sequences
+fn get_input_sequences(input: &str) -> Result<Vec<Vec<i64>>> {
+    utils::parse_all(parse::lines(parse::space_separated_ints), input)
 }
 
 fn extrapolate(sequence: Vec<i64>) -> (i64, i64) {
@@ -24,22 +17,30 @@ fn extrapolate(sequence: Vec<i64>) -> (i64, i64) {
     (sequence[0] - d1, sequence[sequence.len() - 1] + d2)
 }
 
-pub fn solve1() -> i64 {
-    let sequences = get_input_sequences();
+pub fn part1(input: &str) -> Result<i64> {
+    let sequences = get_input_sequences(input)?;
     let mut total = 0;
     for seq in sequences {
         total += extrapolate(seq).1;
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let sequences = get_input_sequences();
+pub fn part2(input: &str) -> Result<i64> {
+    let sequences = get_input_sequences(input)?;
     let mut total = 0;
     for seq in sequences {
         total += extrapolate(seq).0;
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2023, 9).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 9).unwrap()).unwrap()
 }
 
 #[cfg(test)]
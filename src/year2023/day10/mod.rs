@@ -1,58 +1,32 @@
 use crate::utils;
-
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-struct Direction {
-    di: i32,
-    dj: i32,
-}
-impl Direction {
-    fn north() -> Self {
-        Self { di: -1, dj: 0 }
-    }
-    fn south() -> Self {
-        Self { di: 1, dj: 0 }
-    }
-    fn east() -> Self {
-        Self { di: 0, dj: 1 }
-    }
-    fn west() -> Self {
-        Self { di: 0, dj: -1 }
-    }
-    fn opposite(&self) -> Self {
-        Self {
-            di: -self.di,
-            dj: -self.dj,
-        }
-    }
+use crate::utils::direction::Direction;
+use crate::utils::polygon::Polygon;
+use crate::{Error, Result};
+
+/// Position reached by moving from (i, j) in the given direction
+fn step(i: usize, j: usize, direction: Direction) -> (usize, usize) {
+    let (di, dj) = direction.offset();
+    ((i as i64 + di) as usize, (j as i64 + dj) as usize)
 }
 
 fn get_directions(symbol: char) -> Vec<Direction> {
     match symbol {
-        '|' => vec![Direction::north(), Direction::south()],
-        '-' => vec![Direction::west(), Direction::east()],
-        'L' => vec![Direction::north(), Direction::east()],
-        'J' => vec![Direction::north(), Direction::west()],
-        '7' => vec![Direction::south(), Direction::west()],
-        'F' => vec![Direction::south(), Direction::east()],
+        '|' => vec![Direction::North, Direction::South],
+        '-' => vec![Direction::West, Direction::East],
+        'L' => vec![Direction::North, Direction::East],
+        'J' => vec![Direction::North, Direction::West],
+        '7' => vec![Direction::South, Direction::West],
+        'F' => vec![Direction::South, Direction::East],
         '.' => Vec::new(),
         'S' => Vec::new(),
-        _ => panic!("Unknown symbol: {}", symbol),
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Cell {
-    prev: Direction,
-    next: Direction,
-}
-impl Cell {
-    fn new(prev: Direction, next: Direction) -> Self {
-        Self { prev, next }
+        // symbols are checked by `make_grid`
+        _ => unreachable!("unknown symbol: {}", symbol),
     }
 }
 
 struct Grid {
-    cells: Vec<Vec<Option<Cell>>>,
+    /// Direction to the next cell of the loop, for the cells on the loop
+    cells: Vec<Vec<Option<Direction>>>,
     i0: usize,
     j0: usize,
 }
@@ -63,9 +37,7 @@ impl Grid {
         let mut j = self.j0;
         loop {
             loop_cells.push((i, j));
-            let next = self.cells[i][j].unwrap().next;
-            i = (i as i32 + next.di) as usize;
-            j = (j as i32 + next.dj) as usize;
+            (i, j) = step(i, j, self.cells[i][j].unwrap());
             if (i, j) == (self.i0, self.j0) {
                 break;
             }
@@ -74,119 +46,77 @@ impl Grid {
     }
 }
 
-fn make_grid() -> Grid {
-    let input = utils::read_input("src/year2023/day10/input.txt").unwrap();
-    let symbols_grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    let mut grid = vec![vec![None; symbols_grid[0].len()]; symbols_grid.len()];
-
-    // find starting cell
-    let (mut i0, mut j0) = (0, 0);
-    'outer: for i in 0..symbols_grid.len() {
-        for j in 0..symbols_grid[i].len() {
-            if symbols_grid[i][j] == 'S' {
-                (i0, j0) = (i, j);
-                break 'outer;
-            }
+fn make_grid(input: &str) -> Result<Grid> {
+    let mut symbols_grid = Vec::new();
+    for line in input.lines() {
+        if let Some(k) = line.find(|c| !"|-LJ7F.S".contains(c)) {
+            return Err(Error::parse_in(input, &line[k..], "unknown symbol"));
         }
+        symbols_grid.push(line.chars().collect::<Vec<char>>());
     }
+    let symbol_at = |(i, j): (usize, usize)| symbols_grid.get(i).and_then(|row| row.get(j)).copied();
+
+    // find starting cell
+    let (i0, j0) = symbols_grid
+        .iter()
+        .enumerate()
+        .find_map(|(i, row)| row.iter().position(|&symbol| symbol == 'S').map(|j| (i, j)))
+        .ok_or_else(|| Error::parse_at(input, input.len(), "missing starting cell S"))?;
+    let mut grid: Vec<Vec<Option<Direction>>> =
+        symbols_grid.iter().map(|row| vec![None; row.len()]).collect();
 
     // set directions for starting cell
     let mut starting_directions = Vec::new();
-    if get_directions(symbols_grid[i0 - 1][j0]).contains(&Direction::south()) {
-        starting_directions.push(Direction::north());
-    }
-    if get_directions(symbols_grid[i0 + 1][j0]).contains(&Direction::north()) {
-        starting_directions.push(Direction::south());
-    }
-    if get_directions(symbols_grid[i0][j0 - 1]).contains(&Direction::east()) {
-        starting_directions.push(Direction::west());
+    for direction in [Direction::North, Direction::South, Direction::West, Direction::East] {
+        let symbol = symbol_at(step(i0, j0, direction));
+        if symbol.is_some_and(|symbol| get_directions(symbol).contains(&direction.reverse())) {
+            starting_directions.push(direction);
+        }
     }
-    if get_directions(symbols_grid[i0][j0 + 1]).contains(&Direction::west()) {
-        starting_directions.push(Direction::east());
+    if starting_directions.len() != 2 {
+        return Err(Error::no_solution("the starting cell is not on a single loop"));
     }
 
-    grid[i0][j0] = Some(Cell::new(starting_directions[0], starting_directions[1]));
-    let mut direction = grid[i0][j0].unwrap().next;
-    let (mut i, mut j) = (
-        (i0 as i32 + direction.di) as usize,
-        (j0 as i32 + direction.dj) as usize,
-    );
+    grid[i0][j0] = Some(starting_directions[1]);
+    let mut direction = starting_directions[1];
+    let (mut i, mut j) = step(i0, j0, direction);
     while (i, j) != (i0, j0) {
-        let directions = get_directions(symbols_grid[i][j]);
-        let prev = direction.opposite();
-        let next = directions.iter().filter(|d| **d != prev).next().unwrap();
-        grid[i][j] = Some(Cell::new(prev, *next));
+        let directions = symbol_at((i, j)).map(get_directions).unwrap_or_default();
+        let prev = direction.reverse();
+        if !directions.contains(&prev) {
+            return Err(Error::no_solution("the loop is broken"));
+        }
+        let next = directions.iter().find(|d| **d != prev).unwrap();
+        grid[i][j] = Some(*next);
         direction = *next;
-        i = (i as i32 + direction.di) as usize;
-        j = (j as i32 + direction.dj) as usize;
+        (i, j) = step(i, j, direction);
     }
 
-    Grid {
+    Ok(Grid {
         cells: grid,
         i0,
         j0,
-    }
+    })
 }
 
-fn paint_areas(grid: &Grid) -> Vec<Vec<i32>> {
-    let mut area_map = vec![vec![0; grid.cells[0].len()]; grid.cells.len()];
-    for (i, j) in grid.get_loop_cells() {
-        area_map[i][j] = 1;
-    }
-    for (i, j) in grid.get_loop_cells() {
-        let prev = grid.cells[i][j].unwrap().prev.opposite();
-        paint_area(&mut area_map, i as i32 + prev.dj, j as i32 - prev.di, 2);
-        paint_area(&mut area_map, i as i32 - prev.dj, j as i32 + prev.di, 3);
-        let next = grid.cells[i][j].unwrap().next;
-        paint_area(&mut area_map, i as i32 + next.dj, j as i32 - next.di, 2);
-        paint_area(&mut area_map, i as i32 - next.dj, j as i32 + next.di, 3);
-    }
-    area_map
+pub fn part1(input: &str) -> Result<usize> {
+    let grid = make_grid(input)?;
+    Ok(grid.get_loop_cells().len() / 2)
 }
 
-fn paint_area(area_map: &mut Vec<Vec<i32>>, i: i32, j: i32, area: i32) {
-    let mut to_do = vec![(i as i32, j as i32)];
-    while !to_do.is_empty() {
-        let (i, j) = to_do.pop().unwrap();
-        if i >= 0
-            && i < area_map.len() as i32
-            && j >= 0
-            && j < area_map[0].len() as i32
-            && area_map[i as usize][j as usize] == 0
-        {
-            area_map[i as usize][j as usize] = area;
-            to_do.push((i - 1, j));
-            to_do.push((i + 1, j));
-            to_do.push((i, j - 1));
-            to_do.push((i, j + 1));
-        }
-    }
+pub fn part2(input: &str) -> Result<i64> {
+    let grid = make_grid(input)?;
+    let vertices = grid.get_loop_cells().into_iter().map(|(i, j)| (i as i64, j as i64)).collect();
+    // tiles enclosed by the loop are the lattice points inside the polygon it draws
+    Ok(Polygon::new(vertices).interior_points())
 }
 
 pub fn solve1() -> usize {
-    let grid = make_grid();
-    grid.get_loop_cells().len() / 2
+    part1(&utils::read_day_input(2023, 10).unwrap()).unwrap()
 }
 
-pub fn solve2() -> i32 {
-    let grid = make_grid();
-    let area_map = paint_areas(&grid);
-    let mut count = vec![0, 0, 0, 0];
-    for i in 0..area_map.len() {
-        for j in 0..area_map[i].len() {
-            count[area_map[i][j] as usize] += 1;
-        }
-    }
-
-    if area_map[0][0] == 2 {
-        return count[3];
-    } else if area_map[0][0] == 3 {
-        return count[2];
-    }
-    panic!("No area found");
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 10).unwrap()).unwrap()
 }
 
 #[cfg(test)]
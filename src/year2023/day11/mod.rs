@@ -1,26 +1,31 @@
 use std::collections::HashSet;
 
 use crate::utils;
+use crate::{Error, Result};
 
-fn make_galaxies() -> Vec<(usize, usize)> {
+fn make_galaxies(input: &str) -> Result<Vec<(usize, usize)>> {
     let mut galaxies = Vec::new();
-    let input = utils::read_input("src/year2023/day11/input.txt").unwrap();
     for (i, line) in input.lines().enumerate() {
-        for (j, chars) in line.chars().enumerate() {
-            if chars == '#' {
-                galaxies.push((i, j));
+        for (j, (k, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => galaxies.push((i, j)),
+                '.' => {}
+                _ => return Err(Error::parse_in(input, &line[k..], "expected '#' or '.'")),
             }
         }
     }
-    galaxies
+    if galaxies.is_empty() {
+        return Err(Error::no_solution("there are no galaxies"));
+    }
+    Ok(galaxies)
 }
 
-fn get_total_distance(galaxies: &Vec<(usize, usize)>, expansion: i64) -> i64 {
+fn get_total_distance(galaxies: &[(usize, usize)], expansion: i64) -> i64 {
     let rows = galaxies.iter().map(|(i, _)| i).collect::<HashSet<_>>();
     let cols = galaxies.iter().map(|(_, j)| j).collect::<HashSet<_>>();
 
     let mut total = 0;
-    for i in 0..galaxies.len() - 1 {
+    for i in 0..galaxies.len() {
         for j in i + 1..galaxies.len() {
             let (i1, j1) = galaxies[i];
             let (i2, j2) = galaxies[j];
@@ -45,14 +50,22 @@ fn get_total_distance(galaxies: &Vec<(usize, usize)>, expansion: i64) -> i64 {
     total
 }
 
+pub fn part1(input: &str) -> Result<i64> {
+    let galaxies = make_galaxies(input)?;
+    Ok(get_total_distance(&galaxies, 2))
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let galaxies = make_galaxies(input)?;
+    Ok(get_total_distance(&galaxies, 1_000_000))
+}
+
 pub fn solve1() -> i64 {
-    let galaxies = make_galaxies();
-    get_total_distance(&galaxies, 2)
+    part1(&utils::read_day_input(2023, 11).unwrap()).unwrap()
 }
 
 pub fn solve2() -> i64 {
-    let galaxies = make_galaxies();
-    get_total_distance(&galaxies, 1_000_000)
+    part2(&utils::read_day_input(2023, 11).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -60,6 +73,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1.5s)")]
     fn test_solve1() {
         let solution = solve1();
         println!("Part One: {}", solution);
@@ -67,6 +81,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1.4s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use nom::{
     bytes::complete::{is_a, tag},
     multi::separated_list1,
@@ -7,7 +5,9 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::utils::memo::Memo;
+use crate::Result;
 
 fn parse_line(input: &str) -> IResult<&str, (&str, Vec<usize>)> {
     separated_pair(
@@ -17,79 +17,64 @@ fn parse_line(input: &str) -> IResult<&str, (&str, Vec<usize>)> {
     )(input)
 }
 
-fn count(
-    springs: &[char],
-    segments: &[usize],
-    memo: &mut HashMap<(Vec<char>, Vec<usize>), i64>,
-) -> i64 {
-    let key = (springs.to_vec(), segments.to_vec());
-    if memo.contains_key(&key) {
-        return *memo.get(&key).unwrap();
-    }
+fn parse_input(input: &str) -> Result<Vec<(&str, Vec<usize>)>> {
+    utils::parse_all(parse::lines(parse_line), input)
+}
 
-    let mut value;
-    match springs.get(0) {
+/// Memo of the number of arrangements, indexed by the number of springs and segments left (which
+/// identifies the suffixes of the springs and segments of a line)
+type CountMemo = Memo<(usize, usize), i64>;
+
+/// Number of ways to place the damaged segments of given lengths in the springs
+fn count(springs: &[char], segments: &[usize], memo: &mut CountMemo) -> i64 {
+    memo.get_or_compute((springs.len(), segments.len()), |memo, _| match springs.first() {
         None => {
-            if segments.len() == 0 {
-                value = 1;
-            } else {
-                value = 0;
-            }
-        }
-        Some('.') => {
-            value = count(&springs[1..], segments, memo);
-        }
-        Some('#') => {
-            if segments.len() == 0 {
-                value = 0;
+            if segments.is_empty() {
+                1
             } else {
-                let n = segments[0];
-                if springs.len() < n {
-                    value = 0;
-                } else if springs.len() == n && springs.iter().all(|c| *c != '.') {
-                    if segments.len() == 1 {
-                        value = 1;
-                    } else {
-                        value = 0;
-                    }
-                } else if springs[..n].iter().all(|c| *c != '.') && (springs[n] != '#') {
-                    value = count(&springs[n + 1..], &segments[1..], memo);
-                } else {
-                    value = 0;
-                }
+                0
             }
         }
-        Some('?') => {
-            value = count(&springs[1..], segments, memo);
-            let mut new_springs = springs.to_vec();
-            new_springs[0] = '#';
-            value += count(&new_springs, segments, memo);
+        Some('.') => count(&springs[1..], segments, memo),
+        Some('#') => count_from_segment(springs, segments, memo),
+        Some('?') => count(&springs[1..], segments, memo) + count_from_segment(springs, segments, memo),
+        // springs are parsed with `is_a(".#?")`
+        _ => unreachable!("invalid spring"),
+    })
+}
+
+/// Number of ways to place the segments when the first one starts on the first spring
+fn count_from_segment(springs: &[char], segments: &[usize], memo: &mut CountMemo) -> i64 {
+    let Some(&n) = segments.first() else {
+        return 0;
+    };
+    if springs.len() < n || springs[..n].contains(&'.') {
+        0
+    } else if springs.len() == n {
+        if segments.len() == 1 {
+            1
+        } else {
+            0
         }
-        _ => panic!("Invalid input"),
+    } else if springs[n] != '#' {
+        count(&springs[n + 1..], &segments[1..], memo)
+    } else {
+        0
     }
-
-    memo.insert(key, value);
-    value
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2023/day12/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i64> {
     let mut total = 0;
-    let mut memo = HashMap::new();
-    for line in input.lines() {
-        let (_, (springs, segments)) = parse_line(line).unwrap();
+    for (springs, segments) in parse_input(input)? {
         let springs = springs.chars().collect::<Vec<char>>();
-        total += count(&springs, &segments, &mut memo);
+        total += count(&springs, &segments, &mut Memo::new());
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2023/day12/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut total = 0;
-    let mut memo = HashMap::new();
-    for line in input.lines() {
-        let (_, (root_springs, root_segments)) = parse_line(line).unwrap();
+    for (root_springs, root_segments) in parse_input(input)? {
         let root_springs = root_springs.chars().collect::<Vec<char>>();
 
         // copy root springs and segments 5 times
@@ -103,9 +88,17 @@ pub fn solve2() -> i64 {
             segments.append(&mut root_segments.clone());
         }
 
-        total += count(&springs, &segments, &mut memo);
+        total += count(&springs, &segments, &mut Memo::new());
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2023, 12).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 12).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -120,7 +113,6 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (2s)"]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,50 +1,66 @@
 use crate::utils;
+use crate::{Error, Result};
 
-fn parse_input() -> Vec<Vec<Vec<bool>>> {
-    let input = utils::read_input("src/year2023/day13/input.txt").unwrap();
+/// Parses the patterns (blocks of rows of the same length, separated by blank lines)
+fn parse_input(input: &str) -> Result<Vec<Vec<Vec<bool>>>> {
     let mut result = Vec::new();
-    let mut current_pattern = Vec::new();
+    let mut current_pattern: Vec<Vec<bool>> = Vec::new();
 
     for line in input.lines() {
         if line.is_empty() {
+            if current_pattern.is_empty() {
+                return Err(Error::parse_in(input, line, "expected a pattern"));
+            }
             result.push(current_pattern);
             current_pattern = Vec::new();
             continue;
         }
         let mut row = Vec::new();
-        for c in line.chars() {
-            row.push(c == '#');
+        for (k, c) in line.char_indices() {
+            match c {
+                '#' => row.push(true),
+                '.' => row.push(false),
+                _ => return Err(Error::parse_in(input, &line[k..], "expected '#' or '.'")),
+            }
+        }
+        if current_pattern.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::parse_in(input, line, "rows of different lengths"));
         }
         current_pattern.push(row);
     }
-    result.push(current_pattern);
-    result
+    if !current_pattern.is_empty() {
+        result.push(current_pattern);
+    }
+    if result.is_empty() {
+        return Err(Error::parse_at(input, 0, "expected a pattern"));
+    }
+    Ok(result)
 }
 
-fn has_horizontal_mirror(pattern: &Vec<Vec<bool>>, row: usize) -> bool {
+fn has_horizontal_mirror(pattern: &[Vec<bool>], row: usize) -> bool {
     let mut result = true;
     let mut i = 0;
-    while i + 1 <= row && row + i < pattern.len() && result {
+    while i < row && row + i < pattern.len() && result {
         result &= pattern[row - 1 - i] == pattern[row + i];
         i += 1;
     }
     result
 }
 
-fn has_vertical_mirror(pattern: &Vec<Vec<bool>>, col: usize) -> bool {
+fn has_vertical_mirror(pattern: &[Vec<bool>], col: usize) -> bool {
     let mut result = true;
     let mut j = 0;
-    while j + 1 <= col && col + j < pattern[0].len() && result {
-        for i in 0..pattern.len() {
-            result &= pattern[i][col - j - 1] == pattern[i][col + j];
+    while j < col && col + j < pattern[0].len() && result {
+        for line in pattern {
+            result &= line[col - j - 1] == line[col + j];
         }
         j += 1;
     }
     result
 }
 
-pub fn solve1() -> usize {
-    let patterns = parse_input();
+pub fn part1(input: &str) -> Result<usize> {
+    let patterns = parse_input(input)?;
     let mut total = 0;
     for pattern in patterns {
         for col in 1..pattern[0].len() {
@@ -58,15 +74,15 @@ pub fn solve1() -> usize {
             }
         }
     }
-    total
+    Ok(total)
 }
 
-fn has_horizontal_mirror_with_smudge(pattern: &Vec<Vec<bool>>, row: usize) -> bool {
+fn has_horizontal_mirror_with_smudge(pattern: &[Vec<bool>], row: usize) -> bool {
     let mut did_find_smudge = false;
     let mut i = 0;
-    while i + 1 <= row && row + i < pattern.len() {
-        for j in 0..pattern[0].len() {
-            if pattern[row - 1 - i][j] != pattern[row + i][j] {
+    while i < row && row + i < pattern.len() {
+        for (a, b) in pattern[row - 1 - i].iter().zip(&pattern[row + i]) {
+            if a != b {
                 if !did_find_smudge {
                     did_find_smudge = true;
                 } else {
@@ -79,12 +95,12 @@ fn has_horizontal_mirror_with_smudge(pattern: &Vec<Vec<bool>>, row: usize) -> bo
     did_find_smudge
 }
 
-fn has_vertical_mirror_with_smudge(pattern: &Vec<Vec<bool>>, col: usize) -> bool {
+fn has_vertical_mirror_with_smudge(pattern: &[Vec<bool>], col: usize) -> bool {
     let mut did_find_smudge = false;
     let mut j = 0;
-    while j + 1 <= col && col + j < pattern[0].len() {
-        for i in 0..pattern.len() {
-            if pattern[i][col - j - 1] != pattern[i][col + j] {
+    while j < col && col + j < pattern[0].len() {
+        for line in pattern {
+            if line[col - j - 1] != line[col + j] {
                 if !did_find_smudge {
                     did_find_smudge = true;
                 } else {
@@ -97,8 +113,8 @@ fn has_vertical_mirror_with_smudge(pattern: &Vec<Vec<bool>>, col: usize) -> bool
     did_find_smudge
 }
 
-pub fn solve2() -> usize {
-    let patterns = parse_input();
+pub fn part2(input: &str) -> Result<usize> {
+    let patterns = parse_input(input)?;
     let mut total = 0;
     'pattern:
     for pattern in patterns {
@@ -115,7 +131,15 @@ pub fn solve2() -> usize {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2023, 13).unwrap()).unwrap()
+}
+
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2023, 13).unwrap()).unwrap()
 }
 
 #[cfg(test)]
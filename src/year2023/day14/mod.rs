@@ -1,19 +1,15 @@
-use crate::utils;
+use crate::utils::{self, cycle, Grid};
+use crate::{Error, Result};
 
-fn parse_input(filename: &str) -> Vec<Vec<char>> {
-    let input = utils::read_input(filename).unwrap();
-    let mut result = Vec::new();
-    for line in input.lines() {
-        let mut row = Vec::new();
-        for c in line.chars() {
-            row.push(c);
-        }
-        result.push(row);
+fn parse_input(input: &str) -> Result<Vec<Vec<char>>> {
+    let grid = Grid::try_parse(input, |c| matches!(c, 'O' | '#' | '.').then_some(c))?;
+    if grid.width() == 0 {
+        return Err(Error::parse_at(input, 0, "empty platform"));
     }
-    result
+    Ok(grid.rows().map(<[char]>::to_vec).collect())
 }
 
-fn roll_north(rocks: &mut Vec<Vec<char>>) {
+fn roll_north(rocks: &mut [Vec<char>]) {
     for i in 0..rocks.len() {
         for j in 0..rocks[0].len() {
             if rocks[i][j] == 'O' {
@@ -30,7 +26,7 @@ fn roll_north(rocks: &mut Vec<Vec<char>>) {
     }
 }
 
-fn roll_south(rocks: &mut Vec<Vec<char>>) {
+fn roll_south(rocks: &mut [Vec<char>]) {
     for i in (0..rocks.len()).rev() {
         for j in 0..rocks[0].len() {
             if rocks[i][j] == 'O' {
@@ -47,7 +43,7 @@ fn roll_south(rocks: &mut Vec<Vec<char>>) {
     }
 }
 
-fn roll_west(rocks: &mut Vec<Vec<char>>) {
+fn roll_west(rocks: &mut [Vec<char>]) {
     for j in 0..rocks[0].len() {
         for i in (0..rocks.len()).rev() {
             if rocks[i][j] == 'O' {
@@ -64,7 +60,7 @@ fn roll_west(rocks: &mut Vec<Vec<char>>) {
     }
 }
 
-fn roll_east(rocks: &mut Vec<Vec<char>>) {
+fn roll_east(rocks: &mut [Vec<char>]) {
     for j in (0..rocks[0].len()).rev() {
         for i in (0..rocks.len()).rev() {
             if rocks[i][j] == 'O' {
@@ -81,21 +77,21 @@ fn roll_east(rocks: &mut Vec<Vec<char>>) {
     }
 }
 
-fn spin_cycle(rocks: &mut Vec<Vec<char>>, nb_loops: usize) {
-    for _ in 0..nb_loops {
-        roll_north(rocks);
-        roll_west(rocks);
-        roll_south(rocks);
-        roll_east(rocks);
-    }
+fn spin_cycle(rocks: &[Vec<char>]) -> Vec<Vec<char>> {
+    let mut rocks = rocks.to_vec();
+    roll_north(&mut rocks);
+    roll_west(&mut rocks);
+    roll_south(&mut rocks);
+    roll_east(&mut rocks);
+    rocks
 }
 
-fn get_total_load(rocks: &Vec<Vec<char>>) -> usize {
+fn get_total_load(rocks: &[Vec<char>]) -> usize {
     let mut result = 0;
     let n = rocks.len();
-    for i in 0..rocks.len() {
-        for j in 0..rocks[0].len() {
-            if rocks[i][j] == 'O' {
+    for (i, row) in rocks.iter().enumerate() {
+        for &rock in row {
+            if rock == 'O' {
                 result += n - i;
             }
         }
@@ -103,28 +99,24 @@ fn get_total_load(rocks: &Vec<Vec<char>>) -> usize {
     result
 }
 
-pub fn solve1() -> usize {
-    let mut rocks = parse_input("src/year2023/day14/input.txt");
+pub fn part1(input: &str) -> Result<usize> {
+    let mut rocks = parse_input(input)?;
     roll_north(&mut rocks);
-    get_total_load(&rocks)
+    Ok(get_total_load(&rocks))
 }
 
-pub fn solve2() -> usize {
-    let mut rocks = parse_input("src/year2023/day14/input.txt");
-    let mut rocks2 = rocks.clone();
-    let mut counter = 0;
+pub fn part2(input: &str) -> Result<usize> {
+    let rocks = parse_input(input)?;
+    let rocks = cycle::state_after(rocks, |rocks| spin_cycle(rocks), 1000000000);
+    Ok(get_total_load(&rocks))
+}
 
-    loop {
-        spin_cycle(&mut rocks, 1);
-        spin_cycle(&mut rocks2, 2);
-        counter += 1;
-        if rocks == rocks2 {
-            break;
-        }
-    }
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2023, 14).unwrap()).unwrap()
+}
 
-    spin_cycle(&mut rocks, 1000000000 % counter);
-    get_total_load(&rocks)
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2023, 14).unwrap()).unwrap()
 }
 
 #[cfg(test)]
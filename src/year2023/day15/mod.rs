@@ -7,7 +7,8 @@ use nom::{
     IResult,
 };
 
-use crate::utils;
+use crate::utils::{self, parse};
+use crate::{Error, Result};
 
 #[derive(Debug, PartialEq)]
 enum StepAction {
@@ -28,10 +29,8 @@ impl Lens {
     }
 }
 
-fn parse_input(filename: &str) -> Vec<String> {
-    let input = utils::read_input(filename).unwrap();
-    let steps = input.trim_end().split(',').map(|s| s.to_string()).collect();
-    steps
+fn parse_input(input: &str) -> Vec<&str> {
+    input.trim_end().split(',').collect()
 }
 
 fn parse_step(input: &str) -> IResult<&str, (String, StepAction)> {
@@ -55,24 +54,27 @@ fn hash(input: &str) -> usize {
     acc as usize
 }
 
-pub fn solve1() -> usize {
-    let steps = parse_input("src/year2023/day15/input.txt");
+pub fn part1(input: &str) -> Result<usize> {
+    let steps = parse_input(input);
     let mut total = 0;
     for step in steps {
-        total += hash(&step);
+        if let Some(k) = step.find(|c: char| !c.is_ascii_graphic()) {
+            return Err(Error::parse_in(input, &step[k..], "unexpected character"));
+        }
+        total += hash(step);
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> usize {
-    let steps = parse_input("src/year2023/day15/input.txt");
+pub fn part2(input: &str) -> Result<usize> {
+    let steps = parse_input(input);
     let mut boxes: Vec<Vec<Lens>> = Vec::new();
     for _ in 0..256 {
         boxes.push(Vec::new());
     }
 
     for step in steps {
-        let (_, (label, action)) = parse_step(&step).unwrap();
+        let (label, action) = parse::parse_all_in(parse_step, input, step)?;
         let current_box = &mut boxes[hash(&label)];
         match action {
             StepAction::Remove => {
@@ -98,7 +100,15 @@ pub fn solve2() -> usize {
             total += (box_index + 1) * (lens_index + 1) * f.focal_length;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2023, 15).unwrap()).unwrap()
+}
+
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2023, 15).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
-use crate::utils;
+use crate::utils::{self, Grid};
+use crate::{Error, Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Ray {
@@ -15,19 +16,18 @@ impl Ray {
     }
 }
 
-fn make_grid(filename: &str) -> Vec<Vec<char>> {
-    let input = utils::read_input(filename).unwrap();
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    grid
+fn make_grid(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::try_parse(input, |c| ".|-/\\".contains(c).then_some(c))?;
+    if grid.width() == 0 {
+        return Err(Error::parse_at(input, 0, "empty grid"));
+    }
+    Ok(grid)
 }
 
-fn ray_next(grid: &Vec<Vec<char>>, r: &Ray) -> Vec<Ray> {
+fn ray_next(grid: &Grid<char>, r: &Ray) -> Vec<Ray> {
     let mut result = Vec::new();
 
-    match grid[r.i as usize][r.j as usize] {
+    match grid[(r.i as usize, r.j as usize)] {
         '.' => result.push(Ray::new(r.i + r.di, r.j + r.dj, r.di, r.dj)),
         '|' => {
             if r.dj != 0 {
@@ -59,52 +59,63 @@ fn ray_next(grid: &Vec<Vec<char>>, r: &Ray) -> Vec<Ray> {
                 result.push(Ray::new(r.i, r.j + r.di, 0, r.di));
             }
         }
-        _ => panic!("Invalid symbol"),
+        // symbols are checked when parsing the grid
+        _ => unreachable!("invalid symbol"),
     }
 
-    return result
+    result
         .into_iter()
-        .filter(|r| 0 <= r.i && r.i < grid.len() as i32 && 0 <= r.j && r.j < grid.len() as i32)
-        .collect::<Vec<Ray>>();
+        .filter(|r| grid.get_signed(r.i as i64, r.j as i64).is_some())
+        .collect::<Vec<Ray>>()
 }
 
-fn count_energized_cells(grid: &Vec<Vec<char>>, initial_ray: Ray) -> usize {
-    let mut rays_grid = vec![vec![HashSet::new(); grid.len()]; grid.len()];
+fn count_energized_cells(grid: &Grid<char>, initial_ray: Ray) -> usize {
+    let mut rays_grid = Grid::new(grid.height(), grid.width(), HashSet::new());
     let mut to_do = Vec::new();
 
-    rays_grid[initial_ray.i as usize][initial_ray.j as usize].insert(initial_ray);
+    rays_grid[(initial_ray.i as usize, initial_ray.j as usize)].insert(initial_ray);
     to_do.push(initial_ray);
 
-    while !to_do.is_empty() {
-        let r = to_do.pop().unwrap();
-        let new_rays = ray_next(&grid, &r);
+    while let Some(r) = to_do.pop() {
+        let new_rays = ray_next(grid, &r);
         for new_ray in new_rays {
-            if !rays_grid[new_ray.i as usize][new_ray.j as usize].contains(&new_ray) {
-                rays_grid[new_ray.i as usize][new_ray.j as usize].insert(new_ray);
+            let cell = &mut rays_grid[(new_ray.i as usize, new_ray.j as usize)];
+            if !cell.contains(&new_ray) {
+                cell.insert(new_ray);
                 to_do.push(new_ray);
             }
         }
     }
 
-    return rays_grid.iter().flatten().filter(|s| !s.is_empty()).count()
+    rays_grid.iter().filter(|(_, s)| !s.is_empty()).count()
 }
 
-pub fn solve1() -> usize {
-    let grid = make_grid("src/year2023/day16/input.txt");
-    count_energized_cells(&grid, Ray::new(0, 0, 0, 1))
+pub fn part1(input: &str) -> Result<usize> {
+    let grid = make_grid(input)?;
+    Ok(count_energized_cells(&grid, Ray::new(0, 0, 0, 1)))
 }
 
-pub fn solve2() -> usize {
-    let grid = make_grid("src/year2023/day16/input.txt");
+pub fn part2(input: &str) -> Result<usize> {
+    let grid = make_grid(input)?;
     let mut best = 0;
-    let n = grid.len() as i32;
-    for k in 0..n {
-        best = best.max(count_energized_cells(&grid, Ray::new(k, 0, 0, 1)));
-        best = best.max(count_energized_cells(&grid, Ray::new(k, n - 1, 0, -1)));
-        best = best.max(count_energized_cells(&grid, Ray::new(0, k, 1, 0)));
-        best = best.max(count_energized_cells(&grid, Ray::new(n - 1, k, -1, 0)));
+    let (h, w) = (grid.height() as i32, grid.width() as i32);
+    for i in 0..h {
+        best = best.max(count_energized_cells(&grid, Ray::new(i, 0, 0, 1)));
+        best = best.max(count_energized_cells(&grid, Ray::new(i, w - 1, 0, -1)));
     }
-    best
+    for j in 0..w {
+        best = best.max(count_energized_cells(&grid, Ray::new(0, j, 1, 0)));
+        best = best.max(count_energized_cells(&grid, Ray::new(h - 1, j, -1, 0)));
+    }
+    Ok(best)
+}
+
+pub fn solve1() -> usize {
+    part1(&utils::read_day_input(2023, 16).unwrap()).unwrap()
+}
+
+pub fn solve2() -> usize {
+    part2(&utils::read_day_input(2023, 16).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -119,7 +130,7 @@ mod tests {
     }
 
     #[test]
-    // #[ignore = "long test (3s)"]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (2s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
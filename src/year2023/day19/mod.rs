@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 
-use nom::combinator::opt;
+use nom::combinator::{ map_opt, opt };
 use nom::multi::separated_list1;
-use nom::sequence::tuple;
+use nom::sequence::{ delimited, pair, preceded, tuple };
 use nom::IResult;
 use nom::character::complete::{ alpha1, char, one_of };
-use crate::utils::{ self, parse_unsigned_int };
+use crate::utils::{ self, parse, parse_unsigned_int };
+use crate::utils::interval::Interval;
+use crate::{ Error, Result };
 
 type MachinePart = [i32; 4];
 
+/// Rules of each workflow, indexed by the workflow names
+type Workflows = HashMap<String, Vec<WorkflowRule>>;
+
 #[derive(Debug)]
 struct WorkflowRule {
     condition: Option<RuleCondition>,
@@ -28,7 +33,8 @@ impl RuleCondition {
         match self.comparator {
             '<' => value < self.value,
             '>' => value > self.value,
-            _ => panic!("Invalid comparator"),
+            // comparators are parsed with `one_of("<>")`
+            _ => unreachable!("invalid comparator"),
         }
     }
 }
@@ -40,33 +46,12 @@ enum RuleOutput {
     Redirect(String),
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Interval {
-    min: i32,
-    max: i32,
-}
-
-impl Interval {
-    fn split(&self, value: i32) -> (Option<Interval>, Option<Interval>) {
-        if value <= self.min {
-            (None, Some(Interval { min: self.min, max: self.max }))
-        } else if value >= self.max {
-            (Some(Interval { min: self.min, max: self.max }), None)
-        } else {
-            (
-                Some(Interval { min: self.min, max: value }),
-                Some(Interval { min: value, max: self.max }),
-            )
-        }
-    }
-}
-
-type CombinationsBox = [Interval; 4];
+type CombinationsBox = [Interval<i32>; 4];
 
 fn get_box_size(combinations: &CombinationsBox) -> i64 {
     let mut size = 1;
     for interval in combinations {
-        size *= (interval.max - interval.min) as i64;
+        size *= interval.len() as i64;
     }
     size
 }
@@ -78,11 +63,11 @@ fn parse_name(input: &str) -> IResult<&str, String> {
 }
 
 fn parse_condition(input: &str) -> IResult<&str, RuleCondition> {
-    let (input, category) = one_of("xmas")(input)?;
+    let (input, category) = map_opt(one_of("xmas"), |c| "xmas".find(c))(input)?;
     let (input, comparator) = one_of("<>")(input)?;
     let (input, value) = parse_unsigned_int(input)?;
     let (input, _) = char(':')(input)?;
-    Ok((input, RuleCondition { category: "xmas".find(category).unwrap(), comparator, value }))
+    Ok((input, RuleCondition { category, comparator, value }))
 }
 
 fn parse_rule(input: &str) -> IResult<&str, WorkflowRule> {
@@ -102,50 +87,45 @@ fn parse_rule(input: &str) -> IResult<&str, WorkflowRule> {
     ))
 }
 
-fn parse_machine_part(input: &str) -> IResult<&str, MachinePart> {
-    let (input, _) = char('{')(input)?;
-    let (input, pairs) = separated_list1(
-        char(','),
-        tuple((one_of("xmas"), char('='), parse_unsigned_int::<i32>))
-    )(input)?;
-    let (input, _) = char('}')(input)?;
+fn parse_workflow(input: &str) -> IResult<&str, (String, Vec<WorkflowRule>)> {
+    tuple((
+        parse_name,
+        delimited(char('{'), separated_list1(char(','), parse_rule), char('}')),
+    ))(input)
+}
 
-    let mut machine_part = [0; 4];
-    for i in 0..4 {
-        machine_part[i] = pairs[i].2;
-    }
-    Ok((input, machine_part))
+fn parse_machine_part(input: &str) -> IResult<&str, MachinePart> {
+    let rating = preceded(pair(one_of("xmas"), char('=')), parse_unsigned_int::<i32>);
+    // a machine part has exactly one rating for each category
+    map_opt(
+        delimited(char('{'), separated_list1(char(','), rating), char('}')),
+        |ratings| MachinePart::try_from(ratings).ok()
+    )(input)
 }
 
-fn parse_input(input: &str) -> (HashMap<String, Vec<WorkflowRule>>, Vec<MachinePart>) {
-    let sections = input
-        .split("\n\n")
-        .map(|section| section.to_string())
-        .collect::<Vec<String>>();
+fn parse_input(input: &str) -> Result<(Workflows, Vec<MachinePart>)> {
+    let (workflows, machine_parts) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse_at(input, input.len(), "expected a blank line after the workflows"))?;
+    let workflows = parse::parse_all_in(parse::lines(parse_workflow), input, workflows)?;
+    let machine_parts = parse::parse_all_in(parse::lines(parse_machine_part), input, machine_parts)?;
+    Ok((workflows.into_iter().collect(), machine_parts))
+}
 
-    let mut workflows: HashMap<String, Vec<WorkflowRule>> = HashMap::new();
-    for line in sections[0].lines() {
-        let (_, (name, _, rules)) = tuple((
-            parse_name,
-            char('{'),
-            separated_list1(char(','), parse_rule),
-        ))(line).unwrap();
-        workflows.insert(name, rules);
-    }
+fn get_workflow<'a>(workflows: &'a Workflows, name: &str) -> Result<&'a Vec<WorkflowRule>> {
+    workflows.get(name).ok_or_else(|| Error::no_solution(format!("no workflow named {:?}", name)))
+}
 
-    let mut machine_parts: Vec<MachinePart> = Vec::new();
-    for line in sections[1].lines() {
-        let (_, machine_part) = parse_machine_part(line).unwrap();
-        machine_parts.push(machine_part);
-    }
-    (workflows, machine_parts)
+/// Workflow from which all machine parts start
+fn initial_workflow(workflows: &Workflows) -> Result<&Vec<WorkflowRule>> {
+    get_workflow(workflows, "in")
 }
 
 fn sort(
     machine_part: &MachinePart,
     workflow: &Vec<WorkflowRule>,
     workflows: &HashMap<String, Vec<WorkflowRule>>
-) -> bool {
+) -> Result<bool> {
     for rule in workflow {
         match &rule.condition {
             Some(condition) => {
@@ -154,32 +134,32 @@ fn sort(
                 }
                 match rule.output {
                     RuleOutput::Accept => {
-                        return true;
+                        return Ok(true);
                     }
                     RuleOutput::Reject => {
-                        return false;
+                        return Ok(false);
                     }
                     RuleOutput::Redirect(ref target) => {
-                        return sort(machine_part, workflows.get(target).unwrap(), workflows);
+                        return sort(machine_part, get_workflow(workflows, target)?, workflows);
                     }
                 }
             }
             None => {
                 match rule.output {
                     RuleOutput::Accept => {
-                        return true;
+                        return Ok(true);
                     }
                     RuleOutput::Reject => {
-                        return false;
+                        return Ok(false);
                     }
                     RuleOutput::Redirect(ref target) => {
-                        return sort(machine_part, workflows.get(target).unwrap(), workflows);
+                        return sort(machine_part, get_workflow(workflows, target)?, workflows);
                     }
                 }
             }
         }
     }
-    false
+    Ok(false)
 }
 
 /// Counts the number of combinations in a given intervals box that will be accepted if processed through a workflow
@@ -195,18 +175,21 @@ fn count_accepted(
     workflow: &Vec<WorkflowRule>,
     rule_index: usize,
     workflows: &HashMap<String, Vec<WorkflowRule>>
-) -> i64 {
+) -> Result<i64> {
     let mut total: i64 = 0;
-    let rule = &workflow[rule_index];
+    let Some(rule) = workflow.get(rule_index) else {
+        // combinations that fail all rules of a workflow are rejected
+        return Ok(0);
+    };
     match &rule.condition {
         Some(condition) => {
             let passed_combinations: Option<CombinationsBox>; // combinations that pass the rule condition
             let failed_combinations: Option<CombinationsBox>; // combinations that fail the rule condition
             if condition.comparator == '<' {
-                let (low, high) = combinations[condition.category].split(condition.value);
+                let (low, high) = combinations[condition.category].split_at(condition.value);
                 match low {
                     Some(low) => {
-                        let mut passed = combinations.clone();
+                        let mut passed = *combinations;
                         passed[condition.category] = low;
                         passed_combinations = Some(passed);
                     }
@@ -216,7 +199,7 @@ fn count_accepted(
                 }
                 match high {
                     Some(high) => {
-                        let mut failed = combinations.clone();
+                        let mut failed = *combinations;
                         failed[condition.category] = high;
                         failed_combinations = Some(failed);
                     }
@@ -225,10 +208,10 @@ fn count_accepted(
                     }
                 }
             } else {
-                let (low, high) = combinations[condition.category].split(condition.value + 1);
+                let (low, high) = combinations[condition.category].split_at(condition.value + 1);
                 match low {
                     Some(low) => {
-                        let mut failed = combinations.clone();
+                        let mut failed = *combinations;
                         failed[condition.category] = low;
                         failed_combinations = Some(failed);
                     }
@@ -238,7 +221,7 @@ fn count_accepted(
                 }
                 match high {
                     Some(high) => {
-                        let mut passed = combinations.clone();
+                        let mut passed = *combinations;
                         passed[condition.category] = high;
                         passed_combinations = Some(passed);
                     }
@@ -261,17 +244,17 @@ fn count_accepted(
                     if let Some(passed_combinations) = passed_combinations {
                         total += count_accepted(
                             &passed_combinations,
-                            workflows.get(target).unwrap(),
+                            get_workflow(workflows, target)?,
                             0,
                             workflows
-                        );
+                        )?;
                     }
                 }
             }
             if let Some(failed_combinations) = failed_combinations {
                 // process the combinations that failed the rule condition
                 // combinations are passed through the next rule in the current workflow
-                total += count_accepted(&failed_combinations, workflow, rule_index + 1, workflows);
+                total += count_accepted(&failed_combinations, workflow, rule_index + 1, workflows)?;
             }
         }
         None => {
@@ -286,49 +269,57 @@ fn count_accepted(
                     // forward all combinations to the next workflow
                     total += count_accepted(
                         combinations,
-                        workflows.get(target).unwrap(),
+                        get_workflow(workflows, target)?,
                         0,
                         workflows
-                    );
+                    )?;
                 }
             }
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2023/day19/input.txt").unwrap();
-    let (workflows, machine_parts) = parse_input(&input);
+pub fn part1(input: &str) -> Result<i32> {
+    let (workflows, machine_parts) = parse_input(input)?;
 
     let mut total = 0;
-    let initial_workflow = workflows.get("in").unwrap();
+    let initial_workflow = initial_workflow(&workflows)?;
 
     for machine_part in &machine_parts {
-        if sort(machine_part, initial_workflow, &workflows) {
+        if sort(machine_part, initial_workflow, &workflows)? {
             total += machine_part.iter().sum::<i32>();
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2023/day19/input.txt").unwrap();
-    let (workflows, _) = parse_input(&input);
+pub fn part2(input: &str) -> Result<i64> {
+    let (workflows, _) = parse_input(input)?;
 
     let combinations: CombinationsBox = [
-        Interval { min: 1, max: 4001 },
-        Interval { min: 1, max: 4001 },
-        Interval { min: 1, max: 4001 },
-        Interval { min: 1, max: 4001 },
+        Interval::new(1, 4001),
+        Interval::new(1, 4001),
+        Interval::new(1, 4001),
+        Interval::new(1, 4001),
     ];
-    count_accepted(&combinations, &workflows.get("in").unwrap(), 0, &workflows)
+    count_accepted(&combinations, initial_workflow(&workflows)?, 0, &workflows)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 19).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2023, 19).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2023, 19);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -8,7 +8,8 @@ use nom::multi::separated_list1;
 use nom::sequence::{ pair, tuple };
 use nom::IResult;
 
-use crate::utils;
+use crate::utils::{ self, parse };
+use crate::Result;
 
 #[derive(Debug)]
 struct Pulse {
@@ -76,23 +77,6 @@ impl Module {
             }
         }
     }
-
-    fn print(&self) {
-        let module_type = match (&self.flipflop_state, &self.conjunction_state) {
-            (Some(_), _) => " (flip-flop)",
-            (_, Some(_)) => " (conjunction)",
-            _ => "",
-        };
-        println!("{}{}", self.name, module_type);
-        println!("  Outputs: {}", self.outputs.join(", "));
-        if let Some(conjunction_state) = &self.conjunction_state {
-            let keys: Vec<String> = conjunction_state
-                .keys()
-                .map(|s| s.to_string())
-                .collect();
-            println!("  Inputs: {}", keys.join(", "));
-        }
-    }
 }
 
 fn parse_module_name(input: &str) -> IResult<&str, (Option<char>, &str)> {
@@ -103,14 +87,13 @@ fn parse_module_outputs(input: &str) -> IResult<&str, Vec<&str>> {
     separated_list1(tag(", "), alpha1)(input)
 }
 
-fn parse_input(input: &str) -> HashMap<String, Module> {
+fn parse_input(input: &str) -> Result<HashMap<String, Module>> {
+    let lines = utils::parse_all(
+        parse::lines(tuple((parse_module_name, tag(" -> "), parse_module_outputs))),
+        input
+    )?;
     let mut modules = HashMap::new();
-    for line in input.lines() {
-        let (_, ((module_type, name), _, outputs)) = tuple((
-            parse_module_name,
-            tag(" -> "),
-            parse_module_outputs,
-        ))(line).unwrap();
+    for ((module_type, name), _, outputs) in lines {
         let flipflop_state = match module_type {
             Some('%') => Some(false), // initial state is off
             _ => None,
@@ -132,11 +115,11 @@ fn parse_input(input: &str) -> HashMap<String, Module> {
 
     // make map of inputs for each module
     let mut inputs_map: HashMap<String, Vec<String>> = HashMap::new();
-    for (_, module) in &modules {
+    for module in modules.values() {
         for output in &module.outputs {
             inputs_map
                 .entry(output.to_string())
-                .or_insert(Vec::new())
+                .or_default()
                 .push(module.name.to_string());
         }
     }
@@ -150,7 +133,7 @@ fn parse_input(input: &str) -> HashMap<String, Module> {
             }
         }
     }
-    modules
+    Ok(modules)
 }
 
 fn press_button_1(modules: &mut HashMap<String, Module>) -> (u64, u64) {
@@ -199,9 +182,8 @@ fn press_button_1(modules: &mut HashMap<String, Module>) -> (u64, u64) {
 //     false
 // }
 
-pub fn solve1() -> u64 {
-    let input = utils::read_input("src/year2023/day20/input.txt").unwrap();
-    let mut modules = parse_input(&input);
+pub fn part1(input: &str) -> Result<u64> {
+    let mut modules = parse_input(input)?;
 
     let mut low_count = 0;
     let mut high_count = 0;
@@ -212,16 +194,11 @@ pub fn solve1() -> u64 {
         high_count += delta_high;
     }
 
-    low_count * high_count
+    Ok(low_count * high_count)
 }
 
-pub fn solve2() -> u64 {
-    let input = utils::read_input("src/year2023/day20/input.txt").unwrap();
-    let modules = parse_input(&input);
-    for (_, module) in &modules {
-        module.print();
-        println!("");
-    }
+pub fn part2(input: &str) -> Result<u64> {
+    let _modules = parse_input(input)?;
 
     // let mut button_count = 0;
     // loop {
@@ -230,13 +207,23 @@ pub fn solve2() -> u64 {
     //         return button_count;
     //     }
     // }
-    0
+    Ok(0)
+}
+
+pub fn solve1() -> u64 {
+    part1(&utils::read_day_input(2023, 20).unwrap()).unwrap()
+}
+
+pub fn solve2() -> u64 {
+    part2(&utils::read_day_input(2023, 20).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2023, 20);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
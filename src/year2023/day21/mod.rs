@@ -1,56 +1,40 @@
 use std::collections::HashSet;
 
-use crate::utils;
+use crate::utils::{self, Grid};
+use crate::{Error, Result};
 
-fn make_grid(filename: &str) -> (Vec<Vec<bool>>, usize, usize) {
-    let input = utils::read_input(filename).unwrap();
-    let mut i0 = 0;
-    let mut j0 = 0;
-    let mut grid = Vec::new();
-    for (i, line) in input.lines().enumerate() {
-        let mut grid_line = Vec::new();
-        for (j, c) in line.chars().enumerate() {
-            if c == 'S' {
-                i0 = i;
-                j0 = j;
-            }
-            grid_line.push(c == '#');
-        }
-        grid.push(grid_line);
-    }
-    (grid, i0, j0)
+fn make_grid(input: &str) -> Result<(Grid<bool>, (usize, usize))> {
+    let grid = Grid::parse(input, |c| c)?;
+    let start = grid.find('S').ok_or_else(|| Error::parse_at(input, input.len(), "missing starting position S"))?;
+    Ok((grid.map(|&c| c == '#'), start))
 }
 
-pub fn solve1() -> i32 {
-    let (grid, i0, j0) = make_grid("src/year2023/day21/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let (grid, start) = make_grid(input)?;
     let mut positions: HashSet<(usize, usize)> = HashSet::new();
-    positions.insert((i0, j0));
+    positions.insert(start);
     for _ in 0..64 {
         let mut new_positions: HashSet<(usize, usize)> = HashSet::new();
         for (i, j) in positions.iter().cloned() {
-            if !grid[i+1][j] {
-                new_positions.insert((i + 1, j));
-            }
-            if !grid[i-1][j] {
-                new_positions.insert((i - 1, j));
-            }
-            if !grid[i][j+1] {
-                new_positions.insert((i, j+1));
-            }
-            if !grid[i][j-1] {
-                new_positions.insert((i, j-1));
-            }
+            new_positions.extend(grid.neighbors4(i, j).filter(|&p| !grid[p]));
         }
         positions = new_positions;
     }
-    positions.len() as i32
+    Ok(positions.len() as i32)
 }
 
-pub fn solve2() -> i32 {
-    // let input = utils::read_input("src/year2023/day21/input.txt").unwrap();
+pub fn part2(_input: &str) -> i32 {
     0
 }
 
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 21).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2023, 21).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,7 +43,7 @@ mod tests {
     fn test_solve1() {
         let solution = solve1();
         println!("Part One: {}", solution);
-        // assert_eq!(solution, 0);
+        assert_eq!(solution, 3503);
     }
 
     #[test]
@@ -1,25 +1,14 @@
 use nom::{bytes::complete::tag, sequence::{separated_pair, tuple}, IResult};
 
-use crate::utils::{self, parse_int};
-
-#[derive(Debug)]
-struct Point3D {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl std::fmt::Display for Point3D {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
-    }
-}
+use crate::utils::{self, parse, parse_int};
+use crate::Result;
+use crate::utils::geometry::Point3;
 
 #[derive(Debug)]
 struct Brick {
     id: usize,
-    p1: Point3D,
-    p2: Point3D,
+    p1: Point3,
+    p2: Point3,
 }
 
 impl std::fmt::Display for Brick {
@@ -28,38 +17,41 @@ impl std::fmt::Display for Brick {
     }
 }
 
-fn parse_point(input: &str) -> IResult<&str, Point3D> {
+fn parse_point(input: &str) -> IResult<&str, Point3> {
     let (remaining, (x, _, y, _, z)) = tuple((
         parse_int, tag(","), parse_int, tag(","), parse_int
     ))(input)?;
-    Ok((remaining, Point3D { x, y, z }))
+    Ok((remaining, Point3::new(x, y, z)))
 }
 
-fn make_bricks(filename: &str) -> Vec<Brick> {
-    let input = utils::read_input(filename).unwrap();
+fn make_bricks(input: &str) -> Result<Vec<Brick>> {
     let mut bricks = Vec::new();
-    for (i, line) in input.lines().enumerate() {
-        let (_, (p1, p2)) = separated_pair(parse_point, tag("~"), parse_point)(line).unwrap();
+    let lines = utils::parse_all(parse::lines(separated_pair(parse_point, tag("~"), parse_point)), input)?;
+    for (i, (p1, p2)) in lines.into_iter().enumerate() {
         bricks.push(Brick {
             id: i,
             p1,
             p2,
         });
     }
-    return bricks;
+    Ok(bricks)
 }
 
-pub fn solve1() -> i32 {
-    let bricks = make_bricks("src/year2023/day22/input.txt");
-    for brick in bricks {
-        println!("{}", brick);
-    }
+pub fn part1(input: &str) -> Result<i32> {
+    let _bricks = make_bricks(input)?;
+    Ok(0)
+}
+
+pub fn part2(_input: &str) -> i32 {
     0
 }
 
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2023, 22).unwrap()).unwrap()
+}
+
 pub fn solve2() -> i32 {
-    // let input = utils::read_input("src/year2023/dayXX/input.txt").unwrap();
-    0
+    part2(&utils::read_day_input(2023, 22).unwrap())
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use crate::solution::Registry;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -20,3 +22,36 @@ pub mod day19;
 pub mod day20;
 pub mod day21;
 pub mod day22;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2023, 1, day01::part1, day01::part2);
+    registry.add(2023, 2, day02::part1, day02::part2);
+    registry.add(2023, 3, day03::part1, day03::part2);
+    registry.add(2023, 4, day04::part1, day04::part2);
+    registry.add(2023, 5, day05::part1, day05::part2);
+    registry.add(2023, 6, day06::part1, day06::part2);
+    registry.add(2023, 7, day07::part1, day07::part2);
+    registry.add(2023, 8, day08::part1, day08::part2);
+    registry.add(2023, 9, day09::part1, day09::part2);
+    registry.add(2023, 10, day10::part1, day10::part2);
+    registry.add(2023, 11, day11::part1, day11::part2);
+    registry.mark_slow(2023, 11, 1);
+    registry.mark_slow(2023, 11, 2);
+    registry.add(2023, 12, day12::part1, day12::part2);
+    registry.add(2023, 13, day13::part1, day13::part2);
+    registry.add(2023, 14, day14::part1, day14::part2);
+    registry.add(2023, 15, day15::part1, day15::part2);
+    registry.add(2023, 16, day16::part1, day16::part2);
+    registry.mark_slow(2023, 16, 2);
+    registry.add(2023, 17, day17::part1, day17::part2);
+    registry.mark_slow(2023, 17, 1);
+    registry.mark_slow(2023, 17, 2);
+    registry.add(2023, 18, day18::part1, day18::part2);
+    registry.add(2023, 19, day19::part1, day19::part2);
+    registry.add_part(2023, 20, 1, day20::part1);
+    registry.add_stub(2023, 20, 2, day20::part2);
+    registry.add_part(2023, 21, 1, day21::part1);
+    registry.add_stub(2023, 21, 2, day21::part2);
+    registry.add_stub(2023, 22, 1, day22::part1);
+    registry.add_stub(2023, 22, 2, day22::part2);
+}
@@ -1,36 +1,35 @@
 use nom::{ bytes::complete::tag, sequence::separated_pair, IResult };
 
-use crate::utils::{ self, parse_unsigned_int };
+use crate::utils::{ self, parse, parse_unsigned_int };
+use crate::Result;
 
 fn parse_line(input: &str) -> IResult<&str, (i32, i32)> {
     separated_pair(parse_unsigned_int, tag("   "), parse_unsigned_int)(input)
 }
 
-fn parse_input(filename: &str) -> (Vec<i32>, Vec<i32>) {
-    let input = utils::read_input(filename).unwrap();
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
     let mut t1 = Vec::new();
     let mut t2 = Vec::new();
-    for line in input.lines() {
-        let (_, (a, b)) = parse_line(line).unwrap();
+    for (a, b) in utils::parse_all(parse::lines(parse_line), input)? {
         t1.push(a);
         t2.push(b);
     }
-    (t1, t2)
+    Ok((t1, t2))
 }
 
-pub fn solve1() -> i32 {
-    let (mut left, mut right) = parse_input("src/year2024/day01/input.txt");
+pub fn part1(input: &str) -> Result<i32> {
+    let (mut left, mut right) = parse_input(input)?;
     left.sort();
     right.sort();
     let mut total = 0;
     for i in 0..left.len() {
         total += (left[i] - right[i]).abs();
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let (left, right) = parse_input("src/year2024/day01/input.txt");
+pub fn part2(input: &str) -> Result<i32> {
+    let (left, right) = parse_input(input)?;
     let mut total = 0;
     for x in &left {
         for y in &right {
@@ -39,7 +38,15 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 1).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 1).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,22 +1,27 @@
 use nom::{ bytes::complete::tag, multi::separated_list1, IResult };
 
 use crate::utils;
+use crate::Result;
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i32>>> {
     separated_list1(tag("\n"), separated_list1(tag(" "), utils::parse_int))(input)
 }
 
-fn remove_at_index(v: &Vec<i32>, i: usize) -> Vec<i32> {
-    let mut result = v.clone();
+fn remove_at_index(v: &[i32], i: usize) -> Vec<i32> {
+    let mut result = v.to_vec();
     result.remove(i);
     result
 }
 
-fn is_safe(v: &Vec<i32>) -> bool {
+fn is_safe(v: &[i32]) -> bool {
+    if v.len() < 2 {
+        // no pair of levels to check
+        return true;
+    }
     let should_increase = v[1] > v[0];
     for i in 0..v.len() - 1 {
         let d = (v[i + 1] - v[i]).abs();
-        if d < 1 || d > 3 {
+        if !(1..=3).contains(&d) {
             return false;
         }
         if should_increase && v[i + 1] <= v[i] {
@@ -29,21 +34,19 @@ fn is_safe(v: &Vec<i32>) -> bool {
     true
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day02/input.txt").unwrap();
-    let (_, data) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let data = utils::parse_all(parse_input, input)?;
     let mut total = 0;
     for report in &data {
         if is_safe(report) {
             total += 1;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day02/input.txt").unwrap();
-    let (_, data) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let data = utils::parse_all(parse_input, input)?;
     let mut total = 0;
     for report in &data {
         if is_safe(report) {
@@ -57,13 +60,27 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 2).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 2).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_short_reports() {
+        assert_eq!(part1("5\n1 9").unwrap(), 1);
+        assert_eq!(part2("5\n1 9").unwrap(), 2);
+    }
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
@@ -2,6 +2,7 @@ use nom::{ bytes::complete::tag, combinator::map, sequence::tuple, IResult };
 use regex::Regex;
 
 use crate::utils::{ self, parse_unsigned_int };
+use crate::{ Error, Result };
 
 struct Multiplication {
     left: i32,
@@ -34,37 +35,43 @@ fn parse_multiplication(input: &str) -> IResult<&str, Multiplication> {
     )(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day03/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i32> {
 
     let re = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
     let mut total = 0;
-    for m in re.find_iter(&input) {
-        let (_, mult) = parse_multiplication(m.as_str()).unwrap();
+    for m in re.find_iter(input) {
+        let (_, mult) = parse_multiplication(m.as_str()).map_err(|e| Error::from_nom(input, e))?;
         total += mult.eval();
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day03/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i32> {
 
     let re = Regex::new(r"(mul\(\d{1,3},\d{1,3}\))|(do\(\))|(don't\(\))").unwrap();
     let mut total = 0;
     let mut is_active = true;
-    for m in re.find_iter(&input) {
+    for m in re.find_iter(input) {
         if m.as_str() == "do()" {
             is_active = true;
         } else if m.as_str() == "don't()" {
             is_active = false;
         } else {
             if is_active {
-                let (_, mult) = parse_multiplication(m.as_str()).unwrap();
+                let (_, mult) = parse_multiplication(m.as_str()).map_err(|e| Error::from_nom(input, e))?;
                 total += mult.eval();
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 3).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 3).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,13 +1,14 @@
-use crate::utils;
+use crate::utils::{ self, Grid };
+use crate::Result;
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day04/input.txt").unwrap();
-    let grid: Vec<Vec<char>> = input
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
+fn parse_grid(input: &str) -> Result<Vec<Vec<char>>> {
+    Ok(Grid::parse(input, |c| c)?.rows().map(<[char]>::to_vec).collect())
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let grid = parse_grid(input)?;
     let height = grid.len();
-    let width = grid[0].len();
+    let width = grid.first().map_or(0, Vec::len);
 
     let mut total = 0;
     for i in 0..height {
@@ -86,21 +87,17 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day04/input.txt").unwrap();
-    let grid: Vec<Vec<char>> = input
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
-    let width = grid[0].len();
+pub fn part2(input: &str) -> Result<i32> {
+    let grid = parse_grid(input)?;
+    let width = grid.first().map_or(0, Vec::len);
     let height = grid.len();
 
     let mut total = 0;
-    for i in 1..height - 1 {
-        for j in 1..width - 1 {
+    for i in 1..height.saturating_sub(1) {
+        for j in 1..width.saturating_sub(1) {
             if grid[i][j] == 'A' {
                 if
                     grid[i - 1][j - 1] == 'M' &&
@@ -137,7 +134,15 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 4).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 4).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,15 +1,20 @@
 use nom::{ bytes::complete::tag, multi::separated_list1, sequence::separated_pair, IResult };
 use crate::utils;
+use crate::utils::parse;
+use crate::Result;
 
-fn parse_input(input: &str) -> IResult<&str, (Vec<(i32, i32)>, Vec<Vec<i32>>)> {
+/// Page ordering rules and updates
+type Input = (Vec<(i32, i32)>, Vec<Vec<i32>>);
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
     separated_pair(
         separated_list1(tag("\n"), separated_pair(utils::parse_int, tag("|"), utils::parse_int)),
         tag("\n\n"),
-        separated_list1(tag("\n"), separated_list1(tag(","), utils::parse_int))
+        parse::lines(parse::comma_separated_ints)
     )(input)
 }
 
-fn is_valid(production: &Vec<i32>, pairs: &Vec<(i32, i32)>) -> bool {
+fn is_valid(production: &[i32], pairs: &Vec<(i32, i32)>) -> bool {
     for pair in pairs {
         let index0 = production.iter().position(|&x| x == pair.0);
         let index1 = production.iter().position(|&x| x == pair.1);
@@ -22,9 +27,8 @@ fn is_valid(production: &Vec<i32>, pairs: &Vec<(i32, i32)>) -> bool {
     true
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day05/input.txt").unwrap();
-    let (_, (pairs, productions)) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let (pairs, productions) = utils::parse_all(parse_input, input)?;
 
     let mut total = 0;
     for production in productions {
@@ -32,12 +36,11 @@ pub fn solve1() -> i32 {
             total += production.get(production.len() / 2).unwrap();
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day05/input.txt").unwrap();
-    let (_, (pairs, productions)) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let (pairs, productions) = utils::parse_all(parse_input, input)?;
 
     let mut total = 0;
     for mut production in productions {
@@ -50,12 +53,20 @@ pub fn solve2() -> i32 {
                         return std::cmp::Ordering::Greater;
                     }
                 }
-                return std::cmp::Ordering::Equal;
+                std::cmp::Ordering::Equal
             });
             total += production.get(production.len() / 2).unwrap();
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 5).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 5).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,123 +1,97 @@
-use crate::utils;
+use crate::utils::{self, Direction, Grid};
+use crate::{Error, Result};
 
 struct Configuration {
-    grid: Vec<Vec<char>>,
-    x: i32,
-    y: i32,
-    dx: i32,
-    dy: i32,
+    grid: Grid<char>,
+    position: (usize, usize),
+    direction: Direction,
 }
 
 impl Configuration {
     fn step(&mut self) -> bool {
-        let next_x = self.x + self.dx;
-        let next_y = self.y + self.dy;
-        if
-            next_x < 0 ||
-            next_x >= (self.grid[0].len() as i32) ||
-            next_y < 0 ||
-            next_y >= (self.grid.len() as i32)
-        {
+        let Some(next) = self.grid.step(self.position, self.direction.offset()) else {
             // exit the grid
-            self.x = next_x;
-            self.y = next_y;
             return false;
-        }
+        };
 
-        if self.grid[next_y as usize][next_x as usize] == '#' {
-            // turn right
-            (self.dx, self.dy) = (-self.dy, self.dx);
+        if self.grid[next] == '#' {
+            self.direction = self.direction.turn_right();
         } else {
             // move forward
-            self.x = next_x;
-            self.y = next_y;
+            self.position = next;
         }
-        return true;
+        true
     }
 }
 
-fn make_starting_configuration(grid: Vec<Vec<char>>) -> Configuration {
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] == '^' {
-                return Configuration {
-                    grid: grid,
-                    x: j as i32,
-                    y: i as i32,
-                    dx: 0,
-                    dy: -1,
-                };
-            }
-        }
-    }
-    panic!("No starting position found");
+fn make_starting_configuration(input: &str) -> Result<Configuration> {
+    let grid = Grid::parse(input, |c| c)?;
+    let position = grid.find('^').ok_or_else(|| Error::parse_at(input, input.len(), "no starting position found"))?;
+    Ok(Configuration {
+        grid,
+        position,
+        direction: Direction::North,
+    })
 }
 
-fn test_obstacle(grid: &Vec<Vec<char>>, x: i32, y: i32, start_x: i32, start_y: i32) -> bool {
+fn test_obstacle(grid: &Grid<char>, obstacle: (usize, usize), start: (usize, usize)) -> bool {
     let mut new_grid = grid.clone();
-    new_grid[y as usize][x as usize] = '#';
+    new_grid[obstacle] = '#';
     let mut c = Configuration {
         grid: new_grid,
-        x: start_x,
-        y: start_y,
-        dx: 0,
-        dy: -1,
+        position: start,
+        direction: Direction::North,
     };
 
-    for _ in 0..grid.len() * grid[0].len() {
+    for _ in 0..grid.height() * grid.width() {
         if !c.step() {
             return false;
         }
     }
-    return true;
+    true
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day06/input.txt").unwrap();
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
-    let mut c = make_starting_configuration(grid);
-    c.grid[c.y as usize][c.x as usize] = 'X';
+pub fn part1(input: &str) -> Result<i32> {
+    let mut c = make_starting_configuration(input)?;
+    c.grid[c.position] = 'X';
 
     let mut total = 1;
     while c.step() {
-        if c.grid[c.y as usize][c.x as usize] == '.' {
-            c.grid[c.y as usize][c.x as usize] = 'X';
+        if c.grid[c.position] == '.' {
+            c.grid[c.position] = 'X';
             total += 1;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day06/input.txt").unwrap();
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
-    let mut c = make_starting_configuration(grid);
-    let start_x = c.x;
-    let start_y = c.y;
+pub fn part2(input: &str) -> Result<i32> {
+    let mut c = make_starting_configuration(input)?;
+    let start = c.position;
 
     let mut possible_positions = Vec::new();
     while c.step() {
-        if c.grid[c.y as usize][c.x as usize] == '.' {
-            c.grid[c.y as usize][c.x as usize] = 'X';
-            possible_positions.push((c.x, c.y));
+        if c.grid[c.position] == '.' {
+            c.grid[c.position] = 'X';
+            possible_positions.push(c.position);
         }
     }
 
     let mut total = 0;
-    for (x, y) in possible_positions {
-        if test_obstacle(&c.grid, x as i32, y as i32, start_x, start_y) {
+    for position in possible_positions {
+        if test_obstacle(&c.grid, position, start) {
             total += 1;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 6).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 6).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -132,6 +106,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (700ms)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,9 +1,18 @@
 use nom::{ bytes::complete::tag, multi::separated_list1, sequence::separated_pair, IResult };
 
-use crate::utils;
+use crate::utils::{ self, parse };
+use crate::Result;
 
 fn parse_line(input: &str) -> IResult<&str, (i64, Vec<i64>)> {
-    separated_pair(utils::parse_int, tag(": "), separated_list1(tag(" "), utils::parse_int))(input)
+    separated_pair(
+        utils::parse_unsigned_int,
+        tag(": "),
+        separated_list1(tag(" "), utils::parse_unsigned_int)
+    )(input)
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>> {
+    utils::parse_all(parse::lines(parse_line), input)
 }
 
 fn can_solve_1(target: i64, operands: Vec<i64>) -> bool {
@@ -15,30 +24,18 @@ fn can_solve_1(target: i64, operands: Vec<i64>) -> bool {
         return operands[0] == target;
     }
 
-    // try multiplication
-    if
-        can_solve_1(
-            target,
-            std::iter
-                ::once(operands[0] * operands[1])
-                .chain(operands[2..].iter().cloned())
-                .collect()
-        )
-    {
-        return true;
+    // try multiplication (operands are non-negative, so a result that overflows is above the target)
+    if let Some(x) = operands[0].checked_mul(operands[1]) {
+        if can_solve_1(target, std::iter::once(x).chain(operands[2..].iter().cloned()).collect()) {
+            return true;
+        }
     }
 
     // try addition
-    if
-        can_solve_1(
-            target,
-            std::iter
-                ::once(operands[0] + operands[1])
-                .chain(operands[2..].iter().cloned())
-                .collect()
-        )
-    {
-        return true;
+    if let Some(x) = operands[0].checked_add(operands[1]) {
+        if can_solve_1(target, std::iter::once(x).chain(operands[2..].iter().cloned()).collect()) {
+            return true;
+        }
     }
 
     false
@@ -53,75 +50,60 @@ fn can_solve_2(target: i64, operands: Vec<i64>) -> bool {
         return operands[0] == target;
     }
 
-    // try multiplication
-    if
-        can_solve_2(
-            target,
-            std::iter
-                ::once(operands[0] * operands[1])
-                .chain(operands[2..].iter().cloned())
-                .collect()
-        )
-    {
-        return true;
+    // try multiplication (operands are non-negative, so a result that overflows is above the target)
+    if let Some(x) = operands[0].checked_mul(operands[1]) {
+        if can_solve_2(target, std::iter::once(x).chain(operands[2..].iter().cloned()).collect()) {
+            return true;
+        }
     }
 
     // try addition
-    if
-        can_solve_2(
-            target,
-            std::iter
-                ::once(operands[0] + operands[1])
-                .chain(operands[2..].iter().cloned())
-                .collect()
-        )
-    {
-        return true;
+    if let Some(x) = operands[0].checked_add(operands[1]) {
+        if can_solve_2(target, std::iter::once(x).chain(operands[2..].iter().cloned()).collect()) {
+            return true;
+        }
     }
 
-    // try concatenation
+    // try concatenation (a result that overflows is above the target)
     let str_0 = operands[0].to_string();
     let str_1 = operands[1].to_string();
     let concatenated = format!("{}{}", str_0, str_1);
-    let x = concatenated.parse::<i64>().unwrap();
-    if
-        can_solve_2(
-            target,
-            std::iter
-                ::once(x)
-                .chain(operands[2..].iter().cloned())
-                .collect()
-        )
-    {
-        return true;
+    if let Ok(x) = concatenated.parse::<i64>() {
+        if can_solve_2(target, std::iter::once(x).chain(operands[2..].iter().cloned()).collect()) {
+            return true;
+        }
     }
 
-    return false;
+    false
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2024/day07/input.txt").unwrap();
+pub fn part1(input: &str) -> Result<i64> {
 
     let mut total = 0;
-    for line in input.lines() {
-        let (_, (target, operands)) = parse_line(line).unwrap();
+    for (target, operands) in parse_input(input)? {
         if can_solve_1(target, operands) {
             total += target;
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2024/day07/input.txt").unwrap();
+pub fn part2(input: &str) -> Result<i64> {
     let mut total = 0;
-    for line in input.lines() {
-        let (_, (target, operands)) = parse_line(line).unwrap();
+    for (target, operands) in parse_input(input)? {
         if can_solve_2(target, operands) {
             total += target;
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2024, 7).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2024, 7).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -136,6 +118,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (2s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
@@ -1,24 +1,25 @@
 use std::collections::{ HashMap, HashSet };
 
-use crate::utils;
+use crate::utils::{ self, Grid };
+use crate::Result;
 
-fn get_antennas(input: &str) -> HashMap<char, Vec<(usize, usize)>> {
+/// Positions of the antennas of each frequency
+type Antennas = HashMap<char, Vec<(usize, usize)>>;
+
+/// Size of the map (height and width) and positions of the antennas
+fn get_antennas(input: &str) -> Result<(usize, usize, Antennas)> {
+    let grid = Grid::parse(input, |c| c)?;
     let mut antennas = HashMap::new();
-    for (i, line) in input.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            if c != '.' {
-                antennas.entry(c).or_insert_with(Vec::new).push((j, i));
-            }
+    for ((i, j), &c) in grid.iter() {
+        if c != '.' {
+            antennas.entry(c).or_insert_with(Vec::new).push((j, i));
         }
     }
-    antennas
+    Ok((grid.height(), grid.width(), antennas))
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day08/input.txt").unwrap();
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().chars().count();
-    let antennas: HashMap<char, Vec<(usize, usize)>> = get_antennas(&input);
+pub fn part1(input: &str) -> Result<i32> {
+    let (height, width, antennas) = get_antennas(input)?;
     
     let mut antinodes = HashSet::new();
     for (_, positions) in antennas {
@@ -39,14 +40,11 @@ pub fn solve1() -> i32 {
             }
         }
     }
-    antinodes.len() as i32
+    Ok(antinodes.len() as i32)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day08/input.txt").unwrap();
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().chars().count();
-    let antennas = get_antennas(&input);
+pub fn part2(input: &str) -> Result<i32> {
+    let (height, width, antennas) = get_antennas(input)?;
     
     let mut antinodes = HashSet::new();
     for (_, positions) in antennas {
@@ -78,7 +76,15 @@ pub fn solve2() -> i32 {
             }
         }
     }
-    antinodes.len() as i32
+    Ok(antinodes.len() as i32)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 8).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 8).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::utils;
+use crate::{Error, Result};
 
 struct Block {
     id: i32,
@@ -17,15 +18,15 @@ fn get_checksum(files: Vec<Block>) -> u64 {
     total
 }
 
-fn parse_input(input: &str) -> (Vec<Block>, Vec<Block>) {
+fn parse_input(input: &str) -> Result<(Vec<Block>, Vec<Block>)> {
     let mut files: Vec<Block> = Vec::new();
     let mut free_blocks = Vec::new();
 
     let mut is_file = true;
     let mut id = 0;
     let mut address = 0;
-    for c in input.chars() {
-        let size = c.to_digit(10).unwrap();
+    for (k, c) in input.trim_end().char_indices() {
+        let size = c.to_digit(10).ok_or_else(|| Error::parse_at(input, k, "expected a digit"))?;
         if is_file {
             // file
             if size > 0 {
@@ -49,18 +50,17 @@ fn parse_input(input: &str) -> (Vec<Block>, Vec<Block>) {
         address += size;
         is_file = !is_file;
     }
-    (files, free_blocks)
+    Ok((files, free_blocks))
 }
 
-pub fn solve1() -> u64 {
-    let input = utils::read_input("src/year2024/day09/input.txt").unwrap();
-    let (mut files, mut free_space) = parse_input(&input);
+pub fn part1(input: &str) -> Result<u64> {
+    let (mut files, mut free_space) = parse_input(input)?;
     files.reverse();
 
     let mut i = 0;  // index for files
     let mut j = 0;  // index for free_space
 
-    while files[i].address > free_space[j].address {
+    while i < files.len() && j < free_space.len() && files[i].address > free_space[j].address {
         if files[i].size < free_space[j].size {
             files[i].address = free_space[j].address;
             free_space[j].address += files[i].size;
@@ -77,17 +77,16 @@ pub fn solve1() -> u64 {
                 address: free_space[j].address,
                 size: free_space[j].size,
             });
-            files[i].size = files[i].size - free_space[j].size;
+            files[i].size -= free_space[j].size;
             free_space[j].size = 0;
             j += 1;
         }
     }
-    get_checksum(files)
+    Ok(get_checksum(files))
 }
 
-pub fn solve2() -> u64 {
-    let input = utils::read_input("src/year2024/day09/input.txt").unwrap();
-    let (mut files, mut free_blocks) = parse_input(&input);
+pub fn part2(input: &str) -> Result<u64> {
+    let (mut files, mut free_blocks) = parse_input(input)?;
     files.reverse();
 
     for file in &mut files {
@@ -103,7 +102,15 @@ pub fn solve2() -> u64 {
             }
         }
     }
-    get_checksum(files)
+    Ok(get_checksum(files))
+}
+
+pub fn solve1() -> u64 {
+    part1(&utils::read_day_input(2024, 9).unwrap()).unwrap()
+}
+
+pub fn solve2() -> u64 {
+    part2(&utils::read_day_input(2024, 9).unwrap()).unwrap()
 }
 
 #[cfg(test)]
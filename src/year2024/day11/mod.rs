@@ -3,21 +3,21 @@ use std::collections::HashMap;
 use nom::{ bytes::complete::tag, multi::separated_list1 };
 
 use crate::utils::{ self, parse_unsigned_int };
+use crate::Result;
 
 fn apply_rule(x: i64) -> Vec<i64> {
     if x == 0 {
         return vec![1];
     }
     let s = x.to_string();
-    if s.len() % 2 == 0 {
+    if s.len().is_multiple_of(2) {
         return vec![s[..s.len() / 2].parse().unwrap(), s[s.len() / 2..].parse().unwrap()];
     }
-    return vec![x * 2024];
+    vec![x * 2024]
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2024/day11/input.txt").unwrap();
-    let (_, mut values) = separated_list1(tag(" "), parse_unsigned_int::<i64>)(&input).unwrap();
+pub fn part1(input: &str) -> Result<i64> {
+    let mut values = utils::parse_all(separated_list1(tag(" "), parse_unsigned_int::<i64>), input)?;
     for _ in 0..25 {
         let mut new_values = Vec::new();
         for v in values {
@@ -25,12 +25,11 @@ pub fn solve1() -> i64 {
         }
         values = new_values;
     }
-    values.len() as i64
+    Ok(values.len() as i64)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2024/day11/input.txt").unwrap();
-    let (_, input_values) = separated_list1(tag(" "), parse_unsigned_int::<i64>)(&input).unwrap();
+pub fn part2(input: &str) -> Result<i64> {
+    let input_values = utils::parse_all(separated_list1(tag(" "), parse_unsigned_int::<i64>), input)?;
 
     let mut count = HashMap::new();
     for v in input_values {
@@ -46,7 +45,15 @@ pub fn solve2() -> i64 {
         }
         count = new_count;
     }
-    count.values().sum::<i64>()
+    Ok(count.values().sum::<i64>())
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2024, 11).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2024, 11).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,27 +1,23 @@
 use std::collections::HashSet;
 
-use crate::utils;
+use crate::utils::{self, Grid};
+use crate::Result;
 
-fn get(grid: &Vec<Vec<char>>, i: i32, j: i32) -> Option<char> {
-    if i < 0 || i >= (grid.len() as i32) || j < 0 || j >= (grid[0].len() as i32) {
-        None
-    } else {
-        Some(grid[i as usize][j as usize])
-    }
+fn get(grid: &Grid<char>, i: i32, j: i32) -> Option<char> {
+    grid.get_signed(i as i64, j as i64).copied()
 }
 
-fn get_price_1(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
+fn get_price_1(grid: &mut Grid<char>, i: usize, j: usize) -> i32 {
     let mut to_do = Vec::new();
-    let region = grid[i][j];
+    let region = grid[(i, j)];
     let mut visited = HashSet::new();
     to_do.push((i, j));
     visited.insert((i, j));
 
     let mut perimeter: i32 = 0;
-    while to_do.len() > 0 {
-        let (i, j) = to_do.pop().unwrap();
+    while let Some((i, j)) = to_do.pop() {
         // check (i-1, j)
-        if get(&grid, (i as i32) - 1, j as i32) != Some(region) {
+        if get(grid, (i as i32) - 1, j as i32) != Some(region) {
             perimeter += 1;
         } else {
             if !visited.contains(&(i - 1, j)) {
@@ -30,7 +26,7 @@ fn get_price_1(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
             }
         }
         // check (i+1, j)
-        if get(&grid, (i as i32) + 1, j as i32) != Some(region) {
+        if get(grid, (i as i32) + 1, j as i32) != Some(region) {
             perimeter += 1;
         } else {
             if !visited.contains(&(i + 1, j)) {
@@ -39,7 +35,7 @@ fn get_price_1(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
             }
         }
         // check (i, j-1)
-        if get(&grid, i as i32, (j as i32) - 1) != Some(region) {
+        if get(grid, i as i32, (j as i32) - 1) != Some(region) {
             perimeter += 1;
         } else {
             if !visited.contains(&(i, j - 1)) {
@@ -48,7 +44,7 @@ fn get_price_1(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
             }
         }
         // check (i, j+1)
-        if get(&grid, i as i32, (j as i32) + 1) != Some(region) {
+        if get(grid, i as i32, (j as i32) + 1) != Some(region) {
             perimeter += 1;
         } else {
             if !visited.contains(&(i, j + 1)) {
@@ -59,78 +55,77 @@ fn get_price_1(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
     }
 
     for (i, j) in &visited {
-        grid[*i][*j] = '.';
+        grid[(*i, *j)] = '.';
     }
 
     perimeter * (visited.len() as i32)
 }
 
-fn get_price_2(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
+fn get_price_2(grid: &mut Grid<char>, i: usize, j: usize) -> i32 {
     let mut to_do = Vec::new();
-    let region = grid[i][j];
+    let region = grid[(i, j)];
     let mut visited = HashSet::new();
     to_do.push((i, j));
     visited.insert((i, j));
     let mut nb_sides = 0;
 
-    while to_do.len() > 0 {
-        let (i, j) = to_do.pop().unwrap();
+    while let Some((i, j)) = to_do.pop() {
         // check (i-1, j)
-        if get(&grid, (i as i32) - 1, j as i32) == Some(region) {
+        if get(grid, (i as i32) - 1, j as i32) == Some(region) {
             if !visited.contains(&(i - 1, j)) {
                 to_do.push((i - 1, j));
                 visited.insert((i - 1, j));
             }
         } else {
             if
-                get(&grid, i as i32, (j as i32) + 1) != Some(region) ||
-                (get(&grid, i as i32, (j as i32) + 1) == Some(region) &&
-                    get(&grid, (i as i32) - 1, (j as i32) + 1) == Some(region))
+                get(grid, i as i32, (j as i32) + 1) != Some(region) ||
+                (get(grid, i as i32, (j as i32) + 1) == Some(region) &&
+                    get(grid, (i as i32) - 1, (j as i32) + 1) == Some(region))
             {
                 nb_sides += 1;
             }
         }
         // check (i+1, j)
-        if get(&grid, (i as i32) + 1, j as i32) == Some(region) {
+        if get(grid, (i as i32) + 1, j as i32) == Some(region) {
             if !visited.contains(&(i + 1, j)) {
                 to_do.push((i + 1, j));
                 visited.insert((i + 1, j));
             }
         } else {
             if
-                get(&grid, i as i32, (j as i32) + 1) != Some(region) ||
-                (get(&grid, i as i32, (j as i32) + 1) == Some(region) &&
-                    get(&grid, (i as i32) + 1, (j as i32) + 1) == Some(region))
+                get(grid, i as i32, (j as i32) + 1) != Some(region) ||
+                (get(grid, i as i32, (j as i32) + 1) == Some(region) &&
+                    get(grid, (i as i32) + 1, (j as i32) + 1) == Some(region))
             {
                 nb_sides += 1;
             }
         }
         // check (i, j-1)
-        if get(&grid, i as i32, (j as i32) - 1) == Some(region) {
+        if get(grid, i as i32, (j as i32) - 1) == Some(region) {
             if !visited.contains(&(i, j - 1)) {
                 to_do.push((i, j - 1));
                 visited.insert((i, j - 1));
             }
         } else {
             if
-                get(&grid, (i as i32) + 1, j as i32) != Some(region) ||
-                (get(&grid, (i as i32) + 1, j as i32) == Some(region) &&
-                    get(&grid, (i as i32) + 1, (j as i32) - 1) == Some(region))
+                get(grid, (i as i32) + 1, j as i32) != Some(region) ||
+                (get(grid, (i as i32) + 1, j as i32) == Some(region) &&
+                    get(grid, (i as i32) + 1, (j as i32) - 1) == Some(region))
             {
                 nb_sides += 1;
             }
         }
         // check (i, j+1)
-        if get(&grid, i as i32, (j as i32) + 1) == Some(region) {
+        if get(grid, i as i32, (j as i32) + 1) == Some(region) {
             if !visited.contains(&(i, j + 1)) {
                 to_do.push((i, j + 1));
                 visited.insert((i, j + 1));
             }
         } else {
             if
-                get(&grid, (i as i32) + 1, j as i32) != Some(region) ||
-                (get(&grid, (i as i32) + 1, j as i32) == Some(region) &&
-                    get(&grid, (i as i32) + 1, (j as i32) + 1) == Some(region))
+                get(grid, (i as i32) + 1, j as i32) != Some(region) ||
+                (get(grid, (i as i32) + 1, j as i32) == Some(region) &&
+                    get(grid, (i as i32) + 1, (j as i32) + 1) == Some(region))
             {
                 nb_sides += 1;
             }
@@ -138,52 +133,54 @@ fn get_price_2(grid: &mut Vec<Vec<char>>, i: usize, j: usize) -> i32 {
     }
 
     for (i, j) in &visited {
-        grid[*i][*j] = '.';
+        grid[(*i, *j)] = '.';
     }
 
     nb_sides * (visited.len() as i32)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day12/input.txt").unwrap();
-    let mut grid: Vec<Vec<char>> = input
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
+pub fn part1(input: &str) -> Result<i32> {
+    let mut grid = Grid::parse(input, |c| c)?;
 
     let mut total = 0;
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] != '.' {
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            if grid[(i, j)] != '.' {
                 total += get_price_1(&mut grid, i, j);
             }
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day12/input.txt").unwrap();
-    let mut grid: Vec<Vec<char>> = input
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
+pub fn part2(input: &str) -> Result<i32> {
+    let mut grid = Grid::parse(input, |c| c)?;
 
     let mut total = 0;
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] != '.' {
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            if grid[(i, j)] != '.' {
                 total += get_price_2(&mut grid, i, j);
             }
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 12).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 12).unwrap()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    crate::example_tests!(2024, 12);
+
     #[test]
     fn test_solve1() {
         let solution = solve1();
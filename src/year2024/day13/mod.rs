@@ -1,5 +1,9 @@
 use nom::{ bytes::complete::tag, combinator::map, multi::separated_list1, sequence::tuple };
 use crate::utils::{ self, parse_int };
+use crate::utils::linalg::{Matrix, Solutions};
+use crate::Result;
+use num::Integer;
+use std::cmp::Ordering;
 
 struct Machine {
     x_a: i64,
@@ -10,24 +14,60 @@ struct Machine {
     y_target: i64,
 }
 
+/// Number of tokens needed to press button A `a` times and button B `b` times
+fn cost(a: i64, b: i64) -> i64 {
+    3 * a + b
+}
+
 impl Machine {
+    /// Numbers of presses of buttons A and B that reach the prize with the fewest tokens
     fn solve(&self) -> Option<(i64, i64)> {
-        let delta = self.x_a * self.y_b - self.x_b * self.y_a;
-        if (self.y_b * self.x_target - self.x_b * self.y_target) % delta != 0 {
-            return None;
-        }
-        if (self.x_a * self.y_target - self.y_a * self.x_target) % delta != 0 {
-            return None;
+        let buttons = Matrix::from_rows(vec![vec![self.x_a, self.x_b], vec![self.y_a, self.y_b]]);
+        let solutions = buttons.solve(&[self.x_target, self.y_target]);
+        let (a, b) = match solutions {
+            Solutions::None => return None,
+            Solutions::Unique(_) => {
+                let presses = solutions.integer_solution()?;
+                (presses[0], presses[1])
+            }
+            Solutions::Infinite { ref kernel, .. } if kernel.len() > 1 => {
+                // neither button moves the claw, and the prize is where the claw starts
+                (0, 0)
+            }
+            Solutions::Infinite { .. } => {
+                // the buttons move the claw in the same direction
+                let (point, step) = solutions.integer_line()?;
+                cheapest_on_line((point[0], point[1]), (step[0], step[1]))?
+            }
+        };
+        (a >= 0 && b >= 0).then_some((a, b))
+    }
+}
+
+/// Cheapest numbers of presses `point + n * step` (for an integer `n`) where both numbers are
+/// non-negative
+fn cheapest_on_line(point: (i64, i64), step: (i64, i64)) -> Option<(i64, i64)> {
+    // range of n for which both numbers of presses are non-negative
+    let mut n_min = i64::MIN;
+    let mut n_max = i64::MAX;
+    for (p, s) in [(point.0, step.0), (point.1, step.1)] {
+        match s.cmp(&0) {
+            Ordering::Greater => n_min = n_min.max(Integer::div_ceil(&-p, &s)),
+            Ordering::Less => n_max = n_max.min(Integer::div_floor(&p, &-s)),
+            Ordering::Equal if p < 0 => return None,
+            Ordering::Equal => {}
         }
-        return Some((
-            (self.y_b * self.x_target - self.x_b * self.y_target) / delta,
-            (self.x_a * self.y_target - self.y_a * self.x_target) / delta,
-        ));
     }
+    if n_min > n_max {
+        return None;
+    }
+    // the cost is linear in n, so the cheapest is at one end of the range
+    let n = if cost(step.0, step.1) > 0 { n_min } else { n_max };
+    Some((point.0 + n * step.0, point.1 + n * step.1))
 }
 
-fn parse_input(input: &str) -> Vec<Machine> {
-    let (_, machines) = separated_list1(
+fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    let machines = separated_list1(
         tag("\n\n"),
         map(
             tuple((
@@ -56,36 +96,42 @@ fn parse_input(input: &str) -> Vec<Machine> {
                 y_target: target.3,
             }
         )
-    )(input).unwrap();
-    machines
+    );
+    utils::parse_all(machines, input)
 }
 
-pub fn solve1() -> i64 {
-    let input = utils::read_input("src/year2024/day13/input.txt").unwrap();
-    let machines = parse_input(&input);
+pub fn part1(input: &str) -> Result<i64> {
+    let machines = parse_input(input)?;
 
     let mut total = 0;
     for machine in machines {
         if let Some((i, j)) = machine.solve() {
-            total += 3 * i + j;
+            total += cost(i, j);
         }
     }
-    total
+    Ok(total)
 }
 
-pub fn solve2() -> i64 {
-    let input = utils::read_input("src/year2024/day13/input.txt").unwrap();
-    let machines = parse_input(&input);
+pub fn part2(input: &str) -> Result<i64> {
+    let machines = parse_input(input)?;
 
     let mut total = 0;
     for mut machine in machines {
         machine.x_target += 10000000000000;
         machine.y_target += 10000000000000;
         if let Some((i, j)) = machine.solve() {
-            total += 3 * i + j;
+            total += cost(i, j);
         }
     }
-    total
+    Ok(total)
+}
+
+pub fn solve1() -> i64 {
+    part1(&utils::read_day_input(2024, 13).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i64 {
+    part2(&utils::read_day_input(2024, 13).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -105,4 +151,15 @@ mod tests {
         println!("Part Two: {}", solution);
         assert_eq!(solution, 74478585072604);
     }
+
+    #[test]
+    fn test_colinear_buttons() {
+        let machines = "Button A: X+2, Y+2\nButton B: X+1, Y+1\nPrize: X=10, Y=10\n\n\
+                        Button A: X+4, Y+4\nButton B: X+1, Y+1\nPrize: X=10, Y=10\n\n\
+                        Button A: X+4, Y+6\nButton B: X+2, Y+3\nPrize: X=5, Y=7\n\n\
+                        Button A: X+0, Y+0\nButton B: X+0, Y+0\nPrize: X=0, Y=0\n\n\
+                        Button A: X+0, Y+0\nButton B: X+0, Y+0\nPrize: X=1, Y=0";
+        let presses: Vec<_> = parse_input(machines).unwrap().iter().map(Machine::solve).collect();
+        assert_eq!(presses, vec![Some((0, 10)), Some((2, 2)), None, Some((0, 0)), None]);
+    }
 }
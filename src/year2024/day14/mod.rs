@@ -7,6 +7,7 @@ use nom::{
 };
 
 use crate::utils::{ self, parse_int };
+use crate::Result;
 
 struct Robot {
     x: i32,
@@ -40,9 +41,8 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Robot>> {
     )(input)
 }
 
-pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/day14/input.txt").unwrap();
-    let (_, mut robots) = parse_input(&input).unwrap();
+pub fn part1(input: &str) -> Result<i32> {
+    let mut robots = utils::parse_all(parse_input, input)?;
 
     let width = 101;
     let height = 103;
@@ -71,30 +71,10 @@ pub fn solve1() -> i32 {
         }
     }
 
-    t0 * t1 * t2 * t3
-}
-
-fn print_grid(robots: &Vec<Robot>, width: i32, height: i32) {
-    for i in 0..height {
-        for j in 0..width {
-            let mut found = false;
-            for robot in robots {
-                if robot.x == j && robot.y == i {
-                    found = true;
-                    break;
-                }
-            }
-            if found {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!();
-    }
+    Ok(t0 * t1 * t2 * t3)
 }
 
-fn has_line(robots: &mut Vec<Robot>) -> bool {
+fn has_line(robots: &mut [Robot]) -> bool {
     robots.sort_by(|r1, r2| {
         if r1.y == r2.y {
             r1.x.cmp(&r2.x)
@@ -117,9 +97,8 @@ fn has_line(robots: &mut Vec<Robot>) -> bool {
     false
 }
 
-pub fn solve2() -> i32 {
-    let input = utils::read_input("src/year2024/day14/input.txt").unwrap();
-    let (_, mut robots) = parse_input(&input).unwrap();
+pub fn part2(input: &str) -> Result<i32> {
+    let mut robots = utils::parse_all(parse_input, input)?;
     let width = 101;
     let height = 103;
 
@@ -132,12 +111,18 @@ pub fn solve2() -> i32 {
         time += 1;
 
         if has_line(&mut robots) {
-            println!("Time: {}", time);
-            print_grid(&robots, width, height);
             break;
         }
     }
-    time
+    Ok(time)
+}
+
+pub fn solve1() -> i32 {
+    part1(&utils::read_day_input(2024, 14).unwrap()).unwrap()
+}
+
+pub fn solve2() -> i32 {
+    part2(&utils::read_day_input(2024, 14).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -152,6 +137,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(not(feature = "slow"), ignore = "long test (1s)")]
     fn test_solve2() {
         let solution = solve2();
         println!("Part Two: {}", solution);
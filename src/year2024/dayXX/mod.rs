@@ -1,13 +1,19 @@
-use crate::utils;
+use crate::{utils, Result};
+
+pub fn part1(_input: &str) -> Result<i32> {
+    Ok(0)
+}
+
+pub fn part2(_input: &str) -> Result<i32> {
+    Ok(0)
+}
 
 pub fn solve1() -> i32 {
-    let input = utils::read_input("src/year2024/dayXX/input.txt").unwrap();
-    0
+    part1(&utils::read_day_input(YEAR, DAY).unwrap()).unwrap()
 }
 
 pub fn solve2() -> i32 {
-    // let input = utils::read_input("src/year2024/dayXX/input.txt").unwrap();
-    0
+    part2(&utils::read_day_input(YEAR, DAY).unwrap()).unwrap()
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use crate::solution::Registry;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -12,3 +14,23 @@ pub mod day11;
 pub mod day12;
 pub mod day13;
 pub mod day14;
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2024, 1, day01::part1, day01::part2);
+    registry.add(2024, 2, day02::part1, day02::part2);
+    registry.add(2024, 3, day03::part1, day03::part2);
+    registry.add(2024, 4, day04::part1, day04::part2);
+    registry.add(2024, 5, day05::part1, day05::part2);
+    registry.add(2024, 6, day06::part1, day06::part2);
+    registry.mark_slow(2024, 6, 2);
+    registry.add(2024, 7, day07::part1, day07::part2);
+    registry.mark_slow(2024, 7, 2);
+    registry.add(2024, 8, day08::part1, day08::part2);
+    registry.add(2024, 9, day09::part1, day09::part2);
+    registry.add(2024, 10, day10::part1, day10::part2);
+    registry.add(2024, 11, day11::part1, day11::part2);
+    registry.add(2024, 12, day12::part1, day12::part2);
+    registry.add(2024, 13, day13::part1, day13::part2);
+    registry.add(2024, 14, day14::part1, day14::part2);
+    registry.mark_slow(2024, 14, 2);
+}